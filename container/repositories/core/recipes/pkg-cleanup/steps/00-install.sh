@@ -16,3 +16,11 @@ fi
 if command -v apk; then
     rm -rf /var/cache/apk/*
 fi
+
+if command -v dnf; then
+    dnf clean all
+fi
+
+if command -v pacman; then
+    rm -rf /var/cache/pacman/pkg/*
+fi
@@ -1,12 +1,14 @@
 //! Definition of the command line arguments.
 
 use std::path::PathBuf;
+use std::time::Duration;
 
 use clap::Parser;
 
 use crate::config::systems::Architecture;
 use crate::oven::system::ReleaseInfo;
 use crate::oven::BundleOpts;
+use crate::project::lockfile::LockMode;
 
 /// Command line arguments.
 #[derive(Debug, Parser)]
@@ -15,11 +17,29 @@ pub struct Args {
     /// Path to the `rugix-bakery.toml` configuration file.
     #[clap(long)]
     pub config: Option<PathBuf>,
+    /// Pin packages to the versions in `rugix-bakery.lock` and fail if they differ.
+    #[clap(long, global = true, conflicts_with = "update_lock")]
+    pub locked: bool,
+    /// Update `rugix-bakery.lock` with the packages of the baked layers.
+    #[clap(long, global = true)]
+    pub update_lock: bool,
     /// The command to execute.
     #[clap(subcommand)]
     pub cmd: Command,
 }
 
+impl Args {
+    pub fn lock_mode(&self) -> LockMode {
+        if self.locked {
+            LockMode::Locked
+        } else if self.update_lock {
+            LockMode::Update
+        } else {
+            LockMode::Unlocked
+        }
+    }
+}
+
 /// Commands of the CLI.
 #[derive(Debug, Parser)]
 pub enum Command {
@@ -42,6 +62,16 @@ pub enum Command {
     /// Control the cache of Rugix Bakery.
     #[clap(subcommand)]
     Cache(CacheCommand),
+    /// Debug layers interactively.
+    #[clap(subcommand)]
+    Debug(DebugCommand),
+    /// Show reports about baked systems.
+    #[clap(subcommand)]
+    Report(ReportCommand),
+    /// Compare the filesystems of two layers or systems.
+    Diff(DiffCommand),
+    /// Bake a system twice and check that the results are identical.
+    VerifyReproducible(VerifyReproducibleCommand),
     /// Run Rugix Bundler.
     Bundler(BundlerCommand),
 }
@@ -68,6 +98,20 @@ impl ReleaseInfoArgs {
 pub enum ListCommand {
     /// List available images.
     Systems,
+    /// List the recipes of a layer in the order in which they are applied.
+    Schedule {
+        /// The architecture to schedule the layer for.
+        #[clap(long, required_unless_present = "system")]
+        arch: Option<Architecture>,
+        /// Schedule the layer for the architecture and target of the given system.
+        #[clap(long, conflicts_with = "arch")]
+        system: Option<String>,
+        /// Release information of the system, as templates may refer to it.
+        #[clap(flatten)]
+        release: ReleaseInfoArgs,
+        /// The name of the layer.
+        layer: String,
+    },
 }
 
 /// The `bake` command.
@@ -83,6 +127,9 @@ pub enum BakeCommand {
         release: ReleaseInfoArgs,
         #[clap(long)]
         source_date: Option<jiff::Timestamp>,
+        /// Export the system root in the given format instead of baking an image.
+        #[clap(long)]
+        format: Option<ExportFormat>,
     },
     /// Bake a layer.
     Layer {
@@ -93,6 +140,15 @@ pub enum BakeCommand {
         layer: String,
         #[clap(long)]
         source_date: Option<jiff::Timestamp>,
+        /// Export the layer's system root in the given format.
+        #[clap(long)]
+        format: Option<ExportFormat>,
+        /// The output path for the exported layer.
+        #[clap(long, requires = "format")]
+        output: Option<PathBuf>,
+        /// Drop into a shell within the layer's root if a recipe fails.
+        #[clap(long, conflicts_with = "format")]
+        debug_on_failure: bool,
     },
     /// Bake a bundle.
     Bundle {
@@ -106,6 +162,80 @@ pub enum BakeCommand {
     },
 }
 
+/// Format for exporting system roots.
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub enum ExportFormat {
+    /// OCI image layout directory or, if the output ends with `.tar`, OCI archive.
+    Oci,
+}
+
+/// The `debug` command.
+#[derive(Debug, Parser)]
+pub enum DebugCommand {
+    /// Bake a layer and drop into a shell within its root.
+    ///
+    /// If a recipe fails, the shell is opened at the failing step instead.
+    Layer {
+        /// The architecture of the layer.
+        #[clap(long)]
+        arch: Architecture,
+        /// The name of the layer.
+        layer: String,
+        #[clap(long)]
+        source_date: Option<jiff::Timestamp>,
+    },
+}
+
+/// The `report` command.
+#[derive(Debug, Parser)]
+pub enum ReportCommand {
+    /// Show the size attributed to recipes, packages, and directories of a system.
+    Size {
+        /// The name of the system.
+        system: String,
+        /// Maximal number of packages and directories to show.
+        #[clap(long, default_value_t = 20)]
+        limit: usize,
+        /// Output the report as JSON.
+        #[clap(long)]
+        json: bool,
+    },
+}
+
+/// The `diff` command.
+#[derive(Debug, Parser)]
+pub struct DiffCommand {
+    /// The architecture of layers to compare.
+    ///
+    /// Systems are compared with their own architecture.
+    #[clap(long)]
+    pub arch: Option<Architecture>,
+    /// Only show the number of changes per top-level directory.
+    #[clap(long)]
+    pub summary: bool,
+    /// Output the changes as JSON.
+    #[clap(long)]
+    pub json: bool,
+    #[clap(long)]
+    pub source_date: Option<jiff::Timestamp>,
+    /// Release information of compared systems, as templates may refer to it.
+    #[clap(flatten)]
+    pub release: ReleaseInfoArgs,
+    /// The old layer or system.
+    pub old: String,
+    /// The new layer or system.
+    pub new: String,
+}
+
+/// The `verify-reproducible` command.
+#[derive(Debug, Parser)]
+pub struct VerifyReproducibleCommand {
+    /// The name of the system.
+    pub system: String,
+    #[clap(long)]
+    pub source_date: Option<jiff::Timestamp>,
+}
+
 /// The `test` command.
 #[derive(Debug, Parser)]
 pub struct TestCommand {
@@ -117,6 +247,74 @@ pub struct TestCommand {
 pub enum CacheCommand {
     /// Clean the cache.
     Clean,
+    /// Push all baked layers to the shared layer cache.
+    Push,
+    /// Pull the layers of all systems from the shared layer cache.
+    Pull {
+        /// Release information of the systems, as templates may refer to it.
+        #[clap(flatten)]
+        release: ReleaseInfoArgs,
+    },
+    /// Verify the integrity of all downloaded files in the cache.
+    Verify,
+    /// Evict the least recently used layers, checkpoints, and downloads.
+    ///
+    /// Layers and downloads used by the project's systems are never evicted. As
+    /// templates may refer to the release information, layers are only protected if
+    /// they have been baked with the given release information.
+    Gc {
+        /// Maximal size of the cache, e.g., `20G`.
+        #[clap(long, value_parser = parse_size)]
+        max_size: Option<u64>,
+        /// Maximal time since an entry has last been used, e.g., `30d`.
+        #[clap(long, value_parser = parse_age)]
+        max_age: Option<Duration>,
+        /// Only list the entries which would be evicted.
+        #[clap(long)]
+        dry_run: bool,
+        /// Release information of the systems.
+        #[clap(flatten)]
+        release: ReleaseInfoArgs,
+    },
+}
+
+/// Parse a size with an optional binary unit suffix (`K`, `M`, `G`, or `T`).
+fn parse_size(value: &str) -> Result<u64, String> {
+    let value = value.trim_end_matches(['B', 'i']);
+    let (number, shift) = match value.char_indices().last() {
+        Some((idx, 'K' | 'k')) => (&value[..idx], 10),
+        Some((idx, 'M' | 'm')) => (&value[..idx], 20),
+        Some((idx, 'G' | 'g')) => (&value[..idx], 30),
+        Some((idx, 'T' | 't')) => (&value[..idx], 40),
+        _ => (value, 0),
+    };
+    let number = number
+        .trim()
+        .parse::<u64>()
+        .map_err(|_| format!("invalid size `{value}`"))?;
+    number
+        .checked_mul(1 << shift)
+        .ok_or_else(|| format!("size `{value}` is too large"))
+}
+
+/// Parse a duration with a unit suffix (`s`, `m`, `h`, `d`, or `w`).
+fn parse_age(value: &str) -> Result<Duration, String> {
+    let (number, seconds) = match value.char_indices().last() {
+        Some((idx, 's')) => (&value[..idx], 1),
+        Some((idx, 'm')) => (&value[..idx], 60),
+        Some((idx, 'h')) => (&value[..idx], 60 * 60),
+        Some((idx, 'd')) => (&value[..idx], 24 * 60 * 60),
+        Some((idx, 'w')) => (&value[..idx], 7 * 24 * 60 * 60),
+        _ => return Err(format!("duration `{value}` is missing a unit")),
+    };
+    let number = number
+        .trim()
+        .parse::<u64>()
+        .map_err(|_| format!("invalid duration `{value}`"))?;
+    number
+        .checked_mul(seconds)
+        .map(Duration::from_secs)
+        .ok_or_else(|| format!("duration `{value}` is too large"))
 }
 
 /// The `run` command.
@@ -150,3 +348,58 @@ pub struct BundlerCommand {
     #[clap(allow_hyphen_values(true))]
     pub args: Vec<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_size() {
+        assert_eq!(parse_size("0"), Ok(0));
+        assert_eq!(parse_size("512"), Ok(512));
+        assert_eq!(parse_size("512B"), Ok(512));
+        assert_eq!(parse_size("4K"), Ok(4 << 10));
+        assert_eq!(parse_size("4k"), Ok(4 << 10));
+        assert_eq!(parse_size("4KiB"), Ok(4 << 10));
+        assert_eq!(parse_size("20M"), Ok(20 << 20));
+        assert_eq!(parse_size("20G"), Ok(20 << 30));
+        assert_eq!(parse_size("20GB"), Ok(20 << 30));
+        assert_eq!(parse_size("2T"), Ok(2 << 40));
+        assert_eq!(parse_size("16777215T"), Ok(16777215 << 40));
+    }
+
+    #[test]
+    fn test_parse_size_invalid() {
+        for value in ["", "G", "-1G", "1.5G", "20X", "G20", "16777216T"] {
+            assert!(parse_size(value).is_err(), "{value:?} should be invalid");
+        }
+    }
+
+    #[test]
+    fn test_parse_age() {
+        assert_eq!(parse_age("30s"), Ok(Duration::from_secs(30)));
+        assert_eq!(parse_age("15m"), Ok(Duration::from_secs(15 * 60)));
+        assert_eq!(parse_age("12h"), Ok(Duration::from_secs(12 * 60 * 60)));
+        assert_eq!(parse_age("30d"), Ok(Duration::from_secs(30 * 24 * 60 * 60)));
+        assert_eq!(
+            parse_age("2w"),
+            Ok(Duration::from_secs(2 * 7 * 24 * 60 * 60))
+        );
+    }
+
+    #[test]
+    fn test_parse_age_invalid() {
+        for value in [
+            "",
+            "30",
+            "d",
+            "-1d",
+            "1.5d",
+            "30y",
+            "30ä",
+            "18446744073709551615w",
+        ] {
+            assert!(parse_age(value).is_err(), "{value:?} should be invalid");
+        }
+    }
+}
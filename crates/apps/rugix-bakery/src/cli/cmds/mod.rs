@@ -1,9 +1,13 @@
 pub mod run_bake;
 pub mod run_bundler;
 pub mod run_cache;
+pub mod run_debug;
+pub mod run_diff;
 pub mod run_init;
 pub mod run_list;
 pub mod run_pull;
+pub mod run_report;
 pub mod run_run;
 pub mod run_shell;
 pub mod run_test;
+pub mod run_verify_reproducible;
@@ -6,6 +6,7 @@ use reportify::ResultExt;
 
 use crate::cli::{args, load_project};
 use crate::oven::LayerBakery;
+use crate::utils::atomic;
 use crate::{oven, BakeryResult};
 
 /// Run the `bake` command.
@@ -17,10 +18,23 @@ pub fn run(args: &args::Args, cmd: &args::BakeCommand) -> BakeryResult<()> {
             output,
             release,
             source_date,
+            format,
         } => {
             let system_path = Path::new("build").join(system);
             let source_date_epoch =
                 source_date.unwrap_or_else(jiff::Timestamp::now).as_second() as u64;
+            if let Some(args::ExportFormat::Oci) = format {
+                let output = output
+                    .clone()
+                    .unwrap_or_else(|| Path::new("build").join(format!("{system}.oci")));
+                return oven::bake_system_oci(
+                    &project,
+                    &release.release_info(),
+                    system,
+                    &output,
+                    source_date_epoch,
+                );
+            }
             oven::bake_system(
                 &project,
                 &release.release_info(),
@@ -36,12 +50,13 @@ pub fn run(args: &args::Args, cmd: &args::BakeCommand) -> BakeryResult<()> {
                     .join("system.img")
                     .canonicalize()
                     .whatever("unable to canonicalize system image path")?;
-                let output_image_path = output
-                    .canonicalize()
-                    .whatever("unable to canonicalize output image path")?;
+                let output_image_path = output.canonicalize().unwrap_or_else(|_| output.clone());
                 if system_image_path != output_image_path {
-                    std::fs::copy(system_image_path, output_image_path)
+                    let partial_image_path = atomic::partial_path(&output_image_path);
+                    std::fs::copy(system_image_path, &partial_image_path)
                         .whatever("error copying image")?;
+                    atomic::finish(&partial_image_path, &output_image_path)
+                        .whatever("error moving image into place")?;
                 }
             }
         }
@@ -49,10 +64,25 @@ pub fn run(args: &args::Args, cmd: &args::BakeCommand) -> BakeryResult<()> {
             layer,
             arch,
             source_date,
+            format,
+            output,
+            debug_on_failure,
         } => {
             let source_date_epoch =
                 source_date.unwrap_or_else(jiff::Timestamp::now).as_second() as u64;
-            LayerBakery::new(&project, *arch).bake_root(layer, source_date_epoch)?;
+            match format {
+                Some(args::ExportFormat::Oci) => {
+                    let output = output
+                        .clone()
+                        .unwrap_or_else(|| Path::new("build").join(format!("{layer}-{arch}.oci")));
+                    oven::bake_layer_oci(&project, *arch, layer, &output, source_date_epoch)?;
+                }
+                None => {
+                    LayerBakery::new(&project, *arch)
+                        .with_debug_on_failure(*debug_on_failure)
+                        .bake_root(layer, source_date_epoch)?;
+                }
+            }
         }
         args::BakeCommand::Bundle {
             system,
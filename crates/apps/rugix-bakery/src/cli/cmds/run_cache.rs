@@ -2,10 +2,20 @@
 
 use std::path::Path;
 
-use crate::cli::{args, load_project};
+use reportify::{bail, whatever, ResultExt};
+use tracing::{error, info};
+
+use crate::cli::{args, format_size, load_project};
+use crate::oven::cache::LayerCache;
+use crate::oven::customize::is_up_to_date;
+use crate::oven::gc::{plan_gc, GcLimits};
+use crate::oven::system::TargetSystem;
+use crate::oven::LayerBakery;
+use crate::project::ProjectRef;
+use crate::utils::caching::{download_metadata_path, sha256_file, DownloadMetadata};
 use crate::BakeryResult;
 
-/// Run the `list` command.
+/// Run the `cache` command.
 pub fn run(args: &args::Args, cmd: &args::CacheCommand) -> BakeryResult<()> {
     let project = load_project(args)?;
     match cmd {
@@ -16,6 +26,146 @@ pub fn run(args: &args::Args, cmd: &args::CacheCommand) -> BakeryResult<()> {
             )
             .ok();
         }
+        args::CacheCommand::Push => {
+            let cache = layer_cache(&project)?;
+            let layers_dir = project.dir().join(project.layers_dir());
+            let mut pushed = 0;
+            if layers_dir.exists() {
+                for entry in std::fs::read_dir(&layers_dir).whatever("unable to read layers")? {
+                    let entry = entry.whatever("unable to read layers")?;
+                    let layer_dir = entry.path();
+                    if !is_up_to_date(&project, &layer_dir)
+                        || !layer_dir.join("inputs.json").exists()
+                    {
+                        continue;
+                    }
+                    let id = entry.file_name().to_string_lossy().into_owned();
+                    if cache.push(&project, &id, &layer_dir)? {
+                        pushed += 1;
+                    }
+                }
+            }
+            info!("pushed {pushed} layers to the cache");
+        }
+        args::CacheCommand::Pull { release } => {
+            let cache = layer_cache(&project)?;
+            let mut pulled = 0;
+            for (system, system_config) in project.config().systems.iter().flatten() {
+                let bakery = LayerBakery::new(&project, system_config.architecture)
+                    .with_target(system_config.target.clone())
+                    .with_system(TargetSystem {
+                        name: system.clone(),
+                        release: release.release_info(),
+                    });
+                let plan = bakery.plan_root(&system_config.layer)?;
+                // Ancestors are only needed if the layer itself is not available.
+                for plan in plan.ancestors() {
+                    let layer_dir = project.dir().join(plan.path());
+                    if is_up_to_date(&project, &layer_dir) {
+                        break;
+                    }
+                    if cache.pull(&project, &plan.id, &layer_dir)? {
+                        pulled += 1;
+                        break;
+                    }
+                }
+            }
+            info!("pulled {pulled} layers from the cache");
+        }
+        args::CacheCommand::Verify => {
+            let cache_dir = project.dir().join(".rugix/cache");
+            let mut verified = 0;
+            let mut failed = 0;
+            if cache_dir.exists() {
+                for entry in std::fs::read_dir(&cache_dir).whatever("unable to read cache")? {
+                    let path = entry.whatever("unable to read cache")?.path();
+                    let name = path.to_string_lossy();
+                    if name.ends_with(".meta.json") || name.ends_with(".partial") {
+                        continue;
+                    }
+                    match verify_download(&path) {
+                        Ok(()) => verified += 1,
+                        Err(reason) => {
+                            error!("cache file {path:?} is invalid: {reason}");
+                            failed += 1;
+                        }
+                    }
+                }
+            }
+            info!("verified {verified} cache files");
+            if failed > 0 {
+                bail!("{failed} cache files are invalid, run `cache clean` to remove them");
+            }
+        }
+        args::CacheCommand::Gc {
+            max_size,
+            max_age,
+            dry_run,
+            release,
+        } => {
+            if max_size.is_none() && max_age.is_none() {
+                bail!("`--max-size` or `--max-age` is required");
+            }
+            let limits = GcLimits {
+                max_size: *max_size,
+                max_age: *max_age,
+            };
+            let plan = plan_gc(&project, &release.release_info(), &limits)?;
+            let freed = plan.evict.iter().map(|entry| entry.size).sum::<u64>();
+            if *dry_run {
+                rugix_cli::suspend(|| {
+                    for entry in &plan.evict {
+                        eprintln!(
+                            "{:<10} {:>10}  {}",
+                            entry.kind.as_str(),
+                            format_size(entry.size),
+                            entry.paths[0].display()
+                        );
+                    }
+                    eprintln!(
+                        "would free {} of {}",
+                        format_size(freed),
+                        format_size(plan.total_size)
+                    );
+                });
+            } else {
+                for entry in &plan.evict {
+                    info!("evicting {} {:?}", entry.kind.as_str(), entry.paths[0]);
+                    entry.remove()?;
+                }
+                info!(
+                    "freed {} of {}",
+                    format_size(freed),
+                    format_size(plan.total_size)
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Verify a downloaded file against its metadata.
+fn verify_download(path: &Path) -> Result<(), String> {
+    let metadata = std::fs::read_to_string(download_metadata_path(path))
+        .map_err(|_| "missing metadata, download is incomplete".to_owned())?;
+    let metadata: DownloadMetadata =
+        serde_json::from_str(&metadata).map_err(|error| format!("invalid metadata: {error}"))?;
+    let (sha256, size) =
+        sha256_file(path).map_err(|error| format!("unable to compute checksum: {error}"))?;
+    if size != metadata.size {
+        return Err(format!("expected {} bytes, found {size}", metadata.size));
+    }
+    if sha256 != metadata.sha256 {
+        return Err(format!(
+            "expected `sha256:{}`, found `sha256:{sha256}`",
+            metadata.sha256
+        ));
     }
     Ok(())
 }
+
+/// Layer cache of the project.
+fn layer_cache(project: &ProjectRef) -> BakeryResult<LayerCache> {
+    LayerCache::from_project(project)?
+        .ok_or_else(|| whatever!("no layer cache configured in the project"))
+}
@@ -0,0 +1,24 @@
+//! The `debug` command.
+
+use crate::cli::{args, load_project};
+use crate::oven::LayerBakery;
+use crate::BakeryResult;
+
+/// Run the `debug` command.
+pub fn run(args: &args::Args, cmd: &args::DebugCommand) -> BakeryResult<()> {
+    let project = load_project(args)?;
+    match cmd {
+        args::DebugCommand::Layer {
+            arch,
+            layer,
+            source_date,
+        } => {
+            let source_date_epoch =
+                source_date.unwrap_or_else(jiff::Timestamp::now).as_second() as u64;
+            LayerBakery::new(&project, *arch)
+                .with_debug_on_failure(true)
+                .debug_root(layer, source_date_epoch)?;
+        }
+    }
+    Ok(())
+}
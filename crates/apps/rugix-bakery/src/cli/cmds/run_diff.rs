@@ -0,0 +1,142 @@
+//! The `diff` command.
+
+use std::path::Path;
+
+use reportify::{bail, ResultExt};
+use serde::Serialize;
+
+use crate::cli::{args, format_size, format_size_delta, load_project};
+use crate::oven::layer::FrozenLayer;
+use crate::oven::system::TargetSystem;
+use crate::oven::LayerBakery;
+use crate::project::ProjectRef;
+use crate::utils::fsdiff::{self, Change, FileChange, FileInfo, FileKind};
+use crate::BakeryResult;
+
+/// Run the `diff` command.
+pub fn run(args: &args::Args, cmd: &args::DiffCommand) -> BakeryResult<()> {
+    let project = load_project(args)?;
+    let source_date_epoch = cmd
+        .source_date
+        .unwrap_or_else(jiff::Timestamp::now)
+        .as_second() as u64;
+    let old = bake(&project, &cmd.old, cmd, source_date_epoch)?.unfreeze()?;
+    let new = bake(&project, &cmd.new, cmd, source_date_epoch)?.unfreeze()?;
+    let old_tree = fsdiff::scan(old.path()).whatever("unable to scan old layer")?;
+    let new_tree = fsdiff::scan(new.path()).whatever("unable to scan new layer")?;
+    let changes = fsdiff::diff(&old_tree, &new_tree, false);
+    if cmd.summary {
+        let summary = fsdiff::summarize(&changes);
+        if cmd.json {
+            println!("{}", serde_json::to_string_pretty(&summary).unwrap());
+            return Ok(());
+        }
+        rugix_cli::suspend(|| {
+            eprintln!(
+                "{:<24} {:>8} {:>8} {:>8} {:>12}",
+                "Directory", "Added", "Removed", "Modified", "Size"
+            );
+            for (dir, summary) in &summary {
+                eprintln!(
+                    "{:<24} {:>8} {:>8} {:>8} {:>12}",
+                    Path::new("/").join(dir).display(),
+                    summary.added,
+                    summary.removed,
+                    summary.modified,
+                    format_size_delta(summary.size_delta)
+                );
+            }
+        });
+    } else if cmd.json {
+        #[derive(Serialize)]
+        struct DiffOutput<'c> {
+            old: &'c str,
+            new: &'c str,
+            changes: &'c [FileChange],
+        }
+        let output = DiffOutput {
+            old: &cmd.old,
+            new: &cmd.new,
+            changes: &changes,
+        };
+        println!("{}", serde_json::to_string_pretty(&output).unwrap());
+    } else {
+        rugix_cli::suspend(|| {
+            for change in &changes {
+                let path = Path::new("/").join(&change.path);
+                match &change.change {
+                    Change::Added { new } => {
+                        eprintln!("A {} ({})", path.display(), describe(new));
+                    }
+                    Change::Removed { old } => {
+                        eprintln!("D {} ({})", path.display(), describe(old));
+                    }
+                    Change::Modified { changed, old, new } => {
+                        eprintln!("M {}", path.display());
+                        for property in changed {
+                            eprintln!("    {}", describe_change(property, old, new));
+                        }
+                    }
+                }
+            }
+            eprintln!("{} files changed", changes.len());
+        });
+    }
+    Ok(())
+}
+
+/// Bake the layer with the given name or the root layer of the system with the name.
+fn bake(
+    project: &ProjectRef,
+    name: &str,
+    cmd: &args::DiffCommand,
+    source_date_epoch: u64,
+) -> BakeryResult<FrozenLayer> {
+    if let Some(system_config) = project.config().get_system_config(name) {
+        LayerBakery::new(project, system_config.architecture)
+            .with_target(system_config.target.clone())
+            .with_system(TargetSystem {
+                name: name.to_owned(),
+                release: cmd.release.release_info(),
+            })
+            .bake_root(&system_config.layer, source_date_epoch)
+    } else {
+        let Some(arch) = cmd.arch else {
+            bail!("`{name}` is not a system, `--arch` is required to compare layers");
+        };
+        LayerBakery::new(project, arch).bake_root(name, source_date_epoch)
+    }
+}
+
+/// Describe the given file.
+fn describe(info: &FileInfo) -> String {
+    let kind = match &info.kind {
+        FileKind::File => format_size(info.size),
+        FileKind::Directory => "directory".to_owned(),
+        FileKind::Symlink { target } => format!("symlink to {}", target.display()),
+        FileKind::Device { .. } => "device".to_owned(),
+        FileKind::Fifo => "fifo".to_owned(),
+        FileKind::Socket => "socket".to_owned(),
+    };
+    format!("{kind}, {:04o}, {}:{}", info.mode, info.uid, info.gid)
+}
+
+/// Describe the change of the given property.
+fn describe_change(property: &str, old: &FileInfo, new: &FileInfo) -> String {
+    match property {
+        "content" => {
+            let mut description = format!(
+                "content: {} -> {}",
+                format_size(old.size),
+                format_size(new.size)
+            );
+            if let (Some(old), Some(new)) = (&old.sha256, &new.sha256) {
+                description.push_str(&format!(", sha256 {:.12} -> {:.12}", old, new));
+            }
+            description
+        }
+        "mode" => format!("mode: {:04o} -> {:04o}", old.mode, new.mode),
+        "owner" => format!("owner: {}:{} -> {}:{}", old.uid, old.gid, new.uid, new.gid),
+        _ => format!("{property}: {} -> {}", describe(old), describe(new)),
+    }
+}
@@ -1,6 +1,10 @@
 //! The `list` command.
 
+use reportify::whatever;
+
 use crate::cli::{args, load_project};
+use crate::oven::system::TargetSystem;
+use crate::oven::LayerBakery;
 use crate::BakeryResult;
 
 /// Run the `list` command.
@@ -19,6 +23,48 @@ pub fn run(args: &args::Args, cmd: &args::ListCommand) -> BakeryResult<()> {
                 }
             });
         }
+        args::ListCommand::Schedule {
+            arch,
+            system,
+            release,
+            layer,
+        } => {
+            let bakery = match system {
+                Some(system) => {
+                    let system_config = project
+                        .config()
+                        .get_system_config(system)
+                        .ok_or_else(|| whatever!("unable to find system {system}"))?;
+                    LayerBakery::new(&project, system_config.architecture)
+                        .with_target(system_config.target.clone())
+                        .with_system(TargetSystem {
+                            name: system.clone(),
+                            release: release.release_info(),
+                        })
+                }
+                None => LayerBakery::new(&project, arch.unwrap()),
+            };
+            let plan = bakery.plan_root(layer)?;
+            rugix_cli::suspend(|| {
+                if plan.jobs.is_empty() {
+                    eprintln!("Layer `{layer}` has no recipes.");
+                    return;
+                }
+                eprintln!("Schedule of Layer `{layer}`:");
+                for (idx, job) in plan.jobs.iter().enumerate() {
+                    let recipe = &job.recipe;
+                    eprintln!(
+                        "  {:>2}. {} (priority {})",
+                        idx + 1,
+                        recipe.name,
+                        recipe.config.priority.unwrap_or_default()
+                    );
+                    for step in &job.skipped_steps {
+                        eprintln!("      skipped step {step}");
+                    }
+                }
+            });
+        }
     }
     Ok(())
 }
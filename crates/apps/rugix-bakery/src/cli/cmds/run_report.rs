@@ -0,0 +1,70 @@
+//! The `report` command.
+
+use std::path::Path;
+
+use reportify::whatever;
+
+use crate::cli::{args, format_size, format_size_delta, load_project};
+use crate::oven::size::SizeReport;
+use crate::BakeryResult;
+
+/// Run the `report` command.
+pub fn run(args: &args::Args, cmd: &args::ReportCommand) -> BakeryResult<()> {
+    let project = load_project(args)?;
+    match cmd {
+        args::ReportCommand::Size {
+            system,
+            limit,
+            json,
+        } => {
+            project.config().resolve_system_config(system)?;
+            let system_dir = project.dir().join("build").join(system);
+            let report = SizeReport::load(&system_dir)?.ok_or_else(|| {
+                whatever!("no size report for system `{system}`, bake the system first")
+            })?;
+            if *json {
+                println!("{}", serde_json::to_string_pretty(&report).unwrap());
+                return Ok(());
+            }
+            let mut recipes = report.recipes.clone();
+            recipes.sort_by_key(|recipe| std::cmp::Reverse(recipe.size_delta));
+            rugix_cli::suspend(|| {
+                eprintln!(
+                    "Total size of system `{system}`: {}",
+                    format_size(report.total_size)
+                );
+                eprintln!();
+                eprintln!("Recipes:");
+                for recipe in &recipes {
+                    let size = match recipe.size_delta {
+                        Some(delta) => format_size_delta(delta),
+                        None => "unknown".to_owned(),
+                    };
+                    eprintln!("  {size:>12}  {} ({})", recipe.recipe, recipe.layer);
+                }
+                if !report.packages.is_empty() {
+                    eprintln!();
+                    eprintln!("Packages ({} installed):", report.packages.len());
+                    for package in report.packages.iter().take(*limit) {
+                        eprintln!(
+                            "  {:>12}  {} {}",
+                            format_size(package.size),
+                            package.name,
+                            package.version
+                        );
+                    }
+                }
+                eprintln!();
+                eprintln!("Directories:");
+                for directory in report.directories.iter().take(*limit) {
+                    eprintln!(
+                        "  {:>12}  {}",
+                        format_size(directory.size),
+                        Path::new("/").join(&directory.path).display()
+                    );
+                }
+            });
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,78 @@
+//! The `verify-reproducible` command.
+
+use std::path::Path;
+
+use reportify::bail;
+use tracing::info;
+
+use crate::cli::{args, project_loader};
+use crate::oven::reproducible::{verify_reproducible, Outcome};
+use crate::utils::fsdiff::Change;
+use crate::BakeryResult;
+
+/// Maximal number of differing files to report per artifact.
+const MAX_REPORTED_CHANGES: usize = 20;
+
+/// Run the `verify-reproducible` command.
+pub fn run(args: &args::Args, cmd: &args::VerifyReproducibleCommand) -> BakeryResult<()> {
+    let source_date_epoch = cmd
+        .source_date
+        .unwrap_or_else(jiff::Timestamp::now)
+        .as_second() as u64;
+    let mut projects = Vec::new();
+    for name in ["a", "b"] {
+        let build_dir = Path::new(".rugix/reproducibility").join(name);
+        let project = project_loader(args)?.with_build_dir(&build_dir).load()?;
+        std::fs::remove_dir_all(project.dir().join(&build_dir)).ok();
+        projects.push(project);
+    }
+    let comparisons =
+        verify_reproducible(&projects[0], &projects[1], &cmd.system, source_date_epoch)?;
+    let differing = rugix_cli::suspend(|| {
+        let mut differing = 0;
+        for comparison in &comparisons {
+            match &comparison.outcome {
+                Outcome::Identical => {
+                    eprintln!("identical: {}", comparison.name);
+                    continue;
+                }
+                Outcome::DiffersAt(offset) => {
+                    eprintln!("DIFFERS:   {} (at byte {offset})", comparison.name);
+                }
+                Outcome::Differs(changes) => {
+                    eprintln!(
+                        "DIFFERS:   {} ({} files differ)",
+                        comparison.name,
+                        changes.len()
+                    );
+                    for change in changes.iter().take(MAX_REPORTED_CHANGES) {
+                        let kind = if change.change.is_metadata_only() {
+                            "metadata"
+                        } else {
+                            "content"
+                        };
+                        let details = match &change.change {
+                            Change::Added { .. } => "only in second build".to_owned(),
+                            Change::Removed { .. } => "only in first build".to_owned(),
+                            Change::Modified { changed, .. } => changed.join(", "),
+                        };
+                        eprintln!("    [{kind}] {} ({details})", change.path.display());
+                    }
+                    if changes.len() > MAX_REPORTED_CHANGES {
+                        eprintln!("    ... and {} more", changes.len() - MAX_REPORTED_CHANGES);
+                    }
+                }
+            }
+            differing += 1;
+        }
+        differing
+    });
+    if differing > 0 {
+        bail!(
+            "{differing} artifacts of system `{}` are not reproducible",
+            cmd.system
+        );
+    }
+    info!("system `{}` is reproducible", cmd.system);
+    Ok(())
+}
@@ -26,6 +26,10 @@ pub fn run(args: args::Args) -> BakeryResult<()> {
         args::Command::Shell => cmds::run_shell::run(),
         args::Command::Bundler(cmd) => cmds::run_bundler::run(cmd),
         args::Command::Cache(cmd) => cmds::run_cache::run(&args, cmd),
+        args::Command::Debug(cmd) => cmds::run_debug::run(&args, cmd),
+        args::Command::Report(cmd) => cmds::run_report::run(&args, cmd),
+        args::Command::Diff(cmd) => cmds::run_diff::run(&args, cmd),
+        args::Command::VerifyReproducible(cmd) => cmds::run_verify_reproducible::run(&args, cmd),
     }
 }
 
@@ -34,8 +38,31 @@ fn current_dir() -> BakeryResult<PathBuf> {
     std::env::current_dir().whatever("unable to get current working directory")
 }
 
+/// Format the given size in bytes for humans.
+fn format_size(size: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+    let mut value = size as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+/// Format the given change in size in bytes for humans.
+fn format_size_delta(delta: i64) -> String {
+    let sign = if delta < 0 { "-" } else { "+" };
+    format!("{sign}{}", format_size(delta.unsigned_abs()))
+}
+
 /// Load the project from the current working directory.
 fn load_project(args: &args::Args) -> BakeryResult<ProjectRef> {
+    project_loader(args)?.load()
+}
+
+/// Prepare loading the project from the current working directory.
+fn project_loader(args: &args::Args) -> BakeryResult<ProjectLoader> {
     let current_dir =
         std::env::current_dir().whatever("unable to get current working directory")?;
 
@@ -64,8 +91,8 @@ fn load_project(args: &args::Args) -> BakeryResult<ProjectRef> {
     }
     let project_identity = std::env::var("RUGIX_HOST_PROJECT_DIR")
         .unwrap_or_else(|_| current_dir.to_string_lossy().into_owned());
-    ProjectLoader::current_dir()?
+    Ok(ProjectLoader::current_dir()?
         .with_config_file(args.config.as_deref())
         .with_local_id(project_identity.as_bytes())
-        .load()
+        .with_lock_mode(args.lock_mode()))
 }
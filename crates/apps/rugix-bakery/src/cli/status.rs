@@ -1,7 +1,8 @@
 use std::collections::VecDeque;
+use std::sync::Mutex;
 
 use rugix_cli::style::Stylize;
-use rugix_cli::widgets::{Heading, Text, Widget};
+use rugix_cli::widgets::{Heading, ProgressBar, ProgressSpinner, Text, Widget};
 use rugix_cli::{StatusSegment, VisualHeight};
 
 #[derive(Debug)]
@@ -61,3 +62,64 @@ impl StatusSegment for CliLog {
             .draw(ctx);
     }
 }
+
+/// Progress of a download, possibly of unknown size.
+#[derive(Debug)]
+pub struct DownloadStatus {
+    state: Mutex<DownloadState>,
+    title: String,
+}
+
+impl DownloadStatus {
+    pub fn new(title: String) -> Self {
+        Self {
+            state: Mutex::default(),
+            title,
+        }
+    }
+
+    pub fn set_progress(&self, downloaded: u64, size: Option<u64>) {
+        let mut state = self.state.lock().unwrap();
+        state.downloaded = downloaded;
+        state.size = size;
+    }
+
+    pub fn set_message(&self, message: String) {
+        self.state.lock().unwrap().message = message;
+    }
+}
+
+#[derive(Debug, Default)]
+struct DownloadState {
+    downloaded: u64,
+    size: Option<u64>,
+    message: String,
+}
+
+impl StatusSegment for DownloadStatus {
+    fn draw(&self, ctx: &mut rugix_cli::DrawCtx) {
+        Heading::new(&self.title).draw(ctx);
+        let state = self.state.lock().unwrap();
+        match state.size {
+            Some(size) => {
+                ProgressBar::new(state.downloaded, size).draw(ctx);
+            }
+            None => {
+                ProgressSpinner::new().draw(ctx);
+                write!(
+                    ctx,
+                    " {:.1} MiB",
+                    state.downloaded as f64 / (1024.0 * 1024.0)
+                );
+                ctx.start_line();
+            }
+        }
+        if !state.message.is_empty() {
+            Text::new(std::iter::once(&state.message))
+                .prefix("> ")
+                .styled()
+                .dark_gray()
+                .draw(ctx);
+        }
+    }
+}
@@ -13,8 +13,9 @@ use reportify::{whatever, ResultExt};
 
 use crate::BakeryResult;
 
+use self::layers::LayerRecipe;
 use self::recipes::ParameterValue;
-use self::systems::{Architecture, SystemConfig};
+use self::systems::{Architecture, SystemConfig, Target};
 
 mod generated;
 // Re-export the generated data structures.
@@ -63,6 +64,35 @@ impl FromStr for Architecture {
     }
 }
 
+impl Target {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Target::GenericGrubEfi => "generic-grub-efi",
+            Target::RpiTryboot => "rpi-tryboot",
+            Target::RpiUboot => "rpi-uboot",
+            Target::Unknown => "unknown",
+        }
+    }
+}
+
+impl LayerRecipe {
+    /// Name of the recipe.
+    pub fn name(&self) -> &str {
+        match self {
+            LayerRecipe::Name(name) => name,
+            LayerRecipe::Conditional(recipe) => &recipe.name,
+        }
+    }
+
+    /// Condition under which the recipe is included, if any.
+    pub fn when(&self) -> Option<&str> {
+        match self {
+            LayerRecipe::Name(_) => None,
+            LayerRecipe::Conditional(recipe) => recipe.when.as_deref(),
+        }
+    }
+}
+
 impl fmt::Display for ParameterValue {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -70,6 +100,15 @@ impl fmt::Display for ParameterValue {
             ParameterValue::Boolean(value) => value.fmt(f),
             ParameterValue::Integer(value) => value.fmt(f),
             ParameterValue::Float(value) => value.fmt(f),
+            ParameterValue::List(items) => {
+                for (idx, item) in items.iter().enumerate() {
+                    if idx > 0 {
+                        f.write_str("\n")?;
+                    }
+                    item.fmt(f)?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -2009,14 +2009,20 @@ pub mod layers {
         pub name: ::std::option::Option<::std::string::String>,
         #[doc = "Description of the layer.\n"]
         pub description: ::std::option::Option<::std::string::String>,
-        #[doc = "URL for importing the layer.\n"]
+        #[doc = "URL for importing the layer.\n\nSupports disk images and root filesystem tarballs, local root filesystem\ndirectories (`file://`), and OCI image layouts (`oci:`) and archives\n(`oci-archive:`). The fragment of OCI URLs selects an image by its reference name\nor digest, e.g., `oci:images/vendor#v1.0` or `oci:images/vendor#sha256:<hex>`.\n"]
         pub url: ::std::option::Option<::std::string::String>,
+        #[doc = "Expected digest of the image referenced by `url` (`sha256:<hex>` or `sha512:<hex>`).\n\nNot supported for OCI images, which are pinned with a digest reference in `url`.\n"]
+        pub digest: ::std::option::Option<::std::string::String>,
+        #[doc = "Mirrors from which the image referenced by `url` can be downloaded.\n\nMirrors are tried in order if the image cannot be downloaded from `url`.\n"]
+        pub mirrors: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+        #[doc = "Partitions to import if `url` refers to a disk image.\n\nDefaults to importing the first partition as `boot` root and the second partition\nas `system` root.\n"]
+        pub partitions: ::std::option::Option<::std::vec::Vec<ImportPartition>>,
         #[doc = "Parent layer.\n"]
         pub parent: ::std::option::Option<::std::string::String>,
         #[doc = "Indicates whether the layer is a root layer.\n"]
         pub root: ::std::option::Option<bool>,
         #[doc = "Recipes to build the layer with.\n"]
-        pub recipes: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+        pub recipes: ::std::option::Option<::std::vec::Vec<LayerRecipe>>,
         #[doc = "Recipes to specifically exclude.\n"]
         pub exclude: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
         #[doc = "Recipe parameters.\n"]
@@ -2034,6 +2040,9 @@ pub mod layers {
                 name: ::std::default::Default::default(),
                 description: ::std::default::Default::default(),
                 url: ::std::default::Default::default(),
+                digest: ::std::default::Default::default(),
+                mirrors: ::std::default::Default::default(),
+                partitions: ::std::default::Default::default(),
                 parent: ::std::default::Default::default(),
                 root: ::std::default::Default::default(),
                 recipes: ::std::default::Default::default(),
@@ -2080,6 +2089,51 @@ pub mod layers {
             self.url = url;
             self
         }
+        #[doc = "Sets the value of `digest`."]
+        pub fn set_digest(
+            &mut self,
+            digest: ::std::option::Option<::std::string::String>,
+        ) -> &mut Self {
+            self.digest = digest;
+            self
+        }
+        #[doc = "Sets the value of `digest`."]
+        pub fn with_digest(mut self, digest: ::std::option::Option<::std::string::String>) -> Self {
+            self.digest = digest;
+            self
+        }
+        #[doc = "Sets the value of `mirrors`."]
+        pub fn set_mirrors(
+            &mut self,
+            mirrors: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+        ) -> &mut Self {
+            self.mirrors = mirrors;
+            self
+        }
+        #[doc = "Sets the value of `mirrors`."]
+        pub fn with_mirrors(
+            mut self,
+            mirrors: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+        ) -> Self {
+            self.mirrors = mirrors;
+            self
+        }
+        #[doc = "Sets the value of `partitions`."]
+        pub fn set_partitions(
+            &mut self,
+            partitions: ::std::option::Option<::std::vec::Vec<ImportPartition>>,
+        ) -> &mut Self {
+            self.partitions = partitions;
+            self
+        }
+        #[doc = "Sets the value of `partitions`."]
+        pub fn with_partitions(
+            mut self,
+            partitions: ::std::option::Option<::std::vec::Vec<ImportPartition>>,
+        ) -> Self {
+            self.partitions = partitions;
+            self
+        }
         #[doc = "Sets the value of `parent`."]
         pub fn set_parent(
             &mut self,
@@ -2106,7 +2160,7 @@ pub mod layers {
         #[doc = "Sets the value of `recipes`."]
         pub fn set_recipes(
             &mut self,
-            recipes: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+            recipes: ::std::option::Option<::std::vec::Vec<LayerRecipe>>,
         ) -> &mut Self {
             self.recipes = recipes;
             self
@@ -2114,7 +2168,7 @@ pub mod layers {
         #[doc = "Sets the value of `recipes`."]
         pub fn with_recipes(
             mut self,
-            recipes: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+            recipes: ::std::option::Option<::std::vec::Vec<LayerRecipe>>,
         ) -> Self {
             self.recipes = recipes;
             self
@@ -2180,7 +2234,7 @@ pub mod layers {
             __serializer: __S,
         ) -> ::std::result::Result<__S::Ok, __S::Error> {
             let mut __record =
-                __sidex_serde::ser::RecordSerializer::new(__serializer, "LayerConfig", 8usize)?;
+                __sidex_serde::ser::RecordSerializer::new(__serializer, "LayerConfig", 11usize)?;
             __record
                 .serialize_optional_field("name", ::core::option::Option::as_ref(&self.name))?;
             __record.serialize_optional_field(
@@ -2188,6 +2242,16 @@ pub mod layers {
                 ::core::option::Option::as_ref(&self.description),
             )?;
             __record.serialize_optional_field("url", ::core::option::Option::as_ref(&self.url))?;
+            __record
+                .serialize_optional_field("digest", ::core::option::Option::as_ref(&self.digest))?;
+            __record.serialize_optional_field(
+                "mirrors",
+                ::core::option::Option::as_ref(&self.mirrors),
+            )?;
+            __record.serialize_optional_field(
+                "partitions",
+                ::core::option::Option::as_ref(&self.partitions),
+            )?;
             __record
                 .serialize_optional_field("parent", ::core::option::Option::as_ref(&self.parent))?;
             __record
@@ -2239,7 +2303,10 @@ pub mod layers {
                         ::core::option::Option::Some(__value) => __value,
                         ::core::option::Option::None => {
                             return ::core::result::Result::Err(
-                                __serde::de::Error::invalid_length(0usize, &"record with 8 fields"),
+                                __serde::de::Error::invalid_length(
+                                    0usize,
+                                    &"record with 11 fields",
+                                ),
                             );
                         }
                     };
@@ -2250,7 +2317,10 @@ pub mod layers {
                         ::core::option::Option::Some(__value) => __value,
                         ::core::option::Option::None => {
                             return ::core::result::Result::Err(
-                                __serde::de::Error::invalid_length(1usize, &"record with 8 fields"),
+                                __serde::de::Error::invalid_length(
+                                    1usize,
+                                    &"record with 11 fields",
+                                ),
                             );
                         }
                     };
@@ -2261,7 +2331,10 @@ pub mod layers {
                         ::core::option::Option::Some(__value) => __value,
                         ::core::option::Option::None => {
                             return ::core::result::Result::Err(
-                                __serde::de::Error::invalid_length(2usize, &"record with 8 fields"),
+                                __serde::de::Error::invalid_length(
+                                    2usize,
+                                    &"record with 11 fields",
+                                ),
                             );
                         }
                     };
@@ -2272,44 +2345,98 @@ pub mod layers {
                         ::core::option::Option::Some(__value) => __value,
                         ::core::option::Option::None => {
                             return ::core::result::Result::Err(
-                                __serde::de::Error::invalid_length(3usize, &"record with 8 fields"),
+                                __serde::de::Error::invalid_length(
+                                    3usize,
+                                    &"record with 11 fields",
+                                ),
                             );
                         }
                     };
                     let __field4 = match __serde::de::SeqAccess::next_element::<
-                        ::std::option::Option<bool>,
+                        ::std::option::Option<::std::vec::Vec<::std::string::String>>,
                     >(&mut __seq)?
                     {
                         ::core::option::Option::Some(__value) => __value,
                         ::core::option::Option::None => {
                             return ::core::result::Result::Err(
-                                __serde::de::Error::invalid_length(4usize, &"record with 8 fields"),
+                                __serde::de::Error::invalid_length(
+                                    4usize,
+                                    &"record with 11 fields",
+                                ),
                             );
                         }
                     };
                     let __field5 = match __serde::de::SeqAccess::next_element::<
-                        ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+                        ::std::option::Option<::std::vec::Vec<ImportPartition>>,
                     >(&mut __seq)?
                     {
                         ::core::option::Option::Some(__value) => __value,
                         ::core::option::Option::None => {
                             return ::core::result::Result::Err(
-                                __serde::de::Error::invalid_length(5usize, &"record with 8 fields"),
+                                __serde::de::Error::invalid_length(
+                                    5usize,
+                                    &"record with 11 fields",
+                                ),
                             );
                         }
                     };
                     let __field6 = match __serde::de::SeqAccess::next_element::<
-                        ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+                        ::std::option::Option<::std::string::String>,
                     >(&mut __seq)?
                     {
                         ::core::option::Option::Some(__value) => __value,
                         ::core::option::Option::None => {
                             return ::core::result::Result::Err(
-                                __serde::de::Error::invalid_length(6usize, &"record with 8 fields"),
+                                __serde::de::Error::invalid_length(
+                                    6usize,
+                                    &"record with 11 fields",
+                                ),
                             );
                         }
                     };
                     let __field7 = match __serde::de::SeqAccess::next_element::<
+                        ::std::option::Option<bool>,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(
+                                    7usize,
+                                    &"record with 11 fields",
+                                ),
+                            );
+                        }
+                    };
+                    let __field8 = match __serde::de::SeqAccess::next_element::<
+                        ::std::option::Option<::std::vec::Vec<LayerRecipe>>,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(
+                                    8usize,
+                                    &"record with 11 fields",
+                                ),
+                            );
+                        }
+                    };
+                    let __field9 = match __serde::de::SeqAccess::next_element::<
+                        ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(
+                                    9usize,
+                                    &"record with 11 fields",
+                                ),
+                            );
+                        }
+                    };
+                    let __field10 = match __serde::de::SeqAccess::next_element::<
                         ::std::option::Option<
                             ::std::collections::HashMap<
                                 ::std::string::String,
@@ -2324,7 +2451,10 @@ pub mod layers {
                         ::core::option::Option::Some(__value) => __value,
                         ::core::option::Option::None => {
                             return ::core::result::Result::Err(
-                                __serde::de::Error::invalid_length(7usize, &"record with 8 fields"),
+                                __serde::de::Error::invalid_length(
+                                    10usize,
+                                    &"record with 11 fields",
+                                ),
                             );
                         }
                     };
@@ -2332,11 +2462,14 @@ pub mod layers {
                         name: __field0,
                         description: __field1,
                         url: __field2,
-                        parent: __field3,
-                        root: __field4,
-                        recipes: __field5,
-                        exclude: __field6,
-                        parameters: __field7,
+                        digest: __field3,
+                        mirrors: __field4,
+                        partitions: __field5,
+                        parent: __field6,
+                        root: __field7,
+                        recipes: __field8,
+                        exclude: __field9,
+                        parameters: __field10,
                     })
                 }
                 #[inline]
@@ -2352,6 +2485,9 @@ pub mod layers {
                         "name",
                         "description",
                         "url",
+                        "digest",
+                        "mirrors",
+                        "partitions",
                         "parent",
                         "root",
                         "recipes",
@@ -2359,7 +2495,7 @@ pub mod layers {
                         "parameters",
                     ];
                     #[doc(hidden)]
-                    const __EXPECTING_IDENTIFIERS : & 'static str = "an identifier in [\"name\", \"description\", \"url\", \"parent\", \"root\", \"recipes\", \"exclude\", \"parameters\"]" ;
+                    const __EXPECTING_IDENTIFIERS : & 'static str = "an identifier in [\"name\", \"description\", \"url\", \"digest\", \"mirrors\", \"partitions\", \"parent\", \"root\", \"recipes\", \"exclude\", \"parameters\"]" ;
                     #[derive(:: core :: clone :: Clone, :: core :: marker :: Copy)]
                     #[doc(hidden)]
                     enum __Identifier {
@@ -2371,6 +2507,9 @@ pub mod layers {
                         __Identifier5,
                         __Identifier6,
                         __Identifier7,
+                        __Identifier8,
+                        __Identifier9,
+                        __Identifier10,
                         __Unknown,
                     }
                     #[doc(hidden)]
@@ -2399,6 +2538,9 @@ pub mod layers {
                                 5u64 => ::core::result::Result::Ok(__Identifier::__Identifier5),
                                 6u64 => ::core::result::Result::Ok(__Identifier::__Identifier6),
                                 7u64 => ::core::result::Result::Ok(__Identifier::__Identifier7),
+                                8u64 => ::core::result::Result::Ok(__Identifier::__Identifier8),
+                                9u64 => ::core::result::Result::Ok(__Identifier::__Identifier9),
+                                10u64 => ::core::result::Result::Ok(__Identifier::__Identifier10),
                                 _ => ::core::result::Result::Ok(__Identifier::__Unknown),
                             }
                         }
@@ -2415,16 +2557,23 @@ pub mod layers {
                                     ::core::result::Result::Ok(__Identifier::__Identifier1)
                                 }
                                 "url" => ::core::result::Result::Ok(__Identifier::__Identifier2),
-                                "parent" => ::core::result::Result::Ok(__Identifier::__Identifier3),
-                                "root" => ::core::result::Result::Ok(__Identifier::__Identifier4),
-                                "recipes" => {
+                                "digest" => ::core::result::Result::Ok(__Identifier::__Identifier3),
+                                "mirrors" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier4)
+                                }
+                                "partitions" => {
                                     ::core::result::Result::Ok(__Identifier::__Identifier5)
                                 }
+                                "parent" => ::core::result::Result::Ok(__Identifier::__Identifier6),
+                                "root" => ::core::result::Result::Ok(__Identifier::__Identifier7),
+                                "recipes" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier8)
+                                }
                                 "exclude" => {
-                                    ::core::result::Result::Ok(__Identifier::__Identifier6)
+                                    ::core::result::Result::Ok(__Identifier::__Identifier9)
                                 }
                                 "parameters" => {
-                                    ::core::result::Result::Ok(__Identifier::__Identifier7)
+                                    ::core::result::Result::Ok(__Identifier::__Identifier10)
                                 }
                                 _ => ::core::result::Result::Ok(__Identifier::__Unknown),
                             }
@@ -2442,18 +2591,27 @@ pub mod layers {
                                     ::core::result::Result::Ok(__Identifier::__Identifier1)
                                 }
                                 b"url" => ::core::result::Result::Ok(__Identifier::__Identifier2),
-                                b"parent" => {
+                                b"digest" => {
                                     ::core::result::Result::Ok(__Identifier::__Identifier3)
                                 }
-                                b"root" => ::core::result::Result::Ok(__Identifier::__Identifier4),
-                                b"recipes" => {
+                                b"mirrors" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier4)
+                                }
+                                b"partitions" => {
                                     ::core::result::Result::Ok(__Identifier::__Identifier5)
                                 }
-                                b"exclude" => {
+                                b"parent" => {
                                     ::core::result::Result::Ok(__Identifier::__Identifier6)
                                 }
+                                b"root" => ::core::result::Result::Ok(__Identifier::__Identifier7),
+                                b"recipes" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier8)
+                                }
+                                b"exclude" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier9)
+                                }
                                 b"parameters" => {
-                                    ::core::result::Result::Ok(__Identifier::__Identifier7)
+                                    ::core::result::Result::Ok(__Identifier::__Identifier10)
                                 }
                                 _ => ::core::result::Result::Ok(__Identifier::__Unknown),
                             }
@@ -2485,15 +2643,24 @@ pub mod layers {
                     let mut __field3: ::core::option::Option<
                         ::std::option::Option<::std::string::String>,
                     > = ::core::option::Option::None;
-                    let mut __field4: ::core::option::Option<::std::option::Option<bool>> =
-                        ::core::option::Option::None;
-                    let mut __field5: ::core::option::Option<
+                    let mut __field4: ::core::option::Option<
                         ::std::option::Option<::std::vec::Vec<::std::string::String>>,
                     > = ::core::option::Option::None;
+                    let mut __field5: ::core::option::Option<
+                        ::std::option::Option<::std::vec::Vec<ImportPartition>>,
+                    > = ::core::option::Option::None;
                     let mut __field6: ::core::option::Option<
+                        ::std::option::Option<::std::string::String>,
+                    > = ::core::option::Option::None;
+                    let mut __field7: ::core::option::Option<::std::option::Option<bool>> =
+                        ::core::option::Option::None;
+                    let mut __field8: ::core::option::Option<
+                        ::std::option::Option<::std::vec::Vec<LayerRecipe>>,
+                    > = ::core::option::Option::None;
+                    let mut __field9: ::core::option::Option<
                         ::std::option::Option<::std::vec::Vec<::std::string::String>>,
                     > = ::core::option::Option::None;
-                    let mut __field7: ::core::option::Option<
+                    let mut __field10: ::core::option::Option<
                         ::std::option::Option<
                             ::std::collections::HashMap<
                                 ::std::string::String,
@@ -2550,7 +2717,7 @@ pub mod layers {
                                 if ::core::option::Option::is_some(&__field3) {
                                     return ::core::result::Result::Err(
                                         <__A::Error as __serde::de::Error>::duplicate_field(
-                                            "parent",
+                                            "digest",
                                         ),
                                     );
                                 }
@@ -2563,12 +2730,16 @@ pub mod layers {
                             __Identifier::__Identifier4 => {
                                 if ::core::option::Option::is_some(&__field4) {
                                     return ::core::result::Result::Err(
-                                        <__A::Error as __serde::de::Error>::duplicate_field("root"),
+                                        <__A::Error as __serde::de::Error>::duplicate_field(
+                                            "mirrors",
+                                        ),
                                     );
                                 }
                                 __field4 = ::core::option::Option::Some(
                                     __serde::de::MapAccess::next_value::<
-                                        ::std::option::Option<bool>,
+                                        ::std::option::Option<
+                                            ::std::vec::Vec<::std::string::String>,
+                                        >,
                                     >(&mut __map)?,
                                 );
                             }
@@ -2576,15 +2747,13 @@ pub mod layers {
                                 if ::core::option::Option::is_some(&__field5) {
                                     return ::core::result::Result::Err(
                                         <__A::Error as __serde::de::Error>::duplicate_field(
-                                            "recipes",
+                                            "partitions",
                                         ),
                                     );
                                 }
                                 __field5 = ::core::option::Option::Some(
                                     __serde::de::MapAccess::next_value::<
-                                        ::std::option::Option<
-                                            ::std::vec::Vec<::std::string::String>,
-                                        >,
+                                        ::std::option::Option<::std::vec::Vec<ImportPartition>>,
                                     >(&mut __map)?,
                                 );
                             }
@@ -2592,11 +2761,51 @@ pub mod layers {
                                 if ::core::option::Option::is_some(&__field6) {
                                     return ::core::result::Result::Err(
                                         <__A::Error as __serde::de::Error>::duplicate_field(
-                                            "exclude",
+                                            "parent",
                                         ),
                                     );
                                 }
                                 __field6 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<
+                                        ::std::option::Option<::std::string::String>,
+                                    >(&mut __map)?,
+                                );
+                            }
+                            __Identifier::__Identifier7 => {
+                                if ::core::option::Option::is_some(&__field7) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field("root"),
+                                    );
+                                }
+                                __field7 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<
+                                        ::std::option::Option<bool>,
+                                    >(&mut __map)?,
+                                );
+                            }
+                            __Identifier::__Identifier8 => {
+                                if ::core::option::Option::is_some(&__field8) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field(
+                                            "recipes",
+                                        ),
+                                    );
+                                }
+                                __field8 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<
+                                        ::std::option::Option<::std::vec::Vec<LayerRecipe>>,
+                                    >(&mut __map)?,
+                                );
+                            }
+                            __Identifier::__Identifier9 => {
+                                if ::core::option::Option::is_some(&__field9) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field(
+                                            "exclude",
+                                        ),
+                                    );
+                                }
+                                __field9 = ::core::option::Option::Some(
                                     __serde::de::MapAccess::next_value::<
                                         ::std::option::Option<
                                             ::std::vec::Vec<::std::string::String>,
@@ -2604,15 +2813,15 @@ pub mod layers {
                                     >(&mut __map)?,
                                 );
                             }
-                            __Identifier::__Identifier7 => {
-                                if ::core::option::Option::is_some(&__field7) {
+                            __Identifier::__Identifier10 => {
+                                if ::core::option::Option::is_some(&__field10) {
                                     return ::core::result::Result::Err(
                                         <__A::Error as __serde::de::Error>::duplicate_field(
                                             "parameters",
                                         ),
                                     );
                                 }
-                                __field7 = ::core::option::Option::Some(
+                                __field10 = ::core::option::Option::Some(
                                     __serde::de::MapAccess::next_value::<
                                         ::std::option::Option<
                                             ::std::collections::HashMap<
@@ -2665,15 +2874,30 @@ pub mod layers {
                         ::core::option::Option::Some(__value) => __value,
                         ::core::option::Option::None => ::core::option::Option::None,
                     };
+                    let __field8 = match __field8 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    };
+                    let __field9 = match __field9 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    };
+                    let __field10 = match __field10 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    };
                     ::core::result::Result::Ok(LayerConfig {
                         name: __field0,
                         description: __field1,
                         url: __field2,
-                        parent: __field3,
-                        root: __field4,
-                        recipes: __field5,
-                        exclude: __field6,
-                        parameters: __field7,
+                        digest: __field3,
+                        mirrors: __field4,
+                        partitions: __field5,
+                        parent: __field6,
+                        root: __field7,
+                        recipes: __field8,
+                        exclude: __field9,
+                        parameters: __field10,
                     })
                 }
             }
@@ -2682,6 +2906,9 @@ pub mod layers {
                 "name",
                 "description",
                 "url",
+                "digest",
+                "mirrors",
+                "partitions",
                 "parent",
                 "root",
                 "recipes",
@@ -2698,106 +2925,264 @@ pub mod layers {
             )
         }
     }
-}
-pub mod projects {
-    #![doc = "Project configuration.\n"]
-    #[allow(unused)]
-    use :: serde as __serde;
-    #[allow(unused)]
-    use :: sidex_serde as __sidex_serde;
-    #[doc = "Project configuration.\n"]
+    #[doc = "Recipe to build a layer with.\n"]
     #[derive(Clone, Debug)]
-    pub struct ProjectConfig {
-        #[doc = "Repositories imported into the project.\n"]
-        pub repositories: ::std::option::Option<
-            ::std::collections::HashMap<::std::string::String, super::repositories::SourceConfig>,
-        >,
-        #[doc = "System declarations.\n"]
-        pub systems: ::std::option::Option<
-            ::std::collections::HashMap<::std::string::String, super::systems::SystemConfig>,
-        >,
-    }
-    impl ProjectConfig {
-        #[doc = "Creates a new [`ProjectConfig`]."]
-        pub fn new() -> Self {
-            Self {
-                repositories: ::std::default::Default::default(),
-                systems: ::std::default::Default::default(),
-            }
-        }
-        #[doc = "Sets the value of `repositories`."]
-        pub fn set_repositories(
-            &mut self,
-            repositories: ::std::option::Option<
-                ::std::collections::HashMap<
-                    ::std::string::String,
-                    super::repositories::SourceConfig,
-                >,
-            >,
-        ) -> &mut Self {
-            self.repositories = repositories;
-            self
-        }
-        #[doc = "Sets the value of `repositories`."]
-        pub fn with_repositories(
-            mut self,
-            repositories: ::std::option::Option<
-                ::std::collections::HashMap<
-                    ::std::string::String,
-                    super::repositories::SourceConfig,
-                >,
-            >,
-        ) -> Self {
-            self.repositories = repositories;
-            self
-        }
-        #[doc = "Sets the value of `systems`."]
-        pub fn set_systems(
-            &mut self,
-            systems: ::std::option::Option<
-                ::std::collections::HashMap<::std::string::String, super::systems::SystemConfig>,
-            >,
-        ) -> &mut Self {
-            self.systems = systems;
-            self
-        }
-        #[doc = "Sets the value of `systems`."]
-        pub fn with_systems(
-            mut self,
-            systems: ::std::option::Option<
-                ::std::collections::HashMap<::std::string::String, super::systems::SystemConfig>,
-            >,
-        ) -> Self {
-            self.systems = systems;
-            self
-        }
-    }
-    impl ::std::default::Default for ProjectConfig {
-        fn default() -> Self {
-            Self::new()
-        }
+    pub enum LayerRecipe {
+        #[doc = "Name of the recipe.\n"]
+        Name(::std::string::String),
+        #[doc = "Recipe which is only included if its condition holds.\n"]
+        Conditional(ConditionalRecipe),
     }
     #[automatically_derived]
-    impl __serde::Serialize for ProjectConfig {
+    impl __serde::Serialize for LayerRecipe {
         fn serialize<__S: __serde::Serializer>(
             &self,
             __serializer: __S,
         ) -> ::std::result::Result<__S::Ok, __S::Error> {
-            let mut __record =
-                __sidex_serde::ser::RecordSerializer::new(__serializer, "ProjectConfig", 2usize)?;
-            __record.serialize_optional_field(
-                "repositories",
-                ::core::option::Option::as_ref(&self.repositories),
-            )?;
-            __record.serialize_optional_field(
-                "systems",
-                ::core::option::Option::as_ref(&self.systems),
-            )?;
+            let __serializer =
+                __sidex_serde::ser::VariantSerializer::new(__serializer, "LayerRecipe");
+            match self {
+                Self::Name(__value) => {
+                    __serializer.serialize_implicitly_tagged("Name", 0u32, __value)
+                }
+                Self::Conditional(__value) => {
+                    __serializer.serialize_implicitly_tagged("Conditional", 1u32, __value)
+                }
+            }
+        }
+    }
+    #[automatically_derived]
+    impl<'de> __serde::Deserialize<'de> for LayerRecipe {
+        fn deserialize<__D: __serde::Deserializer<'de>>(
+            __deserializer: __D,
+        ) -> ::std::result::Result<Self, __D::Error> {
+            #[doc(hidden)]
+            const __IDENTIFIERS: &'static [&'static str] = &["Name", "Conditional"];
+            #[doc(hidden)]
+            const __EXPECTING_IDENTIFIERS: &'static str =
+                "an identifier in [\"Name\", \"Conditional\"]";
+            #[derive(:: core :: clone :: Clone, :: core :: marker :: Copy)]
+            #[doc(hidden)]
+            enum __Identifier {
+                __Identifier0,
+                __Identifier1,
+            }
+            #[doc(hidden)]
+            struct __IdentifierVisitor;
+            impl<'de> __serde::de::Visitor<'de> for __IdentifierVisitor {
+                type Value = __Identifier;
+                fn expecting(
+                    &self,
+                    __formatter: &mut ::core::fmt::Formatter,
+                ) -> ::core::fmt::Result {
+                    ::core::fmt::Formatter::write_str(__formatter, __EXPECTING_IDENTIFIERS)
+                }
+                fn visit_u64<__E>(self, __value: u64) -> ::core::result::Result<Self::Value, __E>
+                where
+                    __E: __serde::de::Error,
+                {
+                    match __value {
+                        0u64 => ::core::result::Result::Ok(__Identifier::__Identifier0),
+                        1u64 => ::core::result::Result::Ok(__Identifier::__Identifier1),
+                        __variant => {
+                            ::core::result::Result::Err(__serde::de::Error::invalid_value(
+                                __serde::de::Unexpected::Unsigned(__variant),
+                                &__EXPECTING_IDENTIFIERS,
+                            ))
+                        }
+                    }
+                }
+                fn visit_str<__E>(self, __value: &str) -> ::core::result::Result<Self::Value, __E>
+                where
+                    __E: __serde::de::Error,
+                {
+                    match __value {
+                        "Name" => ::core::result::Result::Ok(__Identifier::__Identifier0),
+                        "Conditional" => ::core::result::Result::Ok(__Identifier::__Identifier1),
+                        __variant => ::core::result::Result::Err(
+                            __serde::de::Error::unknown_variant(__variant, __IDENTIFIERS),
+                        ),
+                    }
+                }
+                fn visit_bytes<__E>(
+                    self,
+                    __value: &[u8],
+                ) -> ::core::result::Result<Self::Value, __E>
+                where
+                    __E: __serde::de::Error,
+                {
+                    match __value {
+                        b"Name" => ::core::result::Result::Ok(__Identifier::__Identifier0),
+                        b"Conditional" => ::core::result::Result::Ok(__Identifier::__Identifier1),
+                        __variant => {
+                            ::core::result::Result::Err(__serde::de::Error::invalid_value(
+                                __serde::de::Unexpected::Bytes(__variant),
+                                &__EXPECTING_IDENTIFIERS,
+                            ))
+                        }
+                    }
+                }
+            }
+            impl<'de> __serde::Deserialize<'de> for __Identifier {
+                #[inline]
+                fn deserialize<__D>(__deserializer: __D) -> ::core::result::Result<Self, __D::Error>
+                where
+                    __D: __serde::Deserializer<'de>,
+                {
+                    __serde::Deserializer::deserialize_identifier(
+                        __deserializer,
+                        __IdentifierVisitor,
+                    )
+                }
+            }
+            #[doc(hidden)]
+            const __VARIANTS: &'static [&'static str] = &["Name", "Conditional"];
+            if __serde::Deserializer::is_human_readable(&__deserializer) {
+                let __content =
+                    __sidex_serde::de::content::deserialize_into_content(__deserializer)?;
+                match __sidex_serde::de::content::deserialize_content_ref::<
+                    ::std::string::String,
+                    __D::Error,
+                >(&__content)
+                {
+                    Ok(__value) => return Ok(LayerRecipe::Name(__value)),
+                    Err(_) => {}
+                };
+                match __sidex_serde::de::content::deserialize_content_ref::<
+                    ConditionalRecipe,
+                    __D::Error,
+                >(&__content)
+                {
+                    Ok(__value) => return Ok(LayerRecipe::Conditional(__value)),
+                    Err(_) => {}
+                };
+                Err(<__D::Error as __serde::de::Error>::custom(
+                    "no matching variant found",
+                ))
+            } else {
+                #[doc(hidden)]
+                struct __Visitor {
+                    __phantom_vars: ::core::marker::PhantomData<fn(&())>,
+                }
+                impl<'de> __serde::de::Visitor<'de> for __Visitor {
+                    type Value = LayerRecipe;
+                    fn expecting(
+                        &self,
+                        __formatter: &mut ::core::fmt::Formatter,
+                    ) -> ::core::fmt::Result {
+                        ::core::fmt::Formatter::write_str(__formatter, "enum LayerRecipe")
+                    }
+                    #[inline]
+                    fn visit_str<__E>(
+                        self,
+                        __value: &str,
+                    ) -> ::core::result::Result<Self::Value, __E>
+                    where
+                        __E: __serde::de::Error,
+                    {
+                        let __identifier = __IdentifierVisitor.visit_str(__value)?;
+                        #[allow(unreachable_patterns)]
+                        match __identifier {
+                            _ => Err(__E::invalid_value(
+                                __serde::de::Unexpected::Str(__value),
+                                &self,
+                            )),
+                        }
+                    }
+                    #[inline]
+                    fn visit_enum<__A>(
+                        self,
+                        __data: __A,
+                    ) -> ::core::result::Result<Self::Value, __A::Error>
+                    where
+                        __A: __serde::de::EnumAccess<'de>,
+                    {
+                        match __serde::de::EnumAccess::variant::<__Identifier>(__data)? {
+                            (__Identifier::__Identifier0, __variant) => {
+                                let __value = __serde::de::VariantAccess::newtype_variant::<
+                                    ::std::string::String,
+                                >(__variant)?;
+                                ::core::result::Result::Ok(LayerRecipe::Name(__value))
+                            }
+                            (__Identifier::__Identifier1, __variant) => {
+                                let __value = __serde::de::VariantAccess::newtype_variant::<
+                                    ConditionalRecipe,
+                                >(__variant)?;
+                                ::core::result::Result::Ok(LayerRecipe::Conditional(__value))
+                            }
+                        }
+                    }
+                }
+                __serde::Deserializer::deserialize_enum(
+                    __deserializer,
+                    "LayerRecipe",
+                    __VARIANTS,
+                    __Visitor {
+                        __phantom_vars: ::core::marker::PhantomData,
+                    },
+                )
+            }
+        }
+    }
+    #[doc = "Recipe which is only included if its condition holds.\n"]
+    #[derive(Clone, Debug)]
+    pub struct ConditionalRecipe {
+        #[doc = "Name of the recipe.\n"]
+        pub name: ::std::string::String,
+        #[doc = "Condition under which the recipe is included.\n\nBaking fails if a recipe excluded by its condition is required by another\nrecipe of the layer.\n"]
+        pub when: ::std::option::Option<::std::string::String>,
+    }
+    impl ConditionalRecipe {
+        #[doc = "Creates a new [`ConditionalRecipe`]."]
+        pub fn new(name: ::std::string::String) -> Self {
+            Self {
+                name,
+                when: ::std::default::Default::default(),
+            }
+        }
+        #[doc = "Sets the value of `name`."]
+        pub fn set_name(&mut self, name: ::std::string::String) -> &mut Self {
+            self.name = name;
+            self
+        }
+        #[doc = "Sets the value of `name`."]
+        pub fn with_name(mut self, name: ::std::string::String) -> Self {
+            self.name = name;
+            self
+        }
+        #[doc = "Sets the value of `when`."]
+        pub fn set_when(
+            &mut self,
+            when: ::std::option::Option<::std::string::String>,
+        ) -> &mut Self {
+            self.when = when;
+            self
+        }
+        #[doc = "Sets the value of `when`."]
+        pub fn with_when(mut self, when: ::std::option::Option<::std::string::String>) -> Self {
+            self.when = when;
+            self
+        }
+    }
+    #[automatically_derived]
+    impl __serde::Serialize for ConditionalRecipe {
+        fn serialize<__S: __serde::Serializer>(
+            &self,
+            __serializer: __S,
+        ) -> ::std::result::Result<__S::Ok, __S::Error> {
+            let mut __record = __sidex_serde::ser::RecordSerializer::new(
+                __serializer,
+                "ConditionalRecipe",
+                2usize,
+            )?;
+            __record.serialize_field("name", &self.name)?;
+            __record
+                .serialize_optional_field("when", ::core::option::Option::as_ref(&self.when))?;
             __record.end()
         }
     }
     #[automatically_derived]
-    impl<'de> __serde::Deserialize<'de> for ProjectConfig {
+    impl<'de> __serde::Deserialize<'de> for ConditionalRecipe {
         fn deserialize<__D: __serde::Deserializer<'de>>(
             __deserializer: __D,
         ) -> ::std::result::Result<Self, __D::Error> {
@@ -2806,12 +3191,12 @@ pub mod projects {
                 __phantom_vars: ::core::marker::PhantomData<fn(&())>,
             }
             impl<'de> __serde::de::Visitor<'de> for __Visitor {
-                type Value = ProjectConfig;
+                type Value = ConditionalRecipe;
                 fn expecting(
                     &self,
                     __formatter: &mut ::core::fmt::Formatter,
                 ) -> ::core::fmt::Result {
-                    ::core::fmt::Formatter::write_str(__formatter, "record ProjectConfig")
+                    ::core::fmt::Formatter::write_str(__formatter, "record ConditionalRecipe")
                 }
                 #[inline]
                 fn visit_seq<__A>(
@@ -2822,12 +3207,7 @@ pub mod projects {
                     __A: __serde::de::SeqAccess<'de>,
                 {
                     let __field0 = match __serde::de::SeqAccess::next_element::<
-                        ::std::option::Option<
-                            ::std::collections::HashMap<
-                                ::std::string::String,
-                                super::repositories::SourceConfig,
-                            >,
-                        >,
+                        ::std::string::String,
                     >(&mut __seq)?
                     {
                         ::core::option::Option::Some(__value) => __value,
@@ -2838,12 +3218,7 @@ pub mod projects {
                         }
                     };
                     let __field1 = match __serde::de::SeqAccess::next_element::<
-                        ::std::option::Option<
-                            ::std::collections::HashMap<
-                                ::std::string::String,
-                                super::systems::SystemConfig,
-                            >,
-                        >,
+                        ::std::option::Option<::std::string::String>,
                     >(&mut __seq)?
                     {
                         ::core::option::Option::Some(__value) => __value,
@@ -2853,9 +3228,9 @@ pub mod projects {
                             );
                         }
                     };
-                    ::core::result::Result::Ok(ProjectConfig {
-                        repositories: __field0,
-                        systems: __field1,
+                    ::core::result::Result::Ok(ConditionalRecipe {
+                        name: __field0,
+                        when: __field1,
                     })
                 }
                 #[inline]
@@ -2867,10 +3242,10 @@ pub mod projects {
                     __A: __serde::de::MapAccess<'de>,
                 {
                     #[doc(hidden)]
-                    const __IDENTIFIERS: &'static [&'static str] = &["repositories", "systems"];
+                    const __IDENTIFIERS: &'static [&'static str] = &["name", "when"];
                     #[doc(hidden)]
                     const __EXPECTING_IDENTIFIERS: &'static str =
-                        "an identifier in [\"repositories\", \"systems\"]";
+                        "an identifier in [\"name\", \"when\"]";
                     #[derive(:: core :: clone :: Clone, :: core :: marker :: Copy)]
                     #[doc(hidden)]
                     enum __Identifier {
@@ -2909,12 +3284,8 @@ pub mod projects {
                             __E: __serde::de::Error,
                         {
                             match __value {
-                                "repositories" => {
-                                    ::core::result::Result::Ok(__Identifier::__Identifier0)
-                                }
-                                "systems" => {
-                                    ::core::result::Result::Ok(__Identifier::__Identifier1)
-                                }
+                                "name" => ::core::result::Result::Ok(__Identifier::__Identifier0),
+                                "when" => ::core::result::Result::Ok(__Identifier::__Identifier1),
                                 _ => ::core::result::Result::Ok(__Identifier::__Unknown),
                             }
                         }
@@ -2926,12 +3297,8 @@ pub mod projects {
                             __E: __serde::de::Error,
                         {
                             match __value {
-                                b"repositories" => {
-                                    ::core::result::Result::Ok(__Identifier::__Identifier0)
-                                }
-                                b"systems" => {
-                                    ::core::result::Result::Ok(__Identifier::__Identifier1)
-                                }
+                                b"name" => ::core::result::Result::Ok(__Identifier::__Identifier0),
+                                b"when" => ::core::result::Result::Ok(__Identifier::__Identifier1),
                                 _ => ::core::result::Result::Ok(__Identifier::__Unknown),
                             }
                         }
@@ -2950,21 +3317,10 @@ pub mod projects {
                             )
                         }
                     }
-                    let mut __field0: ::core::option::Option<
-                        ::std::option::Option<
-                            ::std::collections::HashMap<
-                                ::std::string::String,
-                                super::repositories::SourceConfig,
-                            >,
-                        >,
-                    > = ::core::option::Option::None;
+                    let mut __field0: ::core::option::Option<::std::string::String> =
+                        ::core::option::Option::None;
                     let mut __field1: ::core::option::Option<
-                        ::std::option::Option<
-                            ::std::collections::HashMap<
-                                ::std::string::String,
-                                super::systems::SystemConfig,
-                            >,
-                        >,
+                        ::std::option::Option<::std::string::String>,
                     > = ::core::option::Option::None;
                     while let ::core::option::Option::Some(__key) =
                         __serde::de::MapAccess::next_key::<__Identifier>(&mut __map)?
@@ -2973,38 +3329,24 @@ pub mod projects {
                             __Identifier::__Identifier0 => {
                                 if ::core::option::Option::is_some(&__field0) {
                                     return ::core::result::Result::Err(
-                                        <__A::Error as __serde::de::Error>::duplicate_field(
-                                            "repositories",
-                                        ),
+                                        <__A::Error as __serde::de::Error>::duplicate_field("name"),
                                     );
                                 }
                                 __field0 = ::core::option::Option::Some(
-                                    __serde::de::MapAccess::next_value::<
-                                        ::std::option::Option<
-                                            ::std::collections::HashMap<
-                                                ::std::string::String,
-                                                super::repositories::SourceConfig,
-                                            >,
-                                        >,
-                                    >(&mut __map)?,
+                                    __serde::de::MapAccess::next_value::<::std::string::String>(
+                                        &mut __map,
+                                    )?,
                                 );
                             }
                             __Identifier::__Identifier1 => {
                                 if ::core::option::Option::is_some(&__field1) {
                                     return ::core::result::Result::Err(
-                                        <__A::Error as __serde::de::Error>::duplicate_field(
-                                            "systems",
-                                        ),
+                                        <__A::Error as __serde::de::Error>::duplicate_field("when"),
                                     );
                                 }
                                 __field1 = ::core::option::Option::Some(
                                     __serde::de::MapAccess::next_value::<
-                                        ::std::option::Option<
-                                            ::std::collections::HashMap<
-                                                ::std::string::String,
-                                                super::systems::SystemConfig,
-                                            >,
-                                        >,
+                                        ::std::option::Option<::std::string::String>,
                                     >(&mut __map)?,
                                 );
                             }
@@ -3017,23 +3359,27 @@ pub mod projects {
                     }
                     let __field0 = match __field0 {
                         ::core::option::Option::Some(__value) => __value,
-                        ::core::option::Option::None => ::core::option::Option::None,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                <__A::Error as __serde::de::Error>::missing_field("name"),
+                            );
+                        }
                     };
                     let __field1 = match __field1 {
                         ::core::option::Option::Some(__value) => __value,
                         ::core::option::Option::None => ::core::option::Option::None,
                     };
-                    ::core::result::Result::Ok(ProjectConfig {
-                        repositories: __field0,
-                        systems: __field1,
+                    ::core::result::Result::Ok(ConditionalRecipe {
+                        name: __field0,
+                        when: __field1,
                     })
                 }
             }
             #[doc(hidden)]
-            const __FIELDS: &'static [&'static str] = &["repositories", "systems"];
+            const __FIELDS: &'static [&'static str] = &["name", "when"];
             __serde::Deserializer::deserialize_struct(
                 __deserializer,
-                "ProjectConfig",
+                "ConditionalRecipe",
                 __FIELDS,
                 __Visitor {
                     __phantom_vars: ::core::marker::PhantomData,
@@ -3041,133 +3387,115 @@ pub mod projects {
             )
         }
     }
-}
-pub mod recipes {
-    #![doc = "Recipe configuration.\n"]
-    #[allow(unused)]
-    use :: serde as __serde;
-    #[allow(unused)]
-    use :: sidex_serde as __sidex_serde;
-    #[doc = "Recipe configuration.\n"]
+    #[doc = "Partition of a disk image to import.\n\nExactly one of `number`, `label`, and `type` must be given to select the partition.\n"]
     #[derive(Clone, Debug)]
-    pub struct RecipeConfig {
-        #[doc = "Description of the recipe.\n"]
-        pub description: ::std::option::Option<::std::string::String>,
-        #[doc = "Priority of the recipe.\n"]
-        pub priority: ::std::option::Option<i64>,
-        #[doc = "Dependencies of the recipe.\n"]
-        pub dependencies: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
-        #[doc = "Parameter definitions of the recipe.\n"]
-        pub parameters:
-            ::std::option::Option<::std::collections::HashMap<::std::string::String, ParameterDef>>,
+    pub struct ImportPartition {
+        #[doc = "Number of the partition.\n"]
+        pub number: ::std::option::Option<u32>,
+        #[doc = "GPT label of the partition.\n"]
+        pub label: ::std::option::Option<::std::string::String>,
+        #[doc = "GPT type GUID of the partition.\n"]
+        pub ty: ::std::option::Option<::std::string::String>,
+        #[doc = "Root to import the partition into (defaults to `system`).\n"]
+        pub root: ::std::option::Option<ImportRoot>,
+        #[doc = "Path within the root to import the partition to, e.g., `/boot/firmware`.\n"]
+        pub path: ::std::option::Option<::std::string::String>,
     }
-    impl RecipeConfig {
-        #[doc = "Creates a new [`RecipeConfig`]."]
+    impl ImportPartition {
+        #[doc = "Creates a new [`ImportPartition`]."]
         pub fn new() -> Self {
             Self {
-                description: ::std::default::Default::default(),
-                priority: ::std::default::Default::default(),
-                dependencies: ::std::default::Default::default(),
-                parameters: ::std::default::Default::default(),
+                number: ::std::default::Default::default(),
+                label: ::std::default::Default::default(),
+                ty: ::std::default::Default::default(),
+                root: ::std::default::Default::default(),
+                path: ::std::default::Default::default(),
             }
         }
-        #[doc = "Sets the value of `description`."]
-        pub fn set_description(
+        #[doc = "Sets the value of `number`."]
+        pub fn set_number(&mut self, number: ::std::option::Option<u32>) -> &mut Self {
+            self.number = number;
+            self
+        }
+        #[doc = "Sets the value of `number`."]
+        pub fn with_number(mut self, number: ::std::option::Option<u32>) -> Self {
+            self.number = number;
+            self
+        }
+        #[doc = "Sets the value of `label`."]
+        pub fn set_label(
             &mut self,
-            description: ::std::option::Option<::std::string::String>,
+            label: ::std::option::Option<::std::string::String>,
         ) -> &mut Self {
-            self.description = description;
+            self.label = label;
             self
         }
-        #[doc = "Sets the value of `description`."]
-        pub fn with_description(
-            mut self,
-            description: ::std::option::Option<::std::string::String>,
-        ) -> Self {
-            self.description = description;
+        #[doc = "Sets the value of `label`."]
+        pub fn with_label(mut self, label: ::std::option::Option<::std::string::String>) -> Self {
+            self.label = label;
             self
         }
-        #[doc = "Sets the value of `priority`."]
-        pub fn set_priority(&mut self, priority: ::std::option::Option<i64>) -> &mut Self {
-            self.priority = priority;
+        #[doc = "Sets the value of `ty`."]
+        pub fn set_ty(&mut self, ty: ::std::option::Option<::std::string::String>) -> &mut Self {
+            self.ty = ty;
             self
         }
-        #[doc = "Sets the value of `priority`."]
-        pub fn with_priority(mut self, priority: ::std::option::Option<i64>) -> Self {
-            self.priority = priority;
+        #[doc = "Sets the value of `ty`."]
+        pub fn with_ty(mut self, ty: ::std::option::Option<::std::string::String>) -> Self {
+            self.ty = ty;
             self
         }
-        #[doc = "Sets the value of `dependencies`."]
-        pub fn set_dependencies(
-            &mut self,
-            dependencies: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
-        ) -> &mut Self {
-            self.dependencies = dependencies;
+        #[doc = "Sets the value of `root`."]
+        pub fn set_root(&mut self, root: ::std::option::Option<ImportRoot>) -> &mut Self {
+            self.root = root;
             self
         }
-        #[doc = "Sets the value of `dependencies`."]
-        pub fn with_dependencies(
-            mut self,
-            dependencies: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
-        ) -> Self {
-            self.dependencies = dependencies;
+        #[doc = "Sets the value of `root`."]
+        pub fn with_root(mut self, root: ::std::option::Option<ImportRoot>) -> Self {
+            self.root = root;
             self
         }
-        #[doc = "Sets the value of `parameters`."]
-        pub fn set_parameters(
+        #[doc = "Sets the value of `path`."]
+        pub fn set_path(
             &mut self,
-            parameters: ::std::option::Option<
-                ::std::collections::HashMap<::std::string::String, ParameterDef>,
-            >,
+            path: ::std::option::Option<::std::string::String>,
         ) -> &mut Self {
-            self.parameters = parameters;
+            self.path = path;
             self
         }
-        #[doc = "Sets the value of `parameters`."]
-        pub fn with_parameters(
-            mut self,
-            parameters: ::std::option::Option<
-                ::std::collections::HashMap<::std::string::String, ParameterDef>,
-            >,
-        ) -> Self {
-            self.parameters = parameters;
+        #[doc = "Sets the value of `path`."]
+        pub fn with_path(mut self, path: ::std::option::Option<::std::string::String>) -> Self {
+            self.path = path;
             self
         }
     }
-    impl ::std::default::Default for RecipeConfig {
+    impl ::std::default::Default for ImportPartition {
         fn default() -> Self {
             Self::new()
         }
     }
     #[automatically_derived]
-    impl __serde::Serialize for RecipeConfig {
+    impl __serde::Serialize for ImportPartition {
         fn serialize<__S: __serde::Serializer>(
             &self,
             __serializer: __S,
         ) -> ::std::result::Result<__S::Ok, __S::Error> {
             let mut __record =
-                __sidex_serde::ser::RecordSerializer::new(__serializer, "RecipeConfig", 4usize)?;
-            __record.serialize_optional_field(
-                "description",
-                ::core::option::Option::as_ref(&self.description),
-            )?;
-            __record.serialize_optional_field(
-                "priority",
-                ::core::option::Option::as_ref(&self.priority),
-            )?;
-            __record.serialize_optional_field(
-                "dependencies",
-                ::core::option::Option::as_ref(&self.dependencies),
-            )?;
-            __record.serialize_optional_field(
-                "parameters",
-                ::core::option::Option::as_ref(&self.parameters),
-            )?;
+                __sidex_serde::ser::RecordSerializer::new(__serializer, "ImportPartition", 5usize)?;
+            __record
+                .serialize_optional_field("number", ::core::option::Option::as_ref(&self.number))?;
+            __record
+                .serialize_optional_field("label", ::core::option::Option::as_ref(&self.label))?;
+            __record.serialize_optional_field("type", ::core::option::Option::as_ref(&self.ty))?;
+            __record
+                .serialize_optional_field("root", ::core::option::Option::as_ref(&self.root))?;
+            __record
+                .serialize_optional_field("path", ::core::option::Option::as_ref(&self.path))?;
             __record.end()
         }
     }
     #[automatically_derived]
-    impl<'de> __serde::Deserialize<'de> for RecipeConfig {
+    impl<'de> __serde::Deserialize<'de> for ImportPartition {
         fn deserialize<__D: __serde::Deserializer<'de>>(
             __deserializer: __D,
         ) -> ::std::result::Result<Self, __D::Error> {
@@ -3176,12 +3504,12 @@ pub mod recipes {
                 __phantom_vars: ::core::marker::PhantomData<fn(&())>,
             }
             impl<'de> __serde::de::Visitor<'de> for __Visitor {
-                type Value = RecipeConfig;
+                type Value = ImportPartition;
                 fn expecting(
                     &self,
                     __formatter: &mut ::core::fmt::Formatter,
                 ) -> ::core::fmt::Result {
-                    ::core::fmt::Formatter::write_str(__formatter, "record RecipeConfig")
+                    ::core::fmt::Formatter::write_str(__formatter, "record ImportPartition")
                 }
                 #[inline]
                 fn visit_seq<__A>(
@@ -3192,56 +3520,66 @@ pub mod recipes {
                     __A: __serde::de::SeqAccess<'de>,
                 {
                     let __field0 = match __serde::de::SeqAccess::next_element::<
-                        ::std::option::Option<::std::string::String>,
+                        ::std::option::Option<u32>,
                     >(&mut __seq)?
                     {
                         ::core::option::Option::Some(__value) => __value,
                         ::core::option::Option::None => {
                             return ::core::result::Result::Err(
-                                __serde::de::Error::invalid_length(0usize, &"record with 4 fields"),
+                                __serde::de::Error::invalid_length(0usize, &"record with 5 fields"),
                             );
                         }
                     };
                     let __field1 = match __serde::de::SeqAccess::next_element::<
-                        ::std::option::Option<i64>,
+                        ::std::option::Option<::std::string::String>,
                     >(&mut __seq)?
                     {
                         ::core::option::Option::Some(__value) => __value,
                         ::core::option::Option::None => {
                             return ::core::result::Result::Err(
-                                __serde::de::Error::invalid_length(1usize, &"record with 4 fields"),
+                                __serde::de::Error::invalid_length(1usize, &"record with 5 fields"),
                             );
                         }
                     };
                     let __field2 = match __serde::de::SeqAccess::next_element::<
-                        ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+                        ::std::option::Option<::std::string::String>,
                     >(&mut __seq)?
                     {
                         ::core::option::Option::Some(__value) => __value,
                         ::core::option::Option::None => {
                             return ::core::result::Result::Err(
-                                __serde::de::Error::invalid_length(2usize, &"record with 4 fields"),
+                                __serde::de::Error::invalid_length(2usize, &"record with 5 fields"),
                             );
                         }
                     };
                     let __field3 = match __serde::de::SeqAccess::next_element::<
-                        ::std::option::Option<
-                            ::std::collections::HashMap<::std::string::String, ParameterDef>,
-                        >,
+                        ::std::option::Option<ImportRoot>,
                     >(&mut __seq)?
                     {
                         ::core::option::Option::Some(__value) => __value,
                         ::core::option::Option::None => {
                             return ::core::result::Result::Err(
-                                __serde::de::Error::invalid_length(3usize, &"record with 4 fields"),
+                                __serde::de::Error::invalid_length(3usize, &"record with 5 fields"),
                             );
                         }
                     };
-                    ::core::result::Result::Ok(RecipeConfig {
-                        description: __field0,
-                        priority: __field1,
-                        dependencies: __field2,
-                        parameters: __field3,
+                    let __field4 = match __serde::de::SeqAccess::next_element::<
+                        ::std::option::Option<::std::string::String>,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(4usize, &"record with 5 fields"),
+                            );
+                        }
+                    };
+                    ::core::result::Result::Ok(ImportPartition {
+                        number: __field0,
+                        label: __field1,
+                        ty: __field2,
+                        root: __field3,
+                        path: __field4,
                     })
                 }
                 #[inline]
@@ -3254,9 +3592,10 @@ pub mod recipes {
                 {
                     #[doc(hidden)]
                     const __IDENTIFIERS: &'static [&'static str] =
-                        &["description", "priority", "dependencies", "parameters"];
+                        &["number", "label", "type", "root", "path"];
                     #[doc(hidden)]
-                    const __EXPECTING_IDENTIFIERS : & 'static str = "an identifier in [\"description\", \"priority\", \"dependencies\", \"parameters\"]" ;
+                    const __EXPECTING_IDENTIFIERS: &'static str =
+                        "an identifier in [\"number\", \"label\", \"type\", \"root\", \"path\"]";
                     #[derive(:: core :: clone :: Clone, :: core :: marker :: Copy)]
                     #[doc(hidden)]
                     enum __Identifier {
@@ -3264,6 +3603,7 @@ pub mod recipes {
                         __Identifier1,
                         __Identifier2,
                         __Identifier3,
+                        __Identifier4,
                         __Unknown,
                     }
                     #[doc(hidden)]
@@ -3288,6 +3628,7 @@ pub mod recipes {
                                 1u64 => ::core::result::Result::Ok(__Identifier::__Identifier1),
                                 2u64 => ::core::result::Result::Ok(__Identifier::__Identifier2),
                                 3u64 => ::core::result::Result::Ok(__Identifier::__Identifier3),
+                                4u64 => ::core::result::Result::Ok(__Identifier::__Identifier4),
                                 _ => ::core::result::Result::Ok(__Identifier::__Unknown),
                             }
                         }
@@ -3299,18 +3640,11 @@ pub mod recipes {
                             __E: __serde::de::Error,
                         {
                             match __value {
-                                "description" => {
-                                    ::core::result::Result::Ok(__Identifier::__Identifier0)
-                                }
-                                "priority" => {
-                                    ::core::result::Result::Ok(__Identifier::__Identifier1)
-                                }
-                                "dependencies" => {
-                                    ::core::result::Result::Ok(__Identifier::__Identifier2)
-                                }
-                                "parameters" => {
-                                    ::core::result::Result::Ok(__Identifier::__Identifier3)
-                                }
+                                "number" => ::core::result::Result::Ok(__Identifier::__Identifier0),
+                                "label" => ::core::result::Result::Ok(__Identifier::__Identifier1),
+                                "type" => ::core::result::Result::Ok(__Identifier::__Identifier2),
+                                "root" => ::core::result::Result::Ok(__Identifier::__Identifier3),
+                                "path" => ::core::result::Result::Ok(__Identifier::__Identifier4),
                                 _ => ::core::result::Result::Ok(__Identifier::__Unknown),
                             }
                         }
@@ -3322,18 +3656,13 @@ pub mod recipes {
                             __E: __serde::de::Error,
                         {
                             match __value {
-                                b"description" => {
+                                b"number" => {
                                     ::core::result::Result::Ok(__Identifier::__Identifier0)
                                 }
-                                b"priority" => {
-                                    ::core::result::Result::Ok(__Identifier::__Identifier1)
-                                }
-                                b"dependencies" => {
-                                    ::core::result::Result::Ok(__Identifier::__Identifier2)
-                                }
-                                b"parameters" => {
-                                    ::core::result::Result::Ok(__Identifier::__Identifier3)
-                                }
+                                b"label" => ::core::result::Result::Ok(__Identifier::__Identifier1),
+                                b"type" => ::core::result::Result::Ok(__Identifier::__Identifier2),
+                                b"root" => ::core::result::Result::Ok(__Identifier::__Identifier3),
+                                b"path" => ::core::result::Result::Ok(__Identifier::__Identifier4),
                                 _ => ::core::result::Result::Ok(__Identifier::__Unknown),
                             }
                         }
@@ -3352,18 +3681,18 @@ pub mod recipes {
                             )
                         }
                     }
-                    let mut __field0: ::core::option::Option<
+                    let mut __field0: ::core::option::Option<::std::option::Option<u32>> =
+                        ::core::option::Option::None;
+                    let mut __field1: ::core::option::Option<
                         ::std::option::Option<::std::string::String>,
                     > = ::core::option::Option::None;
-                    let mut __field1: ::core::option::Option<::std::option::Option<i64>> =
-                        ::core::option::Option::None;
                     let mut __field2: ::core::option::Option<
-                        ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+                        ::std::option::Option<::std::string::String>,
                     > = ::core::option::Option::None;
-                    let mut __field3: ::core::option::Option<
-                        ::std::option::Option<
-                            ::std::collections::HashMap<::std::string::String, ParameterDef>,
-                        >,
+                    let mut __field3: ::core::option::Option<::std::option::Option<ImportRoot>> =
+                        ::core::option::Option::None;
+                    let mut __field4: ::core::option::Option<
+                        ::std::option::Option<::std::string::String>,
                     > = ::core::option::Option::None;
                     while let ::core::option::Option::Some(__key) =
                         __serde::de::MapAccess::next_key::<__Identifier>(&mut __map)?
@@ -3373,62 +3702,63 @@ pub mod recipes {
                                 if ::core::option::Option::is_some(&__field0) {
                                     return ::core::result::Result::Err(
                                         <__A::Error as __serde::de::Error>::duplicate_field(
-                                            "description",
+                                            "number",
                                         ),
                                     );
                                 }
                                 __field0 = ::core::option::Option::Some(
-                                    __serde::de::MapAccess::next_value::<
-                                        ::std::option::Option<::std::string::String>,
-                                    >(&mut __map)?,
+                                    __serde::de::MapAccess::next_value::<::std::option::Option<u32>>(
+                                        &mut __map,
+                                    )?,
                                 );
                             }
                             __Identifier::__Identifier1 => {
                                 if ::core::option::Option::is_some(&__field1) {
                                     return ::core::result::Result::Err(
                                         <__A::Error as __serde::de::Error>::duplicate_field(
-                                            "priority",
+                                            "label",
                                         ),
                                     );
                                 }
                                 __field1 = ::core::option::Option::Some(
-                                    __serde::de::MapAccess::next_value::<::std::option::Option<i64>>(
-                                        &mut __map,
-                                    )?,
+                                    __serde::de::MapAccess::next_value::<
+                                        ::std::option::Option<::std::string::String>,
+                                    >(&mut __map)?,
                                 );
                             }
                             __Identifier::__Identifier2 => {
                                 if ::core::option::Option::is_some(&__field2) {
                                     return ::core::result::Result::Err(
-                                        <__A::Error as __serde::de::Error>::duplicate_field(
-                                            "dependencies",
-                                        ),
+                                        <__A::Error as __serde::de::Error>::duplicate_field("type"),
                                     );
                                 }
                                 __field2 = ::core::option::Option::Some(
                                     __serde::de::MapAccess::next_value::<
-                                        ::std::option::Option<
-                                            ::std::vec::Vec<::std::string::String>,
-                                        >,
+                                        ::std::option::Option<::std::string::String>,
                                     >(&mut __map)?,
                                 );
                             }
                             __Identifier::__Identifier3 => {
                                 if ::core::option::Option::is_some(&__field3) {
                                     return ::core::result::Result::Err(
-                                        <__A::Error as __serde::de::Error>::duplicate_field(
-                                            "parameters",
-                                        ),
+                                        <__A::Error as __serde::de::Error>::duplicate_field("root"),
                                     );
                                 }
                                 __field3 = ::core::option::Option::Some(
                                     __serde::de::MapAccess::next_value::<
-                                        ::std::option::Option<
-                                            ::std::collections::HashMap<
-                                                ::std::string::String,
-                                                ParameterDef,
-                                            >,
-                                        >,
+                                        ::std::option::Option<ImportRoot>,
+                                    >(&mut __map)?,
+                                );
+                            }
+                            __Identifier::__Identifier4 => {
+                                if ::core::option::Option::is_some(&__field4) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field("path"),
+                                    );
+                                }
+                                __field4 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<
+                                        ::std::option::Option<::std::string::String>,
                                     >(&mut __map)?,
                                 );
                             }
@@ -3455,232 +3785,24 @@ pub mod recipes {
                         ::core::option::Option::Some(__value) => __value,
                         ::core::option::Option::None => ::core::option::Option::None,
                     };
-                    ::core::result::Result::Ok(RecipeConfig {
-                        description: __field0,
-                        priority: __field1,
-                        dependencies: __field2,
-                        parameters: __field3,
+                    let __field4 = match __field4 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    };
+                    ::core::result::Result::Ok(ImportPartition {
+                        number: __field0,
+                        label: __field1,
+                        ty: __field2,
+                        root: __field3,
+                        path: __field4,
                     })
                 }
             }
             #[doc(hidden)]
-            const __FIELDS: &'static [&'static str] =
-                &["description", "priority", "dependencies", "parameters"];
+            const __FIELDS: &'static [&'static str] = &["number", "label", "type", "root", "path"];
             __serde::Deserializer::deserialize_struct(
                 __deserializer,
-                "RecipeConfig",
-                __FIELDS,
-                __Visitor {
-                    __phantom_vars: ::core::marker::PhantomData,
-                },
-            )
-        }
-    }
-    #[doc = "Recipe parameter definition.\n"]
-    #[derive(Clone, Debug)]
-    pub struct ParameterDef {
-        #[doc = "Optional default value of the parameter.\n"]
-        pub default: ::std::option::Option<ParameterValue>,
-    }
-    impl ParameterDef {
-        #[doc = "Creates a new [`ParameterDef`]."]
-        pub fn new() -> Self {
-            Self {
-                default: ::std::default::Default::default(),
-            }
-        }
-        #[doc = "Sets the value of `default`."]
-        pub fn set_default(&mut self, default: ::std::option::Option<ParameterValue>) -> &mut Self {
-            self.default = default;
-            self
-        }
-        #[doc = "Sets the value of `default`."]
-        pub fn with_default(mut self, default: ::std::option::Option<ParameterValue>) -> Self {
-            self.default = default;
-            self
-        }
-    }
-    impl ::std::default::Default for ParameterDef {
-        fn default() -> Self {
-            Self::new()
-        }
-    }
-    #[automatically_derived]
-    impl __serde::Serialize for ParameterDef {
-        fn serialize<__S: __serde::Serializer>(
-            &self,
-            __serializer: __S,
-        ) -> ::std::result::Result<__S::Ok, __S::Error> {
-            let mut __record =
-                __sidex_serde::ser::RecordSerializer::new(__serializer, "ParameterDef", 1usize)?;
-            __record.serialize_optional_field(
-                "default",
-                ::core::option::Option::as_ref(&self.default),
-            )?;
-            __record.end()
-        }
-    }
-    #[automatically_derived]
-    impl<'de> __serde::Deserialize<'de> for ParameterDef {
-        fn deserialize<__D: __serde::Deserializer<'de>>(
-            __deserializer: __D,
-        ) -> ::std::result::Result<Self, __D::Error> {
-            #[doc(hidden)]
-            struct __Visitor {
-                __phantom_vars: ::core::marker::PhantomData<fn(&())>,
-            }
-            impl<'de> __serde::de::Visitor<'de> for __Visitor {
-                type Value = ParameterDef;
-                fn expecting(
-                    &self,
-                    __formatter: &mut ::core::fmt::Formatter,
-                ) -> ::core::fmt::Result {
-                    ::core::fmt::Formatter::write_str(__formatter, "record ParameterDef")
-                }
-                #[inline]
-                fn visit_seq<__A>(
-                    self,
-                    mut __seq: __A,
-                ) -> ::core::result::Result<Self::Value, __A::Error>
-                where
-                    __A: __serde::de::SeqAccess<'de>,
-                {
-                    let __field0 = match __serde::de::SeqAccess::next_element::<
-                        ::std::option::Option<ParameterValue>,
-                    >(&mut __seq)?
-                    {
-                        ::core::option::Option::Some(__value) => __value,
-                        ::core::option::Option::None => {
-                            return ::core::result::Result::Err(
-                                __serde::de::Error::invalid_length(0usize, &"record with 1 fields"),
-                            );
-                        }
-                    };
-                    ::core::result::Result::Ok(ParameterDef { default: __field0 })
-                }
-                #[inline]
-                fn visit_map<__A>(
-                    self,
-                    mut __map: __A,
-                ) -> ::core::result::Result<Self::Value, __A::Error>
-                where
-                    __A: __serde::de::MapAccess<'de>,
-                {
-                    #[doc(hidden)]
-                    const __IDENTIFIERS: &'static [&'static str] = &["default"];
-                    #[doc(hidden)]
-                    const __EXPECTING_IDENTIFIERS: &'static str = "an identifier in [\"default\"]";
-                    #[derive(:: core :: clone :: Clone, :: core :: marker :: Copy)]
-                    #[doc(hidden)]
-                    enum __Identifier {
-                        __Identifier0,
-                        __Unknown,
-                    }
-                    #[doc(hidden)]
-                    struct __IdentifierVisitor;
-                    impl<'de> __serde::de::Visitor<'de> for __IdentifierVisitor {
-                        type Value = __Identifier;
-                        fn expecting(
-                            &self,
-                            __formatter: &mut ::core::fmt::Formatter,
-                        ) -> ::core::fmt::Result {
-                            ::core::fmt::Formatter::write_str(__formatter, __EXPECTING_IDENTIFIERS)
-                        }
-                        fn visit_u64<__E>(
-                            self,
-                            __value: u64,
-                        ) -> ::core::result::Result<Self::Value, __E>
-                        where
-                            __E: __serde::de::Error,
-                        {
-                            match __value {
-                                0u64 => ::core::result::Result::Ok(__Identifier::__Identifier0),
-                                _ => ::core::result::Result::Ok(__Identifier::__Unknown),
-                            }
-                        }
-                        fn visit_str<__E>(
-                            self,
-                            __value: &str,
-                        ) -> ::core::result::Result<Self::Value, __E>
-                        where
-                            __E: __serde::de::Error,
-                        {
-                            match __value {
-                                "default" => {
-                                    ::core::result::Result::Ok(__Identifier::__Identifier0)
-                                }
-                                _ => ::core::result::Result::Ok(__Identifier::__Unknown),
-                            }
-                        }
-                        fn visit_bytes<__E>(
-                            self,
-                            __value: &[u8],
-                        ) -> ::core::result::Result<Self::Value, __E>
-                        where
-                            __E: __serde::de::Error,
-                        {
-                            match __value {
-                                b"default" => {
-                                    ::core::result::Result::Ok(__Identifier::__Identifier0)
-                                }
-                                _ => ::core::result::Result::Ok(__Identifier::__Unknown),
-                            }
-                        }
-                    }
-                    impl<'de> __serde::Deserialize<'de> for __Identifier {
-                        #[inline]
-                        fn deserialize<__D>(
-                            __deserializer: __D,
-                        ) -> ::core::result::Result<Self, __D::Error>
-                        where
-                            __D: __serde::Deserializer<'de>,
-                        {
-                            __serde::Deserializer::deserialize_identifier(
-                                __deserializer,
-                                __IdentifierVisitor,
-                            )
-                        }
-                    }
-                    let mut __field0: ::core::option::Option<
-                        ::std::option::Option<ParameterValue>,
-                    > = ::core::option::Option::None;
-                    while let ::core::option::Option::Some(__key) =
-                        __serde::de::MapAccess::next_key::<__Identifier>(&mut __map)?
-                    {
-                        match __key {
-                            __Identifier::__Identifier0 => {
-                                if ::core::option::Option::is_some(&__field0) {
-                                    return ::core::result::Result::Err(
-                                        <__A::Error as __serde::de::Error>::duplicate_field(
-                                            "default",
-                                        ),
-                                    );
-                                }
-                                __field0 = ::core::option::Option::Some(
-                                    __serde::de::MapAccess::next_value::<
-                                        ::std::option::Option<ParameterValue>,
-                                    >(&mut __map)?,
-                                );
-                            }
-                            _ => {
-                                __serde::de::MapAccess::next_value::<__serde::de::IgnoredAny>(
-                                    &mut __map,
-                                )?;
-                            }
-                        }
-                    }
-                    let __field0 = match __field0 {
-                        ::core::option::Option::Some(__value) => __value,
-                        ::core::option::Option::None => ::core::option::Option::None,
-                    };
-                    ::core::result::Result::Ok(ParameterDef { default: __field0 })
-                }
-            }
-            #[doc(hidden)]
-            const __FIELDS: &'static [&'static str] = &["default"];
-            __serde::Deserializer::deserialize_struct(
-                __deserializer,
-                "ParameterDef",
+                "ImportPartition",
                 __FIELDS,
                 __Visitor {
                     __phantom_vars: ::core::marker::PhantomData,
@@ -3688,60 +3810,42 @@ pub mod recipes {
             )
         }
     }
-    #[doc = "Value of a parameter.\n"]
-    #[derive(Clone, Debug)]
-    pub enum ParameterValue {
-        #[doc = "String.\n"]
-        String(::std::string::String),
-        #[doc = "Boolean.\n"]
-        Boolean(bool),
-        #[doc = "Integer.\n"]
-        Integer(i64),
-        #[doc = "Float.\n"]
-        Float(f64),
+    #[doc = "Root of a layer.\n"]
+    #[derive(Clone, Debug, Copy, PartialEq, Eq, Hash)]
+    pub enum ImportRoot {
+        #[doc = "System root.\n"]
+        System,
+        #[doc = "Boot root.\n"]
+        Boot,
     }
     #[automatically_derived]
-    impl __serde::Serialize for ParameterValue {
+    impl __serde::Serialize for ImportRoot {
         fn serialize<__S: __serde::Serializer>(
             &self,
             __serializer: __S,
         ) -> ::std::result::Result<__S::Ok, __S::Error> {
             let __serializer =
-                __sidex_serde::ser::VariantSerializer::new(__serializer, "ParameterValue");
+                __sidex_serde::ser::VariantSerializer::new(__serializer, "ImportRoot");
             match self {
-                Self::String(__value) => {
-                    __serializer.serialize_implicitly_tagged("String", 0u32, __value)
-                }
-                Self::Boolean(__value) => {
-                    __serializer.serialize_implicitly_tagged("Boolean", 1u32, __value)
-                }
-                Self::Integer(__value) => {
-                    __serializer.serialize_implicitly_tagged("Integer", 2u32, __value)
-                }
-                Self::Float(__value) => {
-                    __serializer.serialize_implicitly_tagged("Float", 3u32, __value)
-                }
+                Self::System => __serializer.serialize_tag("system", 0u32),
+                Self::Boot => __serializer.serialize_tag("boot", 1u32),
             }
         }
     }
     #[automatically_derived]
-    impl<'de> __serde::Deserialize<'de> for ParameterValue {
+    impl<'de> __serde::Deserialize<'de> for ImportRoot {
         fn deserialize<__D: __serde::Deserializer<'de>>(
             __deserializer: __D,
         ) -> ::std::result::Result<Self, __D::Error> {
             #[doc(hidden)]
-            const __IDENTIFIERS: &'static [&'static str] =
-                &["String", "Boolean", "Integer", "Float"];
+            const __IDENTIFIERS: &'static [&'static str] = &["system", "boot"];
             #[doc(hidden)]
-            const __EXPECTING_IDENTIFIERS: &'static str =
-                "an identifier in [\"String\", \"Boolean\", \"Integer\", \"Float\"]";
+            const __EXPECTING_IDENTIFIERS: &'static str = "an identifier in [\"system\", \"boot\"]";
             #[derive(:: core :: clone :: Clone, :: core :: marker :: Copy)]
             #[doc(hidden)]
             enum __Identifier {
                 __Identifier0,
                 __Identifier1,
-                __Identifier2,
-                __Identifier3,
             }
             #[doc(hidden)]
             struct __IdentifierVisitor;
@@ -3760,8 +3864,6 @@ pub mod recipes {
                     match __value {
                         0u64 => ::core::result::Result::Ok(__Identifier::__Identifier0),
                         1u64 => ::core::result::Result::Ok(__Identifier::__Identifier1),
-                        2u64 => ::core::result::Result::Ok(__Identifier::__Identifier2),
-                        3u64 => ::core::result::Result::Ok(__Identifier::__Identifier3),
                         __variant => {
                             ::core::result::Result::Err(__serde::de::Error::invalid_value(
                                 __serde::de::Unexpected::Unsigned(__variant),
@@ -3775,10 +3877,8 @@ pub mod recipes {
                     __E: __serde::de::Error,
                 {
                     match __value {
-                        "String" => ::core::result::Result::Ok(__Identifier::__Identifier0),
-                        "Boolean" => ::core::result::Result::Ok(__Identifier::__Identifier1),
-                        "Integer" => ::core::result::Result::Ok(__Identifier::__Identifier2),
-                        "Float" => ::core::result::Result::Ok(__Identifier::__Identifier3),
+                        "system" => ::core::result::Result::Ok(__Identifier::__Identifier0),
+                        "boot" => ::core::result::Result::Ok(__Identifier::__Identifier1),
                         __variant => ::core::result::Result::Err(
                             __serde::de::Error::unknown_variant(__variant, __IDENTIFIERS),
                         ),
@@ -3792,10 +3892,8 @@ pub mod recipes {
                     __E: __serde::de::Error,
                 {
                     match __value {
-                        b"String" => ::core::result::Result::Ok(__Identifier::__Identifier0),
-                        b"Boolean" => ::core::result::Result::Ok(__Identifier::__Identifier1),
-                        b"Integer" => ::core::result::Result::Ok(__Identifier::__Identifier2),
-                        b"Float" => ::core::result::Result::Ok(__Identifier::__Identifier3),
+                        b"system" => ::core::result::Result::Ok(__Identifier::__Identifier0),
+                        b"boot" => ::core::result::Result::Ok(__Identifier::__Identifier1),
                         __variant => {
                             ::core::result::Result::Err(__serde::de::Error::invalid_value(
                                 __serde::de::Unexpected::Bytes(__variant),
@@ -3818,15 +3916,3309 @@ pub mod recipes {
                 }
             }
             #[doc(hidden)]
-            const __VARIANTS: &'static [&'static str] = &["String", "Boolean", "Integer", "Float"];
-            if __serde::Deserializer::is_human_readable(&__deserializer) {
-                let __content =
-                    __sidex_serde::de::content::deserialize_into_content(__deserializer)?;
-                match __sidex_serde::de::content::deserialize_content_ref::<
-                    ::std::string::String,
-                    __D::Error,
-                >(&__content)
-                {
+            const __VARIANTS: &'static [&'static str] = &["system", "boot"];
+            #[doc(hidden)]
+            struct __Visitor {
+                __phantom_vars: ::core::marker::PhantomData<fn(&())>,
+            }
+            impl<'de> __serde::de::Visitor<'de> for __Visitor {
+                type Value = ImportRoot;
+                fn expecting(
+                    &self,
+                    __formatter: &mut ::core::fmt::Formatter,
+                ) -> ::core::fmt::Result {
+                    ::core::fmt::Formatter::write_str(__formatter, "enum ImportRoot")
+                }
+                #[inline]
+                fn visit_str<__E>(self, __value: &str) -> ::core::result::Result<Self::Value, __E>
+                where
+                    __E: __serde::de::Error,
+                {
+                    let __identifier = __IdentifierVisitor.visit_str(__value)?;
+                    #[allow(unreachable_patterns)]
+                    match __identifier {
+                        __Identifier::__Identifier0 => {
+                            ::core::result::Result::Ok(ImportRoot::System)
+                        }
+                        __Identifier::__Identifier1 => ::core::result::Result::Ok(ImportRoot::Boot),
+                        _ => Err(__E::invalid_value(
+                            __serde::de::Unexpected::Str(__value),
+                            &self,
+                        )),
+                    }
+                }
+                #[inline]
+                fn visit_enum<__A>(
+                    self,
+                    __data: __A,
+                ) -> ::core::result::Result<Self::Value, __A::Error>
+                where
+                    __A: __serde::de::EnumAccess<'de>,
+                {
+                    match __serde::de::EnumAccess::variant::<__Identifier>(__data)? {
+                        (__Identifier::__Identifier0, __variant) => {
+                            __serde::de::VariantAccess::unit_variant(__variant)?;
+                            ::core::result::Result::Ok(ImportRoot::System)
+                        }
+                        (__Identifier::__Identifier1, __variant) => {
+                            __serde::de::VariantAccess::unit_variant(__variant)?;
+                            ::core::result::Result::Ok(ImportRoot::Boot)
+                        }
+                    }
+                }
+            }
+            __serde::Deserializer::deserialize_enum(
+                __deserializer,
+                "ImportRoot",
+                __VARIANTS,
+                __Visitor {
+                    __phantom_vars: ::core::marker::PhantomData,
+                },
+            )
+        }
+    }
+}
+pub mod projects {
+    #![doc = "Project configuration.\n"]
+    #[allow(unused)]
+    use :: serde as __serde;
+    #[allow(unused)]
+    use :: sidex_serde as __sidex_serde;
+    #[doc = "Project configuration.\n"]
+    #[derive(Clone, Debug)]
+    pub struct ProjectConfig {
+        #[doc = "Repositories imported into the project.\n"]
+        pub repositories: ::std::option::Option<
+            ::std::collections::HashMap<::std::string::String, super::repositories::SourceConfig>,
+        >,
+        #[doc = "System declarations.\n"]
+        pub systems: ::std::option::Option<
+            ::std::collections::HashMap<::std::string::String, super::systems::SystemConfig>,
+        >,
+        #[doc = "Shared cache for baked layers.\n"]
+        pub layer_cache: ::std::option::Option<LayerCacheConfig>,
+    }
+    impl ProjectConfig {
+        #[doc = "Creates a new [`ProjectConfig`]."]
+        pub fn new() -> Self {
+            Self {
+                repositories: ::std::default::Default::default(),
+                systems: ::std::default::Default::default(),
+                layer_cache: ::std::default::Default::default(),
+            }
+        }
+        #[doc = "Sets the value of `repositories`."]
+        pub fn set_repositories(
+            &mut self,
+            repositories: ::std::option::Option<
+                ::std::collections::HashMap<
+                    ::std::string::String,
+                    super::repositories::SourceConfig,
+                >,
+            >,
+        ) -> &mut Self {
+            self.repositories = repositories;
+            self
+        }
+        #[doc = "Sets the value of `repositories`."]
+        pub fn with_repositories(
+            mut self,
+            repositories: ::std::option::Option<
+                ::std::collections::HashMap<
+                    ::std::string::String,
+                    super::repositories::SourceConfig,
+                >,
+            >,
+        ) -> Self {
+            self.repositories = repositories;
+            self
+        }
+        #[doc = "Sets the value of `systems`."]
+        pub fn set_systems(
+            &mut self,
+            systems: ::std::option::Option<
+                ::std::collections::HashMap<::std::string::String, super::systems::SystemConfig>,
+            >,
+        ) -> &mut Self {
+            self.systems = systems;
+            self
+        }
+        #[doc = "Sets the value of `systems`."]
+        pub fn with_systems(
+            mut self,
+            systems: ::std::option::Option<
+                ::std::collections::HashMap<::std::string::String, super::systems::SystemConfig>,
+            >,
+        ) -> Self {
+            self.systems = systems;
+            self
+        }
+        #[doc = "Sets the value of `layer_cache`."]
+        pub fn set_layer_cache(
+            &mut self,
+            layer_cache: ::std::option::Option<LayerCacheConfig>,
+        ) -> &mut Self {
+            self.layer_cache = layer_cache;
+            self
+        }
+        #[doc = "Sets the value of `layer_cache`."]
+        pub fn with_layer_cache(
+            mut self,
+            layer_cache: ::std::option::Option<LayerCacheConfig>,
+        ) -> Self {
+            self.layer_cache = layer_cache;
+            self
+        }
+    }
+    impl ::std::default::Default for ProjectConfig {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+    #[automatically_derived]
+    impl __serde::Serialize for ProjectConfig {
+        fn serialize<__S: __serde::Serializer>(
+            &self,
+            __serializer: __S,
+        ) -> ::std::result::Result<__S::Ok, __S::Error> {
+            let mut __record =
+                __sidex_serde::ser::RecordSerializer::new(__serializer, "ProjectConfig", 3usize)?;
+            __record.serialize_optional_field(
+                "repositories",
+                ::core::option::Option::as_ref(&self.repositories),
+            )?;
+            __record.serialize_optional_field(
+                "systems",
+                ::core::option::Option::as_ref(&self.systems),
+            )?;
+            __record.serialize_optional_field(
+                "layer-cache",
+                ::core::option::Option::as_ref(&self.layer_cache),
+            )?;
+            __record.end()
+        }
+    }
+    #[automatically_derived]
+    impl<'de> __serde::Deserialize<'de> for ProjectConfig {
+        fn deserialize<__D: __serde::Deserializer<'de>>(
+            __deserializer: __D,
+        ) -> ::std::result::Result<Self, __D::Error> {
+            #[doc(hidden)]
+            struct __Visitor {
+                __phantom_vars: ::core::marker::PhantomData<fn(&())>,
+            }
+            impl<'de> __serde::de::Visitor<'de> for __Visitor {
+                type Value = ProjectConfig;
+                fn expecting(
+                    &self,
+                    __formatter: &mut ::core::fmt::Formatter,
+                ) -> ::core::fmt::Result {
+                    ::core::fmt::Formatter::write_str(__formatter, "record ProjectConfig")
+                }
+                #[inline]
+                fn visit_seq<__A>(
+                    self,
+                    mut __seq: __A,
+                ) -> ::core::result::Result<Self::Value, __A::Error>
+                where
+                    __A: __serde::de::SeqAccess<'de>,
+                {
+                    let __field0 = match __serde::de::SeqAccess::next_element::<
+                        ::std::option::Option<
+                            ::std::collections::HashMap<
+                                ::std::string::String,
+                                super::repositories::SourceConfig,
+                            >,
+                        >,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(0usize, &"record with 3 fields"),
+                            );
+                        }
+                    };
+                    let __field1 = match __serde::de::SeqAccess::next_element::<
+                        ::std::option::Option<
+                            ::std::collections::HashMap<
+                                ::std::string::String,
+                                super::systems::SystemConfig,
+                            >,
+                        >,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(1usize, &"record with 3 fields"),
+                            );
+                        }
+                    };
+                    let __field2 = match __serde::de::SeqAccess::next_element::<
+                        ::std::option::Option<LayerCacheConfig>,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(2usize, &"record with 3 fields"),
+                            );
+                        }
+                    };
+                    ::core::result::Result::Ok(ProjectConfig {
+                        repositories: __field0,
+                        systems: __field1,
+                        layer_cache: __field2,
+                    })
+                }
+                #[inline]
+                fn visit_map<__A>(
+                    self,
+                    mut __map: __A,
+                ) -> ::core::result::Result<Self::Value, __A::Error>
+                where
+                    __A: __serde::de::MapAccess<'de>,
+                {
+                    #[doc(hidden)]
+                    const __IDENTIFIERS: &'static [&'static str] =
+                        &["repositories", "systems", "layer-cache"];
+                    #[doc(hidden)]
+                    const __EXPECTING_IDENTIFIERS: &'static str =
+                        "an identifier in [\"repositories\", \"systems\", \"layer-cache\"]";
+                    #[derive(:: core :: clone :: Clone, :: core :: marker :: Copy)]
+                    #[doc(hidden)]
+                    enum __Identifier {
+                        __Identifier0,
+                        __Identifier1,
+                        __Identifier2,
+                        __Unknown,
+                    }
+                    #[doc(hidden)]
+                    struct __IdentifierVisitor;
+                    impl<'de> __serde::de::Visitor<'de> for __IdentifierVisitor {
+                        type Value = __Identifier;
+                        fn expecting(
+                            &self,
+                            __formatter: &mut ::core::fmt::Formatter,
+                        ) -> ::core::fmt::Result {
+                            ::core::fmt::Formatter::write_str(__formatter, __EXPECTING_IDENTIFIERS)
+                        }
+                        fn visit_u64<__E>(
+                            self,
+                            __value: u64,
+                        ) -> ::core::result::Result<Self::Value, __E>
+                        where
+                            __E: __serde::de::Error,
+                        {
+                            match __value {
+                                0u64 => ::core::result::Result::Ok(__Identifier::__Identifier0),
+                                1u64 => ::core::result::Result::Ok(__Identifier::__Identifier1),
+                                2u64 => ::core::result::Result::Ok(__Identifier::__Identifier2),
+                                _ => ::core::result::Result::Ok(__Identifier::__Unknown),
+                            }
+                        }
+                        fn visit_str<__E>(
+                            self,
+                            __value: &str,
+                        ) -> ::core::result::Result<Self::Value, __E>
+                        where
+                            __E: __serde::de::Error,
+                        {
+                            match __value {
+                                "repositories" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier0)
+                                }
+                                "systems" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier1)
+                                }
+                                "layer-cache" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier2)
+                                }
+                                _ => ::core::result::Result::Ok(__Identifier::__Unknown),
+                            }
+                        }
+                        fn visit_bytes<__E>(
+                            self,
+                            __value: &[u8],
+                        ) -> ::core::result::Result<Self::Value, __E>
+                        where
+                            __E: __serde::de::Error,
+                        {
+                            match __value {
+                                b"repositories" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier0)
+                                }
+                                b"systems" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier1)
+                                }
+                                b"layer-cache" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier2)
+                                }
+                                _ => ::core::result::Result::Ok(__Identifier::__Unknown),
+                            }
+                        }
+                    }
+                    impl<'de> __serde::Deserialize<'de> for __Identifier {
+                        #[inline]
+                        fn deserialize<__D>(
+                            __deserializer: __D,
+                        ) -> ::core::result::Result<Self, __D::Error>
+                        where
+                            __D: __serde::Deserializer<'de>,
+                        {
+                            __serde::Deserializer::deserialize_identifier(
+                                __deserializer,
+                                __IdentifierVisitor,
+                            )
+                        }
+                    }
+                    let mut __field0: ::core::option::Option<
+                        ::std::option::Option<
+                            ::std::collections::HashMap<
+                                ::std::string::String,
+                                super::repositories::SourceConfig,
+                            >,
+                        >,
+                    > = ::core::option::Option::None;
+                    let mut __field1: ::core::option::Option<
+                        ::std::option::Option<
+                            ::std::collections::HashMap<
+                                ::std::string::String,
+                                super::systems::SystemConfig,
+                            >,
+                        >,
+                    > = ::core::option::Option::None;
+                    let mut __field2: ::core::option::Option<
+                        ::std::option::Option<LayerCacheConfig>,
+                    > = ::core::option::Option::None;
+                    while let ::core::option::Option::Some(__key) =
+                        __serde::de::MapAccess::next_key::<__Identifier>(&mut __map)?
+                    {
+                        match __key {
+                            __Identifier::__Identifier0 => {
+                                if ::core::option::Option::is_some(&__field0) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field(
+                                            "repositories",
+                                        ),
+                                    );
+                                }
+                                __field0 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<
+                                        ::std::option::Option<
+                                            ::std::collections::HashMap<
+                                                ::std::string::String,
+                                                super::repositories::SourceConfig,
+                                            >,
+                                        >,
+                                    >(&mut __map)?,
+                                );
+                            }
+                            __Identifier::__Identifier1 => {
+                                if ::core::option::Option::is_some(&__field1) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field(
+                                            "systems",
+                                        ),
+                                    );
+                                }
+                                __field1 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<
+                                        ::std::option::Option<
+                                            ::std::collections::HashMap<
+                                                ::std::string::String,
+                                                super::systems::SystemConfig,
+                                            >,
+                                        >,
+                                    >(&mut __map)?,
+                                );
+                            }
+                            __Identifier::__Identifier2 => {
+                                if ::core::option::Option::is_some(&__field2) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field(
+                                            "layer-cache",
+                                        ),
+                                    );
+                                }
+                                __field2 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<
+                                        ::std::option::Option<LayerCacheConfig>,
+                                    >(&mut __map)?,
+                                );
+                            }
+                            _ => {
+                                __serde::de::MapAccess::next_value::<__serde::de::IgnoredAny>(
+                                    &mut __map,
+                                )?;
+                            }
+                        }
+                    }
+                    let __field0 = match __field0 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    };
+                    let __field1 = match __field1 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    };
+                    let __field2 = match __field2 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    };
+                    ::core::result::Result::Ok(ProjectConfig {
+                        repositories: __field0,
+                        systems: __field1,
+                        layer_cache: __field2,
+                    })
+                }
+            }
+            #[doc(hidden)]
+            const __FIELDS: &'static [&'static str] = &["repositories", "systems", "layer-cache"];
+            __serde::Deserializer::deserialize_struct(
+                __deserializer,
+                "ProjectConfig",
+                __FIELDS,
+                __Visitor {
+                    __phantom_vars: ::core::marker::PhantomData,
+                },
+            )
+        }
+    }
+    #[doc = "Shared cache for baked layers.\n"]
+    #[derive(Clone, Debug)]
+    pub enum LayerCacheConfig {
+        #[doc = "Cache in a local or network-mounted directory.\n"]
+        Dir(DirLayerCacheConfig),
+        #[doc = "Cache on an HTTP server supporting `GET` and `PUT` requests.\n"]
+        Http(HttpLayerCacheConfig),
+    }
+    #[automatically_derived]
+    impl __serde::Serialize for LayerCacheConfig {
+        fn serialize<__S: __serde::Serializer>(
+            &self,
+            __serializer: __S,
+        ) -> ::std::result::Result<__S::Ok, __S::Error> {
+            let __serializer =
+                __sidex_serde::ser::VariantSerializer::new(__serializer, "LayerCacheConfig");
+            match self {
+                Self::Dir(__value) => {
+                    __serializer.serialize_implicitly_tagged("Dir", 0u32, __value)
+                }
+                Self::Http(__value) => {
+                    __serializer.serialize_implicitly_tagged("Http", 1u32, __value)
+                }
+            }
+        }
+    }
+    #[automatically_derived]
+    impl<'de> __serde::Deserialize<'de> for LayerCacheConfig {
+        fn deserialize<__D: __serde::Deserializer<'de>>(
+            __deserializer: __D,
+        ) -> ::std::result::Result<Self, __D::Error> {
+            #[doc(hidden)]
+            const __IDENTIFIERS: &'static [&'static str] = &["Dir", "Http"];
+            #[doc(hidden)]
+            const __EXPECTING_IDENTIFIERS: &'static str = "an identifier in [\"Dir\", \"Http\"]";
+            #[derive(:: core :: clone :: Clone, :: core :: marker :: Copy)]
+            #[doc(hidden)]
+            enum __Identifier {
+                __Identifier0,
+                __Identifier1,
+            }
+            #[doc(hidden)]
+            struct __IdentifierVisitor;
+            impl<'de> __serde::de::Visitor<'de> for __IdentifierVisitor {
+                type Value = __Identifier;
+                fn expecting(
+                    &self,
+                    __formatter: &mut ::core::fmt::Formatter,
+                ) -> ::core::fmt::Result {
+                    ::core::fmt::Formatter::write_str(__formatter, __EXPECTING_IDENTIFIERS)
+                }
+                fn visit_u64<__E>(self, __value: u64) -> ::core::result::Result<Self::Value, __E>
+                where
+                    __E: __serde::de::Error,
+                {
+                    match __value {
+                        0u64 => ::core::result::Result::Ok(__Identifier::__Identifier0),
+                        1u64 => ::core::result::Result::Ok(__Identifier::__Identifier1),
+                        __variant => {
+                            ::core::result::Result::Err(__serde::de::Error::invalid_value(
+                                __serde::de::Unexpected::Unsigned(__variant),
+                                &__EXPECTING_IDENTIFIERS,
+                            ))
+                        }
+                    }
+                }
+                fn visit_str<__E>(self, __value: &str) -> ::core::result::Result<Self::Value, __E>
+                where
+                    __E: __serde::de::Error,
+                {
+                    match __value {
+                        "Dir" => ::core::result::Result::Ok(__Identifier::__Identifier0),
+                        "Http" => ::core::result::Result::Ok(__Identifier::__Identifier1),
+                        __variant => ::core::result::Result::Err(
+                            __serde::de::Error::unknown_variant(__variant, __IDENTIFIERS),
+                        ),
+                    }
+                }
+                fn visit_bytes<__E>(
+                    self,
+                    __value: &[u8],
+                ) -> ::core::result::Result<Self::Value, __E>
+                where
+                    __E: __serde::de::Error,
+                {
+                    match __value {
+                        b"Dir" => ::core::result::Result::Ok(__Identifier::__Identifier0),
+                        b"Http" => ::core::result::Result::Ok(__Identifier::__Identifier1),
+                        __variant => {
+                            ::core::result::Result::Err(__serde::de::Error::invalid_value(
+                                __serde::de::Unexpected::Bytes(__variant),
+                                &__EXPECTING_IDENTIFIERS,
+                            ))
+                        }
+                    }
+                }
+            }
+            impl<'de> __serde::Deserialize<'de> for __Identifier {
+                #[inline]
+                fn deserialize<__D>(__deserializer: __D) -> ::core::result::Result<Self, __D::Error>
+                where
+                    __D: __serde::Deserializer<'de>,
+                {
+                    __serde::Deserializer::deserialize_identifier(
+                        __deserializer,
+                        __IdentifierVisitor,
+                    )
+                }
+            }
+            #[doc(hidden)]
+            const __VARIANTS: &'static [&'static str] = &["Dir", "Http"];
+            if __serde::Deserializer::is_human_readable(&__deserializer) {
+                let __content =
+                    __sidex_serde::de::content::deserialize_into_content(__deserializer)?;
+                match __sidex_serde::de::content::deserialize_content_ref::<
+                    DirLayerCacheConfig,
+                    __D::Error,
+                >(&__content)
+                {
+                    Ok(__value) => return Ok(LayerCacheConfig::Dir(__value)),
+                    Err(_) => {}
+                };
+                match __sidex_serde::de::content::deserialize_content_ref::<
+                    HttpLayerCacheConfig,
+                    __D::Error,
+                >(&__content)
+                {
+                    Ok(__value) => return Ok(LayerCacheConfig::Http(__value)),
+                    Err(_) => {}
+                };
+                Err(<__D::Error as __serde::de::Error>::custom(
+                    "no matching variant found",
+                ))
+            } else {
+                #[doc(hidden)]
+                struct __Visitor {
+                    __phantom_vars: ::core::marker::PhantomData<fn(&())>,
+                }
+                impl<'de> __serde::de::Visitor<'de> for __Visitor {
+                    type Value = LayerCacheConfig;
+                    fn expecting(
+                        &self,
+                        __formatter: &mut ::core::fmt::Formatter,
+                    ) -> ::core::fmt::Result {
+                        ::core::fmt::Formatter::write_str(__formatter, "enum LayerCacheConfig")
+                    }
+                    #[inline]
+                    fn visit_str<__E>(
+                        self,
+                        __value: &str,
+                    ) -> ::core::result::Result<Self::Value, __E>
+                    where
+                        __E: __serde::de::Error,
+                    {
+                        let __identifier = __IdentifierVisitor.visit_str(__value)?;
+                        #[allow(unreachable_patterns)]
+                        match __identifier {
+                            _ => Err(__E::invalid_value(
+                                __serde::de::Unexpected::Str(__value),
+                                &self,
+                            )),
+                        }
+                    }
+                    #[inline]
+                    fn visit_enum<__A>(
+                        self,
+                        __data: __A,
+                    ) -> ::core::result::Result<Self::Value, __A::Error>
+                    where
+                        __A: __serde::de::EnumAccess<'de>,
+                    {
+                        match __serde::de::EnumAccess::variant::<__Identifier>(__data)? {
+                            (__Identifier::__Identifier0, __variant) => {
+                                let __value = __serde::de::VariantAccess::newtype_variant::<
+                                    DirLayerCacheConfig,
+                                >(__variant)?;
+                                ::core::result::Result::Ok(LayerCacheConfig::Dir(__value))
+                            }
+                            (__Identifier::__Identifier1, __variant) => {
+                                let __value = __serde::de::VariantAccess::newtype_variant::<
+                                    HttpLayerCacheConfig,
+                                >(__variant)?;
+                                ::core::result::Result::Ok(LayerCacheConfig::Http(__value))
+                            }
+                        }
+                    }
+                }
+                __serde::Deserializer::deserialize_enum(
+                    __deserializer,
+                    "LayerCacheConfig",
+                    __VARIANTS,
+                    __Visitor {
+                        __phantom_vars: ::core::marker::PhantomData,
+                    },
+                )
+            }
+        }
+    }
+    #[doc = "Layer cache in a directory.\n"]
+    #[derive(Clone, Debug)]
+    pub struct DirLayerCacheConfig {
+        #[doc = "Path of the cache directory relative to the project directory.\n"]
+        pub dir: ::std::string::String,
+    }
+    impl DirLayerCacheConfig {
+        #[doc = "Creates a new [`DirLayerCacheConfig`]."]
+        pub fn new(dir: ::std::string::String) -> Self {
+            Self { dir }
+        }
+        #[doc = "Sets the value of `dir`."]
+        pub fn set_dir(&mut self, dir: ::std::string::String) -> &mut Self {
+            self.dir = dir;
+            self
+        }
+        #[doc = "Sets the value of `dir`."]
+        pub fn with_dir(mut self, dir: ::std::string::String) -> Self {
+            self.dir = dir;
+            self
+        }
+    }
+    #[automatically_derived]
+    impl __serde::Serialize for DirLayerCacheConfig {
+        fn serialize<__S: __serde::Serializer>(
+            &self,
+            __serializer: __S,
+        ) -> ::std::result::Result<__S::Ok, __S::Error> {
+            let mut __record = __sidex_serde::ser::RecordSerializer::new(
+                __serializer,
+                "DirLayerCacheConfig",
+                1usize,
+            )?;
+            __record.serialize_field("dir", &self.dir)?;
+            __record.end()
+        }
+    }
+    #[automatically_derived]
+    impl<'de> __serde::Deserialize<'de> for DirLayerCacheConfig {
+        fn deserialize<__D: __serde::Deserializer<'de>>(
+            __deserializer: __D,
+        ) -> ::std::result::Result<Self, __D::Error> {
+            #[doc(hidden)]
+            struct __Visitor {
+                __phantom_vars: ::core::marker::PhantomData<fn(&())>,
+            }
+            impl<'de> __serde::de::Visitor<'de> for __Visitor {
+                type Value = DirLayerCacheConfig;
+                fn expecting(
+                    &self,
+                    __formatter: &mut ::core::fmt::Formatter,
+                ) -> ::core::fmt::Result {
+                    ::core::fmt::Formatter::write_str(__formatter, "record DirLayerCacheConfig")
+                }
+                #[inline]
+                fn visit_seq<__A>(
+                    self,
+                    mut __seq: __A,
+                ) -> ::core::result::Result<Self::Value, __A::Error>
+                where
+                    __A: __serde::de::SeqAccess<'de>,
+                {
+                    let __field0 = match __serde::de::SeqAccess::next_element::<
+                        ::std::string::String,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(0usize, &"record with 1 fields"),
+                            );
+                        }
+                    };
+                    ::core::result::Result::Ok(DirLayerCacheConfig { dir: __field0 })
+                }
+                #[inline]
+                fn visit_map<__A>(
+                    self,
+                    mut __map: __A,
+                ) -> ::core::result::Result<Self::Value, __A::Error>
+                where
+                    __A: __serde::de::MapAccess<'de>,
+                {
+                    #[doc(hidden)]
+                    const __IDENTIFIERS: &'static [&'static str] = &["dir"];
+                    #[doc(hidden)]
+                    const __EXPECTING_IDENTIFIERS: &'static str = "an identifier in [\"dir\"]";
+                    #[derive(:: core :: clone :: Clone, :: core :: marker :: Copy)]
+                    #[doc(hidden)]
+                    enum __Identifier {
+                        __Identifier0,
+                        __Unknown,
+                    }
+                    #[doc(hidden)]
+                    struct __IdentifierVisitor;
+                    impl<'de> __serde::de::Visitor<'de> for __IdentifierVisitor {
+                        type Value = __Identifier;
+                        fn expecting(
+                            &self,
+                            __formatter: &mut ::core::fmt::Formatter,
+                        ) -> ::core::fmt::Result {
+                            ::core::fmt::Formatter::write_str(__formatter, __EXPECTING_IDENTIFIERS)
+                        }
+                        fn visit_u64<__E>(
+                            self,
+                            __value: u64,
+                        ) -> ::core::result::Result<Self::Value, __E>
+                        where
+                            __E: __serde::de::Error,
+                        {
+                            match __value {
+                                0u64 => ::core::result::Result::Ok(__Identifier::__Identifier0),
+                                _ => ::core::result::Result::Ok(__Identifier::__Unknown),
+                            }
+                        }
+                        fn visit_str<__E>(
+                            self,
+                            __value: &str,
+                        ) -> ::core::result::Result<Self::Value, __E>
+                        where
+                            __E: __serde::de::Error,
+                        {
+                            match __value {
+                                "dir" => ::core::result::Result::Ok(__Identifier::__Identifier0),
+                                _ => ::core::result::Result::Ok(__Identifier::__Unknown),
+                            }
+                        }
+                        fn visit_bytes<__E>(
+                            self,
+                            __value: &[u8],
+                        ) -> ::core::result::Result<Self::Value, __E>
+                        where
+                            __E: __serde::de::Error,
+                        {
+                            match __value {
+                                b"dir" => ::core::result::Result::Ok(__Identifier::__Identifier0),
+                                _ => ::core::result::Result::Ok(__Identifier::__Unknown),
+                            }
+                        }
+                    }
+                    impl<'de> __serde::Deserialize<'de> for __Identifier {
+                        #[inline]
+                        fn deserialize<__D>(
+                            __deserializer: __D,
+                        ) -> ::core::result::Result<Self, __D::Error>
+                        where
+                            __D: __serde::Deserializer<'de>,
+                        {
+                            __serde::Deserializer::deserialize_identifier(
+                                __deserializer,
+                                __IdentifierVisitor,
+                            )
+                        }
+                    }
+                    let mut __field0: ::core::option::Option<::std::string::String> =
+                        ::core::option::Option::None;
+                    while let ::core::option::Option::Some(__key) =
+                        __serde::de::MapAccess::next_key::<__Identifier>(&mut __map)?
+                    {
+                        match __key {
+                            __Identifier::__Identifier0 => {
+                                if ::core::option::Option::is_some(&__field0) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field("dir"),
+                                    );
+                                }
+                                __field0 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<::std::string::String>(
+                                        &mut __map,
+                                    )?,
+                                );
+                            }
+                            _ => {
+                                __serde::de::MapAccess::next_value::<__serde::de::IgnoredAny>(
+                                    &mut __map,
+                                )?;
+                            }
+                        }
+                    }
+                    let __field0 = match __field0 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                <__A::Error as __serde::de::Error>::missing_field("dir"),
+                            );
+                        }
+                    };
+                    ::core::result::Result::Ok(DirLayerCacheConfig { dir: __field0 })
+                }
+            }
+            #[doc(hidden)]
+            const __FIELDS: &'static [&'static str] = &["dir"];
+            __serde::Deserializer::deserialize_struct(
+                __deserializer,
+                "DirLayerCacheConfig",
+                __FIELDS,
+                __Visitor {
+                    __phantom_vars: ::core::marker::PhantomData,
+                },
+            )
+        }
+    }
+    #[doc = "Layer cache on an HTTP server.\n"]
+    #[derive(Clone, Debug)]
+    pub struct HttpLayerCacheConfig {
+        #[doc = "Base URL of the cache.\n"]
+        pub url: ::std::string::String,
+    }
+    impl HttpLayerCacheConfig {
+        #[doc = "Creates a new [`HttpLayerCacheConfig`]."]
+        pub fn new(url: ::std::string::String) -> Self {
+            Self { url }
+        }
+        #[doc = "Sets the value of `url`."]
+        pub fn set_url(&mut self, url: ::std::string::String) -> &mut Self {
+            self.url = url;
+            self
+        }
+        #[doc = "Sets the value of `url`."]
+        pub fn with_url(mut self, url: ::std::string::String) -> Self {
+            self.url = url;
+            self
+        }
+    }
+    #[automatically_derived]
+    impl __serde::Serialize for HttpLayerCacheConfig {
+        fn serialize<__S: __serde::Serializer>(
+            &self,
+            __serializer: __S,
+        ) -> ::std::result::Result<__S::Ok, __S::Error> {
+            let mut __record = __sidex_serde::ser::RecordSerializer::new(
+                __serializer,
+                "HttpLayerCacheConfig",
+                1usize,
+            )?;
+            __record.serialize_field("url", &self.url)?;
+            __record.end()
+        }
+    }
+    #[automatically_derived]
+    impl<'de> __serde::Deserialize<'de> for HttpLayerCacheConfig {
+        fn deserialize<__D: __serde::Deserializer<'de>>(
+            __deserializer: __D,
+        ) -> ::std::result::Result<Self, __D::Error> {
+            #[doc(hidden)]
+            struct __Visitor {
+                __phantom_vars: ::core::marker::PhantomData<fn(&())>,
+            }
+            impl<'de> __serde::de::Visitor<'de> for __Visitor {
+                type Value = HttpLayerCacheConfig;
+                fn expecting(
+                    &self,
+                    __formatter: &mut ::core::fmt::Formatter,
+                ) -> ::core::fmt::Result {
+                    ::core::fmt::Formatter::write_str(__formatter, "record HttpLayerCacheConfig")
+                }
+                #[inline]
+                fn visit_seq<__A>(
+                    self,
+                    mut __seq: __A,
+                ) -> ::core::result::Result<Self::Value, __A::Error>
+                where
+                    __A: __serde::de::SeqAccess<'de>,
+                {
+                    let __field0 = match __serde::de::SeqAccess::next_element::<
+                        ::std::string::String,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(0usize, &"record with 1 fields"),
+                            );
+                        }
+                    };
+                    ::core::result::Result::Ok(HttpLayerCacheConfig { url: __field0 })
+                }
+                #[inline]
+                fn visit_map<__A>(
+                    self,
+                    mut __map: __A,
+                ) -> ::core::result::Result<Self::Value, __A::Error>
+                where
+                    __A: __serde::de::MapAccess<'de>,
+                {
+                    #[doc(hidden)]
+                    const __IDENTIFIERS: &'static [&'static str] = &["url"];
+                    #[doc(hidden)]
+                    const __EXPECTING_IDENTIFIERS: &'static str = "an identifier in [\"url\"]";
+                    #[derive(:: core :: clone :: Clone, :: core :: marker :: Copy)]
+                    #[doc(hidden)]
+                    enum __Identifier {
+                        __Identifier0,
+                        __Unknown,
+                    }
+                    #[doc(hidden)]
+                    struct __IdentifierVisitor;
+                    impl<'de> __serde::de::Visitor<'de> for __IdentifierVisitor {
+                        type Value = __Identifier;
+                        fn expecting(
+                            &self,
+                            __formatter: &mut ::core::fmt::Formatter,
+                        ) -> ::core::fmt::Result {
+                            ::core::fmt::Formatter::write_str(__formatter, __EXPECTING_IDENTIFIERS)
+                        }
+                        fn visit_u64<__E>(
+                            self,
+                            __value: u64,
+                        ) -> ::core::result::Result<Self::Value, __E>
+                        where
+                            __E: __serde::de::Error,
+                        {
+                            match __value {
+                                0u64 => ::core::result::Result::Ok(__Identifier::__Identifier0),
+                                _ => ::core::result::Result::Ok(__Identifier::__Unknown),
+                            }
+                        }
+                        fn visit_str<__E>(
+                            self,
+                            __value: &str,
+                        ) -> ::core::result::Result<Self::Value, __E>
+                        where
+                            __E: __serde::de::Error,
+                        {
+                            match __value {
+                                "url" => ::core::result::Result::Ok(__Identifier::__Identifier0),
+                                _ => ::core::result::Result::Ok(__Identifier::__Unknown),
+                            }
+                        }
+                        fn visit_bytes<__E>(
+                            self,
+                            __value: &[u8],
+                        ) -> ::core::result::Result<Self::Value, __E>
+                        where
+                            __E: __serde::de::Error,
+                        {
+                            match __value {
+                                b"url" => ::core::result::Result::Ok(__Identifier::__Identifier0),
+                                _ => ::core::result::Result::Ok(__Identifier::__Unknown),
+                            }
+                        }
+                    }
+                    impl<'de> __serde::Deserialize<'de> for __Identifier {
+                        #[inline]
+                        fn deserialize<__D>(
+                            __deserializer: __D,
+                        ) -> ::core::result::Result<Self, __D::Error>
+                        where
+                            __D: __serde::Deserializer<'de>,
+                        {
+                            __serde::Deserializer::deserialize_identifier(
+                                __deserializer,
+                                __IdentifierVisitor,
+                            )
+                        }
+                    }
+                    let mut __field0: ::core::option::Option<::std::string::String> =
+                        ::core::option::Option::None;
+                    while let ::core::option::Option::Some(__key) =
+                        __serde::de::MapAccess::next_key::<__Identifier>(&mut __map)?
+                    {
+                        match __key {
+                            __Identifier::__Identifier0 => {
+                                if ::core::option::Option::is_some(&__field0) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field("url"),
+                                    );
+                                }
+                                __field0 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<::std::string::String>(
+                                        &mut __map,
+                                    )?,
+                                );
+                            }
+                            _ => {
+                                __serde::de::MapAccess::next_value::<__serde::de::IgnoredAny>(
+                                    &mut __map,
+                                )?;
+                            }
+                        }
+                    }
+                    let __field0 = match __field0 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                <__A::Error as __serde::de::Error>::missing_field("url"),
+                            );
+                        }
+                    };
+                    ::core::result::Result::Ok(HttpLayerCacheConfig { url: __field0 })
+                }
+            }
+            #[doc(hidden)]
+            const __FIELDS: &'static [&'static str] = &["url"];
+            __serde::Deserializer::deserialize_struct(
+                __deserializer,
+                "HttpLayerCacheConfig",
+                __FIELDS,
+                __Visitor {
+                    __phantom_vars: ::core::marker::PhantomData,
+                },
+            )
+        }
+    }
+}
+pub mod recipes {
+    #![doc = "Recipe configuration.\n"]
+    #[allow(unused)]
+    use :: serde as __serde;
+    #[allow(unused)]
+    use :: sidex_serde as __sidex_serde;
+    #[doc = "Recipe configuration.\n"]
+    #[derive(Clone, Debug)]
+    pub struct RecipeConfig {
+        #[doc = "Description of the recipe.\n"]
+        pub description: ::std::option::Option<::std::string::String>,
+        #[doc = "Priority of the recipe.\n\nRecipes run after their dependencies and `after`/`before` constraints. Among\nrecipes which are ready to run, those with a higher priority run first.\n"]
+        pub priority: ::std::option::Option<i64>,
+        #[doc = "Dependencies of the recipe.\n"]
+        pub dependencies: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+        #[doc = "Recipes after which the recipe runs, if they are part of the layer.\n"]
+        pub after: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+        #[doc = "Recipes before which the recipe runs, if they are part of the layer.\n"]
+        pub before: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+        #[doc = "Recipes and virtual names which must not be part of the same layer.\n"]
+        pub conflicts: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+        #[doc = "Virtual names provided by the recipe.\n\nOther recipes can depend on virtual names instead of specific recipes.\n"]
+        pub provides: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+        #[doc = "Parameter definitions of the recipe.\n"]
+        pub parameters:
+            ::std::option::Option<::std::collections::HashMap<::std::string::String, ParameterDef>>,
+        #[doc = "Configuration of the recipe's steps by their filename.\n"]
+        pub steps:
+            ::std::option::Option<::std::collections::HashMap<::std::string::String, StepConfig>>,
+        #[doc = "Groups to create before running the recipe's steps.\n"]
+        pub groups: ::std::option::Option<::std::vec::Vec<GroupConfig>>,
+        #[doc = "Users to create before running the recipe's steps.\n"]
+        pub users: ::std::option::Option<::std::vec::Vec<UserConfig>>,
+        #[doc = "Systemd units to enable, disable, or mask after running the recipe's steps.\n"]
+        pub systemd: ::std::option::Option<SystemdConfig>,
+    }
+    impl RecipeConfig {
+        #[doc = "Creates a new [`RecipeConfig`]."]
+        pub fn new() -> Self {
+            Self {
+                description: ::std::default::Default::default(),
+                priority: ::std::default::Default::default(),
+                dependencies: ::std::default::Default::default(),
+                after: ::std::default::Default::default(),
+                before: ::std::default::Default::default(),
+                conflicts: ::std::default::Default::default(),
+                provides: ::std::default::Default::default(),
+                parameters: ::std::default::Default::default(),
+                steps: ::std::default::Default::default(),
+                groups: ::std::default::Default::default(),
+                users: ::std::default::Default::default(),
+                systemd: ::std::default::Default::default(),
+            }
+        }
+        #[doc = "Sets the value of `description`."]
+        pub fn set_description(
+            &mut self,
+            description: ::std::option::Option<::std::string::String>,
+        ) -> &mut Self {
+            self.description = description;
+            self
+        }
+        #[doc = "Sets the value of `description`."]
+        pub fn with_description(
+            mut self,
+            description: ::std::option::Option<::std::string::String>,
+        ) -> Self {
+            self.description = description;
+            self
+        }
+        #[doc = "Sets the value of `priority`."]
+        pub fn set_priority(&mut self, priority: ::std::option::Option<i64>) -> &mut Self {
+            self.priority = priority;
+            self
+        }
+        #[doc = "Sets the value of `priority`."]
+        pub fn with_priority(mut self, priority: ::std::option::Option<i64>) -> Self {
+            self.priority = priority;
+            self
+        }
+        #[doc = "Sets the value of `dependencies`."]
+        pub fn set_dependencies(
+            &mut self,
+            dependencies: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+        ) -> &mut Self {
+            self.dependencies = dependencies;
+            self
+        }
+        #[doc = "Sets the value of `dependencies`."]
+        pub fn with_dependencies(
+            mut self,
+            dependencies: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+        ) -> Self {
+            self.dependencies = dependencies;
+            self
+        }
+        #[doc = "Sets the value of `after`."]
+        pub fn set_after(
+            &mut self,
+            after: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+        ) -> &mut Self {
+            self.after = after;
+            self
+        }
+        #[doc = "Sets the value of `after`."]
+        pub fn with_after(
+            mut self,
+            after: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+        ) -> Self {
+            self.after = after;
+            self
+        }
+        #[doc = "Sets the value of `before`."]
+        pub fn set_before(
+            &mut self,
+            before: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+        ) -> &mut Self {
+            self.before = before;
+            self
+        }
+        #[doc = "Sets the value of `before`."]
+        pub fn with_before(
+            mut self,
+            before: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+        ) -> Self {
+            self.before = before;
+            self
+        }
+        #[doc = "Sets the value of `conflicts`."]
+        pub fn set_conflicts(
+            &mut self,
+            conflicts: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+        ) -> &mut Self {
+            self.conflicts = conflicts;
+            self
+        }
+        #[doc = "Sets the value of `conflicts`."]
+        pub fn with_conflicts(
+            mut self,
+            conflicts: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+        ) -> Self {
+            self.conflicts = conflicts;
+            self
+        }
+        #[doc = "Sets the value of `provides`."]
+        pub fn set_provides(
+            &mut self,
+            provides: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+        ) -> &mut Self {
+            self.provides = provides;
+            self
+        }
+        #[doc = "Sets the value of `provides`."]
+        pub fn with_provides(
+            mut self,
+            provides: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+        ) -> Self {
+            self.provides = provides;
+            self
+        }
+        #[doc = "Sets the value of `parameters`."]
+        pub fn set_parameters(
+            &mut self,
+            parameters: ::std::option::Option<
+                ::std::collections::HashMap<::std::string::String, ParameterDef>,
+            >,
+        ) -> &mut Self {
+            self.parameters = parameters;
+            self
+        }
+        #[doc = "Sets the value of `parameters`."]
+        pub fn with_parameters(
+            mut self,
+            parameters: ::std::option::Option<
+                ::std::collections::HashMap<::std::string::String, ParameterDef>,
+            >,
+        ) -> Self {
+            self.parameters = parameters;
+            self
+        }
+        #[doc = "Sets the value of `steps`."]
+        pub fn set_steps(
+            &mut self,
+            steps: ::std::option::Option<
+                ::std::collections::HashMap<::std::string::String, StepConfig>,
+            >,
+        ) -> &mut Self {
+            self.steps = steps;
+            self
+        }
+        #[doc = "Sets the value of `steps`."]
+        pub fn with_steps(
+            mut self,
+            steps: ::std::option::Option<
+                ::std::collections::HashMap<::std::string::String, StepConfig>,
+            >,
+        ) -> Self {
+            self.steps = steps;
+            self
+        }
+        #[doc = "Sets the value of `groups`."]
+        pub fn set_groups(
+            &mut self,
+            groups: ::std::option::Option<::std::vec::Vec<GroupConfig>>,
+        ) -> &mut Self {
+            self.groups = groups;
+            self
+        }
+        #[doc = "Sets the value of `groups`."]
+        pub fn with_groups(
+            mut self,
+            groups: ::std::option::Option<::std::vec::Vec<GroupConfig>>,
+        ) -> Self {
+            self.groups = groups;
+            self
+        }
+        #[doc = "Sets the value of `users`."]
+        pub fn set_users(
+            &mut self,
+            users: ::std::option::Option<::std::vec::Vec<UserConfig>>,
+        ) -> &mut Self {
+            self.users = users;
+            self
+        }
+        #[doc = "Sets the value of `users`."]
+        pub fn with_users(
+            mut self,
+            users: ::std::option::Option<::std::vec::Vec<UserConfig>>,
+        ) -> Self {
+            self.users = users;
+            self
+        }
+        #[doc = "Sets the value of `systemd`."]
+        pub fn set_systemd(&mut self, systemd: ::std::option::Option<SystemdConfig>) -> &mut Self {
+            self.systemd = systemd;
+            self
+        }
+        #[doc = "Sets the value of `systemd`."]
+        pub fn with_systemd(mut self, systemd: ::std::option::Option<SystemdConfig>) -> Self {
+            self.systemd = systemd;
+            self
+        }
+    }
+    impl ::std::default::Default for RecipeConfig {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+    #[automatically_derived]
+    impl __serde::Serialize for RecipeConfig {
+        fn serialize<__S: __serde::Serializer>(
+            &self,
+            __serializer: __S,
+        ) -> ::std::result::Result<__S::Ok, __S::Error> {
+            let mut __record =
+                __sidex_serde::ser::RecordSerializer::new(__serializer, "RecipeConfig", 12usize)?;
+            __record.serialize_optional_field(
+                "description",
+                ::core::option::Option::as_ref(&self.description),
+            )?;
+            __record.serialize_optional_field(
+                "priority",
+                ::core::option::Option::as_ref(&self.priority),
+            )?;
+            __record.serialize_optional_field(
+                "dependencies",
+                ::core::option::Option::as_ref(&self.dependencies),
+            )?;
+            __record
+                .serialize_optional_field("after", ::core::option::Option::as_ref(&self.after))?;
+            __record
+                .serialize_optional_field("before", ::core::option::Option::as_ref(&self.before))?;
+            __record.serialize_optional_field(
+                "conflicts",
+                ::core::option::Option::as_ref(&self.conflicts),
+            )?;
+            __record.serialize_optional_field(
+                "provides",
+                ::core::option::Option::as_ref(&self.provides),
+            )?;
+            __record.serialize_optional_field(
+                "parameters",
+                ::core::option::Option::as_ref(&self.parameters),
+            )?;
+            __record
+                .serialize_optional_field("steps", ::core::option::Option::as_ref(&self.steps))?;
+            __record
+                .serialize_optional_field("groups", ::core::option::Option::as_ref(&self.groups))?;
+            __record
+                .serialize_optional_field("users", ::core::option::Option::as_ref(&self.users))?;
+            __record.serialize_optional_field(
+                "systemd",
+                ::core::option::Option::as_ref(&self.systemd),
+            )?;
+            __record.end()
+        }
+    }
+    #[automatically_derived]
+    impl<'de> __serde::Deserialize<'de> for RecipeConfig {
+        fn deserialize<__D: __serde::Deserializer<'de>>(
+            __deserializer: __D,
+        ) -> ::std::result::Result<Self, __D::Error> {
+            #[doc(hidden)]
+            struct __Visitor {
+                __phantom_vars: ::core::marker::PhantomData<fn(&())>,
+            }
+            impl<'de> __serde::de::Visitor<'de> for __Visitor {
+                type Value = RecipeConfig;
+                fn expecting(
+                    &self,
+                    __formatter: &mut ::core::fmt::Formatter,
+                ) -> ::core::fmt::Result {
+                    ::core::fmt::Formatter::write_str(__formatter, "record RecipeConfig")
+                }
+                #[inline]
+                fn visit_seq<__A>(
+                    self,
+                    mut __seq: __A,
+                ) -> ::core::result::Result<Self::Value, __A::Error>
+                where
+                    __A: __serde::de::SeqAccess<'de>,
+                {
+                    let __field0 = match __serde::de::SeqAccess::next_element::<
+                        ::std::option::Option<::std::string::String>,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(
+                                    0usize,
+                                    &"record with 12 fields",
+                                ),
+                            );
+                        }
+                    };
+                    let __field1 = match __serde::de::SeqAccess::next_element::<
+                        ::std::option::Option<i64>,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(
+                                    1usize,
+                                    &"record with 12 fields",
+                                ),
+                            );
+                        }
+                    };
+                    let __field2 = match __serde::de::SeqAccess::next_element::<
+                        ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(
+                                    2usize,
+                                    &"record with 12 fields",
+                                ),
+                            );
+                        }
+                    };
+                    let __field3 = match __serde::de::SeqAccess::next_element::<
+                        ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(
+                                    3usize,
+                                    &"record with 12 fields",
+                                ),
+                            );
+                        }
+                    };
+                    let __field4 = match __serde::de::SeqAccess::next_element::<
+                        ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(
+                                    4usize,
+                                    &"record with 12 fields",
+                                ),
+                            );
+                        }
+                    };
+                    let __field5 = match __serde::de::SeqAccess::next_element::<
+                        ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(
+                                    5usize,
+                                    &"record with 12 fields",
+                                ),
+                            );
+                        }
+                    };
+                    let __field6 = match __serde::de::SeqAccess::next_element::<
+                        ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(
+                                    6usize,
+                                    &"record with 12 fields",
+                                ),
+                            );
+                        }
+                    };
+                    let __field7 = match __serde::de::SeqAccess::next_element::<
+                        ::std::option::Option<
+                            ::std::collections::HashMap<::std::string::String, ParameterDef>,
+                        >,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(
+                                    7usize,
+                                    &"record with 12 fields",
+                                ),
+                            );
+                        }
+                    };
+                    let __field8 = match __serde::de::SeqAccess::next_element::<
+                        ::std::option::Option<
+                            ::std::collections::HashMap<::std::string::String, StepConfig>,
+                        >,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(
+                                    8usize,
+                                    &"record with 12 fields",
+                                ),
+                            );
+                        }
+                    };
+                    let __field9 = match __serde::de::SeqAccess::next_element::<
+                        ::std::option::Option<::std::vec::Vec<GroupConfig>>,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(
+                                    9usize,
+                                    &"record with 12 fields",
+                                ),
+                            );
+                        }
+                    };
+                    let __field10 = match __serde::de::SeqAccess::next_element::<
+                        ::std::option::Option<::std::vec::Vec<UserConfig>>,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(
+                                    10usize,
+                                    &"record with 12 fields",
+                                ),
+                            );
+                        }
+                    };
+                    let __field11 = match __serde::de::SeqAccess::next_element::<
+                        ::std::option::Option<SystemdConfig>,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(
+                                    11usize,
+                                    &"record with 12 fields",
+                                ),
+                            );
+                        }
+                    };
+                    ::core::result::Result::Ok(RecipeConfig {
+                        description: __field0,
+                        priority: __field1,
+                        dependencies: __field2,
+                        after: __field3,
+                        before: __field4,
+                        conflicts: __field5,
+                        provides: __field6,
+                        parameters: __field7,
+                        steps: __field8,
+                        groups: __field9,
+                        users: __field10,
+                        systemd: __field11,
+                    })
+                }
+                #[inline]
+                fn visit_map<__A>(
+                    self,
+                    mut __map: __A,
+                ) -> ::core::result::Result<Self::Value, __A::Error>
+                where
+                    __A: __serde::de::MapAccess<'de>,
+                {
+                    #[doc(hidden)]
+                    const __IDENTIFIERS: &'static [&'static str] = &[
+                        "description",
+                        "priority",
+                        "dependencies",
+                        "after",
+                        "before",
+                        "conflicts",
+                        "provides",
+                        "parameters",
+                        "steps",
+                        "groups",
+                        "users",
+                        "systemd",
+                    ];
+                    #[doc(hidden)]
+                    const __EXPECTING_IDENTIFIERS : & 'static str = "an identifier in [\"description\", \"priority\", \"dependencies\", \"after\", \"before\", \"conflicts\", \"provides\", \"parameters\", \"steps\", \"groups\", \"users\", \"systemd\"]" ;
+                    #[derive(:: core :: clone :: Clone, :: core :: marker :: Copy)]
+                    #[doc(hidden)]
+                    enum __Identifier {
+                        __Identifier0,
+                        __Identifier1,
+                        __Identifier2,
+                        __Identifier3,
+                        __Identifier4,
+                        __Identifier5,
+                        __Identifier6,
+                        __Identifier7,
+                        __Identifier8,
+                        __Identifier9,
+                        __Identifier10,
+                        __Identifier11,
+                        __Unknown,
+                    }
+                    #[doc(hidden)]
+                    struct __IdentifierVisitor;
+                    impl<'de> __serde::de::Visitor<'de> for __IdentifierVisitor {
+                        type Value = __Identifier;
+                        fn expecting(
+                            &self,
+                            __formatter: &mut ::core::fmt::Formatter,
+                        ) -> ::core::fmt::Result {
+                            ::core::fmt::Formatter::write_str(__formatter, __EXPECTING_IDENTIFIERS)
+                        }
+                        fn visit_u64<__E>(
+                            self,
+                            __value: u64,
+                        ) -> ::core::result::Result<Self::Value, __E>
+                        where
+                            __E: __serde::de::Error,
+                        {
+                            match __value {
+                                0u64 => ::core::result::Result::Ok(__Identifier::__Identifier0),
+                                1u64 => ::core::result::Result::Ok(__Identifier::__Identifier1),
+                                2u64 => ::core::result::Result::Ok(__Identifier::__Identifier2),
+                                3u64 => ::core::result::Result::Ok(__Identifier::__Identifier3),
+                                4u64 => ::core::result::Result::Ok(__Identifier::__Identifier4),
+                                5u64 => ::core::result::Result::Ok(__Identifier::__Identifier5),
+                                6u64 => ::core::result::Result::Ok(__Identifier::__Identifier6),
+                                7u64 => ::core::result::Result::Ok(__Identifier::__Identifier7),
+                                8u64 => ::core::result::Result::Ok(__Identifier::__Identifier8),
+                                9u64 => ::core::result::Result::Ok(__Identifier::__Identifier9),
+                                10u64 => ::core::result::Result::Ok(__Identifier::__Identifier10),
+                                11u64 => ::core::result::Result::Ok(__Identifier::__Identifier11),
+                                _ => ::core::result::Result::Ok(__Identifier::__Unknown),
+                            }
+                        }
+                        fn visit_str<__E>(
+                            self,
+                            __value: &str,
+                        ) -> ::core::result::Result<Self::Value, __E>
+                        where
+                            __E: __serde::de::Error,
+                        {
+                            match __value {
+                                "description" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier0)
+                                }
+                                "priority" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier1)
+                                }
+                                "dependencies" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier2)
+                                }
+                                "after" => ::core::result::Result::Ok(__Identifier::__Identifier3),
+                                "before" => ::core::result::Result::Ok(__Identifier::__Identifier4),
+                                "conflicts" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier5)
+                                }
+                                "provides" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier6)
+                                }
+                                "parameters" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier7)
+                                }
+                                "steps" => ::core::result::Result::Ok(__Identifier::__Identifier8),
+                                "groups" => ::core::result::Result::Ok(__Identifier::__Identifier9),
+                                "users" => ::core::result::Result::Ok(__Identifier::__Identifier10),
+                                "systemd" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier11)
+                                }
+                                _ => ::core::result::Result::Ok(__Identifier::__Unknown),
+                            }
+                        }
+                        fn visit_bytes<__E>(
+                            self,
+                            __value: &[u8],
+                        ) -> ::core::result::Result<Self::Value, __E>
+                        where
+                            __E: __serde::de::Error,
+                        {
+                            match __value {
+                                b"description" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier0)
+                                }
+                                b"priority" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier1)
+                                }
+                                b"dependencies" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier2)
+                                }
+                                b"after" => ::core::result::Result::Ok(__Identifier::__Identifier3),
+                                b"before" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier4)
+                                }
+                                b"conflicts" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier5)
+                                }
+                                b"provides" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier6)
+                                }
+                                b"parameters" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier7)
+                                }
+                                b"steps" => ::core::result::Result::Ok(__Identifier::__Identifier8),
+                                b"groups" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier9)
+                                }
+                                b"users" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier10)
+                                }
+                                b"systemd" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier11)
+                                }
+                                _ => ::core::result::Result::Ok(__Identifier::__Unknown),
+                            }
+                        }
+                    }
+                    impl<'de> __serde::Deserialize<'de> for __Identifier {
+                        #[inline]
+                        fn deserialize<__D>(
+                            __deserializer: __D,
+                        ) -> ::core::result::Result<Self, __D::Error>
+                        where
+                            __D: __serde::Deserializer<'de>,
+                        {
+                            __serde::Deserializer::deserialize_identifier(
+                                __deserializer,
+                                __IdentifierVisitor,
+                            )
+                        }
+                    }
+                    let mut __field0: ::core::option::Option<
+                        ::std::option::Option<::std::string::String>,
+                    > = ::core::option::Option::None;
+                    let mut __field1: ::core::option::Option<::std::option::Option<i64>> =
+                        ::core::option::Option::None;
+                    let mut __field2: ::core::option::Option<
+                        ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+                    > = ::core::option::Option::None;
+                    let mut __field3: ::core::option::Option<
+                        ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+                    > = ::core::option::Option::None;
+                    let mut __field4: ::core::option::Option<
+                        ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+                    > = ::core::option::Option::None;
+                    let mut __field5: ::core::option::Option<
+                        ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+                    > = ::core::option::Option::None;
+                    let mut __field6: ::core::option::Option<
+                        ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+                    > = ::core::option::Option::None;
+                    let mut __field7: ::core::option::Option<
+                        ::std::option::Option<
+                            ::std::collections::HashMap<::std::string::String, ParameterDef>,
+                        >,
+                    > = ::core::option::Option::None;
+                    let mut __field8: ::core::option::Option<
+                        ::std::option::Option<
+                            ::std::collections::HashMap<::std::string::String, StepConfig>,
+                        >,
+                    > = ::core::option::Option::None;
+                    let mut __field9: ::core::option::Option<
+                        ::std::option::Option<::std::vec::Vec<GroupConfig>>,
+                    > = ::core::option::Option::None;
+                    let mut __field10: ::core::option::Option<
+                        ::std::option::Option<::std::vec::Vec<UserConfig>>,
+                    > = ::core::option::Option::None;
+                    let mut __field11: ::core::option::Option<
+                        ::std::option::Option<SystemdConfig>,
+                    > = ::core::option::Option::None;
+                    while let ::core::option::Option::Some(__key) =
+                        __serde::de::MapAccess::next_key::<__Identifier>(&mut __map)?
+                    {
+                        match __key {
+                            __Identifier::__Identifier0 => {
+                                if ::core::option::Option::is_some(&__field0) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field(
+                                            "description",
+                                        ),
+                                    );
+                                }
+                                __field0 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<
+                                        ::std::option::Option<::std::string::String>,
+                                    >(&mut __map)?,
+                                );
+                            }
+                            __Identifier::__Identifier1 => {
+                                if ::core::option::Option::is_some(&__field1) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field(
+                                            "priority",
+                                        ),
+                                    );
+                                }
+                                __field1 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<::std::option::Option<i64>>(
+                                        &mut __map,
+                                    )?,
+                                );
+                            }
+                            __Identifier::__Identifier2 => {
+                                if ::core::option::Option::is_some(&__field2) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field(
+                                            "dependencies",
+                                        ),
+                                    );
+                                }
+                                __field2 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<
+                                        ::std::option::Option<
+                                            ::std::vec::Vec<::std::string::String>,
+                                        >,
+                                    >(&mut __map)?,
+                                );
+                            }
+                            __Identifier::__Identifier3 => {
+                                if ::core::option::Option::is_some(&__field3) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field(
+                                            "after",
+                                        ),
+                                    );
+                                }
+                                __field3 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<
+                                        ::std::option::Option<
+                                            ::std::vec::Vec<::std::string::String>,
+                                        >,
+                                    >(&mut __map)?,
+                                );
+                            }
+                            __Identifier::__Identifier4 => {
+                                if ::core::option::Option::is_some(&__field4) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field(
+                                            "before",
+                                        ),
+                                    );
+                                }
+                                __field4 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<
+                                        ::std::option::Option<
+                                            ::std::vec::Vec<::std::string::String>,
+                                        >,
+                                    >(&mut __map)?,
+                                );
+                            }
+                            __Identifier::__Identifier5 => {
+                                if ::core::option::Option::is_some(&__field5) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field(
+                                            "conflicts",
+                                        ),
+                                    );
+                                }
+                                __field5 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<
+                                        ::std::option::Option<
+                                            ::std::vec::Vec<::std::string::String>,
+                                        >,
+                                    >(&mut __map)?,
+                                );
+                            }
+                            __Identifier::__Identifier6 => {
+                                if ::core::option::Option::is_some(&__field6) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field(
+                                            "provides",
+                                        ),
+                                    );
+                                }
+                                __field6 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<
+                                        ::std::option::Option<
+                                            ::std::vec::Vec<::std::string::String>,
+                                        >,
+                                    >(&mut __map)?,
+                                );
+                            }
+                            __Identifier::__Identifier7 => {
+                                if ::core::option::Option::is_some(&__field7) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field(
+                                            "parameters",
+                                        ),
+                                    );
+                                }
+                                __field7 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<
+                                        ::std::option::Option<
+                                            ::std::collections::HashMap<
+                                                ::std::string::String,
+                                                ParameterDef,
+                                            >,
+                                        >,
+                                    >(&mut __map)?,
+                                );
+                            }
+                            __Identifier::__Identifier8 => {
+                                if ::core::option::Option::is_some(&__field8) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field(
+                                            "steps",
+                                        ),
+                                    );
+                                }
+                                __field8 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<
+                                        ::std::option::Option<
+                                            ::std::collections::HashMap<
+                                                ::std::string::String,
+                                                StepConfig,
+                                            >,
+                                        >,
+                                    >(&mut __map)?,
+                                );
+                            }
+                            __Identifier::__Identifier9 => {
+                                if ::core::option::Option::is_some(&__field9) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field(
+                                            "groups",
+                                        ),
+                                    );
+                                }
+                                __field9 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<
+                                        ::std::option::Option<::std::vec::Vec<GroupConfig>>,
+                                    >(&mut __map)?,
+                                );
+                            }
+                            __Identifier::__Identifier10 => {
+                                if ::core::option::Option::is_some(&__field10) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field(
+                                            "users",
+                                        ),
+                                    );
+                                }
+                                __field10 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<
+                                        ::std::option::Option<::std::vec::Vec<UserConfig>>,
+                                    >(&mut __map)?,
+                                );
+                            }
+                            __Identifier::__Identifier11 => {
+                                if ::core::option::Option::is_some(&__field11) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field(
+                                            "systemd",
+                                        ),
+                                    );
+                                }
+                                __field11 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<
+                                        ::std::option::Option<SystemdConfig>,
+                                    >(&mut __map)?,
+                                );
+                            }
+                            _ => {
+                                __serde::de::MapAccess::next_value::<__serde::de::IgnoredAny>(
+                                    &mut __map,
+                                )?;
+                            }
+                        }
+                    }
+                    let __field0 = match __field0 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    };
+                    let __field1 = match __field1 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    };
+                    let __field2 = match __field2 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    };
+                    let __field3 = match __field3 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    };
+                    let __field4 = match __field4 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    };
+                    let __field5 = match __field5 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    };
+                    let __field6 = match __field6 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    };
+                    let __field7 = match __field7 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    };
+                    let __field8 = match __field8 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    };
+                    let __field9 = match __field9 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    };
+                    let __field10 = match __field10 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    };
+                    let __field11 = match __field11 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    };
+                    ::core::result::Result::Ok(RecipeConfig {
+                        description: __field0,
+                        priority: __field1,
+                        dependencies: __field2,
+                        after: __field3,
+                        before: __field4,
+                        conflicts: __field5,
+                        provides: __field6,
+                        parameters: __field7,
+                        steps: __field8,
+                        groups: __field9,
+                        users: __field10,
+                        systemd: __field11,
+                    })
+                }
+            }
+            #[doc(hidden)]
+            const __FIELDS: &'static [&'static str] = &[
+                "description",
+                "priority",
+                "dependencies",
+                "after",
+                "before",
+                "conflicts",
+                "provides",
+                "parameters",
+                "steps",
+                "groups",
+                "users",
+                "systemd",
+            ];
+            __serde::Deserializer::deserialize_struct(
+                __deserializer,
+                "RecipeConfig",
+                __FIELDS,
+                __Visitor {
+                    __phantom_vars: ::core::marker::PhantomData,
+                },
+            )
+        }
+    }
+    #[doc = "Configuration of a recipe step.\n"]
+    #[derive(Clone, Debug)]
+    pub struct StepConfig {
+        #[doc = "Condition under which the step is run.\n"]
+        pub when: ::std::option::Option<::std::string::String>,
+    }
+    impl StepConfig {
+        #[doc = "Creates a new [`StepConfig`]."]
+        pub fn new() -> Self {
+            Self {
+                when: ::std::default::Default::default(),
+            }
+        }
+        #[doc = "Sets the value of `when`."]
+        pub fn set_when(
+            &mut self,
+            when: ::std::option::Option<::std::string::String>,
+        ) -> &mut Self {
+            self.when = when;
+            self
+        }
+        #[doc = "Sets the value of `when`."]
+        pub fn with_when(mut self, when: ::std::option::Option<::std::string::String>) -> Self {
+            self.when = when;
+            self
+        }
+    }
+    impl ::std::default::Default for StepConfig {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+    #[automatically_derived]
+    impl __serde::Serialize for StepConfig {
+        fn serialize<__S: __serde::Serializer>(
+            &self,
+            __serializer: __S,
+        ) -> ::std::result::Result<__S::Ok, __S::Error> {
+            let mut __record =
+                __sidex_serde::ser::RecordSerializer::new(__serializer, "StepConfig", 1usize)?;
+            __record
+                .serialize_optional_field("when", ::core::option::Option::as_ref(&self.when))?;
+            __record.end()
+        }
+    }
+    #[automatically_derived]
+    impl<'de> __serde::Deserialize<'de> for StepConfig {
+        fn deserialize<__D: __serde::Deserializer<'de>>(
+            __deserializer: __D,
+        ) -> ::std::result::Result<Self, __D::Error> {
+            #[doc(hidden)]
+            struct __Visitor {
+                __phantom_vars: ::core::marker::PhantomData<fn(&())>,
+            }
+            impl<'de> __serde::de::Visitor<'de> for __Visitor {
+                type Value = StepConfig;
+                fn expecting(
+                    &self,
+                    __formatter: &mut ::core::fmt::Formatter,
+                ) -> ::core::fmt::Result {
+                    ::core::fmt::Formatter::write_str(__formatter, "record StepConfig")
+                }
+                #[inline]
+                fn visit_seq<__A>(
+                    self,
+                    mut __seq: __A,
+                ) -> ::core::result::Result<Self::Value, __A::Error>
+                where
+                    __A: __serde::de::SeqAccess<'de>,
+                {
+                    let __field0 = match __serde::de::SeqAccess::next_element::<
+                        ::std::option::Option<::std::string::String>,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(0usize, &"record with 1 fields"),
+                            );
+                        }
+                    };
+                    ::core::result::Result::Ok(StepConfig { when: __field0 })
+                }
+                #[inline]
+                fn visit_map<__A>(
+                    self,
+                    mut __map: __A,
+                ) -> ::core::result::Result<Self::Value, __A::Error>
+                where
+                    __A: __serde::de::MapAccess<'de>,
+                {
+                    #[doc(hidden)]
+                    const __IDENTIFIERS: &'static [&'static str] = &["when"];
+                    #[doc(hidden)]
+                    const __EXPECTING_IDENTIFIERS: &'static str = "an identifier in [\"when\"]";
+                    #[derive(:: core :: clone :: Clone, :: core :: marker :: Copy)]
+                    #[doc(hidden)]
+                    enum __Identifier {
+                        __Identifier0,
+                        __Unknown,
+                    }
+                    #[doc(hidden)]
+                    struct __IdentifierVisitor;
+                    impl<'de> __serde::de::Visitor<'de> for __IdentifierVisitor {
+                        type Value = __Identifier;
+                        fn expecting(
+                            &self,
+                            __formatter: &mut ::core::fmt::Formatter,
+                        ) -> ::core::fmt::Result {
+                            ::core::fmt::Formatter::write_str(__formatter, __EXPECTING_IDENTIFIERS)
+                        }
+                        fn visit_u64<__E>(
+                            self,
+                            __value: u64,
+                        ) -> ::core::result::Result<Self::Value, __E>
+                        where
+                            __E: __serde::de::Error,
+                        {
+                            match __value {
+                                0u64 => ::core::result::Result::Ok(__Identifier::__Identifier0),
+                                _ => ::core::result::Result::Ok(__Identifier::__Unknown),
+                            }
+                        }
+                        fn visit_str<__E>(
+                            self,
+                            __value: &str,
+                        ) -> ::core::result::Result<Self::Value, __E>
+                        where
+                            __E: __serde::de::Error,
+                        {
+                            match __value {
+                                "when" => ::core::result::Result::Ok(__Identifier::__Identifier0),
+                                _ => ::core::result::Result::Ok(__Identifier::__Unknown),
+                            }
+                        }
+                        fn visit_bytes<__E>(
+                            self,
+                            __value: &[u8],
+                        ) -> ::core::result::Result<Self::Value, __E>
+                        where
+                            __E: __serde::de::Error,
+                        {
+                            match __value {
+                                b"when" => ::core::result::Result::Ok(__Identifier::__Identifier0),
+                                _ => ::core::result::Result::Ok(__Identifier::__Unknown),
+                            }
+                        }
+                    }
+                    impl<'de> __serde::Deserialize<'de> for __Identifier {
+                        #[inline]
+                        fn deserialize<__D>(
+                            __deserializer: __D,
+                        ) -> ::core::result::Result<Self, __D::Error>
+                        where
+                            __D: __serde::Deserializer<'de>,
+                        {
+                            __serde::Deserializer::deserialize_identifier(
+                                __deserializer,
+                                __IdentifierVisitor,
+                            )
+                        }
+                    }
+                    let mut __field0: ::core::option::Option<
+                        ::std::option::Option<::std::string::String>,
+                    > = ::core::option::Option::None;
+                    while let ::core::option::Option::Some(__key) =
+                        __serde::de::MapAccess::next_key::<__Identifier>(&mut __map)?
+                    {
+                        match __key {
+                            __Identifier::__Identifier0 => {
+                                if ::core::option::Option::is_some(&__field0) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field("when"),
+                                    );
+                                }
+                                __field0 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<
+                                        ::std::option::Option<::std::string::String>,
+                                    >(&mut __map)?,
+                                );
+                            }
+                            _ => {
+                                __serde::de::MapAccess::next_value::<__serde::de::IgnoredAny>(
+                                    &mut __map,
+                                )?;
+                            }
+                        }
+                    }
+                    let __field0 = match __field0 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    };
+                    ::core::result::Result::Ok(StepConfig { when: __field0 })
+                }
+            }
+            #[doc(hidden)]
+            const __FIELDS: &'static [&'static str] = &["when"];
+            __serde::Deserializer::deserialize_struct(
+                __deserializer,
+                "StepConfig",
+                __FIELDS,
+                __Visitor {
+                    __phantom_vars: ::core::marker::PhantomData,
+                },
+            )
+        }
+    }
+    #[doc = "Recipe parameter definition.\n"]
+    #[derive(Clone, Debug)]
+    pub struct ParameterDef {
+        #[doc = "Type of the parameter.\n\nValues of parameters without a type are not type-checked.\n"]
+        pub ty: ::std::option::Option<ParameterType>,
+        #[doc = "Description of the parameter.\n"]
+        pub description: ::std::option::Option<::std::string::String>,
+        #[doc = "Optional default value of the parameter.\n"]
+        pub default: ::std::option::Option<ParameterValue>,
+        #[doc = "Indicates whether a value is required (defaults to `true` without a default).\n"]
+        pub required: ::std::option::Option<bool>,
+        #[doc = "Allowed values of the parameter (required for `enum` parameters).\n"]
+        pub values: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+        #[doc = "Regular expression which string values must match.\n"]
+        pub pattern: ::std::option::Option<::std::string::String>,
+        #[doc = "Type of the items of `list` parameters (defaults to `string`).\n"]
+        pub items: ::std::option::Option<ParameterType>,
+        #[doc = "Indicates whether the value is secret and must not be logged or recorded.\n\nAs the values of secret parameters are not recorded, changing them does not\ninvalidate cached layers.\n"]
+        pub secret: ::std::option::Option<bool>,
+    }
+    impl ParameterDef {
+        #[doc = "Creates a new [`ParameterDef`]."]
+        pub fn new() -> Self {
+            Self {
+                ty: ::std::default::Default::default(),
+                description: ::std::default::Default::default(),
+                default: ::std::default::Default::default(),
+                required: ::std::default::Default::default(),
+                values: ::std::default::Default::default(),
+                pattern: ::std::default::Default::default(),
+                items: ::std::default::Default::default(),
+                secret: ::std::default::Default::default(),
+            }
+        }
+        #[doc = "Sets the value of `ty`."]
+        pub fn set_ty(&mut self, ty: ::std::option::Option<ParameterType>) -> &mut Self {
+            self.ty = ty;
+            self
+        }
+        #[doc = "Sets the value of `ty`."]
+        pub fn with_ty(mut self, ty: ::std::option::Option<ParameterType>) -> Self {
+            self.ty = ty;
+            self
+        }
+        #[doc = "Sets the value of `description`."]
+        pub fn set_description(
+            &mut self,
+            description: ::std::option::Option<::std::string::String>,
+        ) -> &mut Self {
+            self.description = description;
+            self
+        }
+        #[doc = "Sets the value of `description`."]
+        pub fn with_description(
+            mut self,
+            description: ::std::option::Option<::std::string::String>,
+        ) -> Self {
+            self.description = description;
+            self
+        }
+        #[doc = "Sets the value of `default`."]
+        pub fn set_default(&mut self, default: ::std::option::Option<ParameterValue>) -> &mut Self {
+            self.default = default;
+            self
+        }
+        #[doc = "Sets the value of `default`."]
+        pub fn with_default(mut self, default: ::std::option::Option<ParameterValue>) -> Self {
+            self.default = default;
+            self
+        }
+        #[doc = "Sets the value of `required`."]
+        pub fn set_required(&mut self, required: ::std::option::Option<bool>) -> &mut Self {
+            self.required = required;
+            self
+        }
+        #[doc = "Sets the value of `required`."]
+        pub fn with_required(mut self, required: ::std::option::Option<bool>) -> Self {
+            self.required = required;
+            self
+        }
+        #[doc = "Sets the value of `values`."]
+        pub fn set_values(
+            &mut self,
+            values: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+        ) -> &mut Self {
+            self.values = values;
+            self
+        }
+        #[doc = "Sets the value of `values`."]
+        pub fn with_values(
+            mut self,
+            values: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+        ) -> Self {
+            self.values = values;
+            self
+        }
+        #[doc = "Sets the value of `pattern`."]
+        pub fn set_pattern(
+            &mut self,
+            pattern: ::std::option::Option<::std::string::String>,
+        ) -> &mut Self {
+            self.pattern = pattern;
+            self
+        }
+        #[doc = "Sets the value of `pattern`."]
+        pub fn with_pattern(
+            mut self,
+            pattern: ::std::option::Option<::std::string::String>,
+        ) -> Self {
+            self.pattern = pattern;
+            self
+        }
+        #[doc = "Sets the value of `items`."]
+        pub fn set_items(&mut self, items: ::std::option::Option<ParameterType>) -> &mut Self {
+            self.items = items;
+            self
+        }
+        #[doc = "Sets the value of `items`."]
+        pub fn with_items(mut self, items: ::std::option::Option<ParameterType>) -> Self {
+            self.items = items;
+            self
+        }
+        #[doc = "Sets the value of `secret`."]
+        pub fn set_secret(&mut self, secret: ::std::option::Option<bool>) -> &mut Self {
+            self.secret = secret;
+            self
+        }
+        #[doc = "Sets the value of `secret`."]
+        pub fn with_secret(mut self, secret: ::std::option::Option<bool>) -> Self {
+            self.secret = secret;
+            self
+        }
+    }
+    impl ::std::default::Default for ParameterDef {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+    #[automatically_derived]
+    impl __serde::Serialize for ParameterDef {
+        fn serialize<__S: __serde::Serializer>(
+            &self,
+            __serializer: __S,
+        ) -> ::std::result::Result<__S::Ok, __S::Error> {
+            let mut __record =
+                __sidex_serde::ser::RecordSerializer::new(__serializer, "ParameterDef", 8usize)?;
+            __record.serialize_optional_field("type", ::core::option::Option::as_ref(&self.ty))?;
+            __record.serialize_optional_field(
+                "description",
+                ::core::option::Option::as_ref(&self.description),
+            )?;
+            __record.serialize_optional_field(
+                "default",
+                ::core::option::Option::as_ref(&self.default),
+            )?;
+            __record.serialize_optional_field(
+                "required",
+                ::core::option::Option::as_ref(&self.required),
+            )?;
+            __record
+                .serialize_optional_field("values", ::core::option::Option::as_ref(&self.values))?;
+            __record.serialize_optional_field(
+                "pattern",
+                ::core::option::Option::as_ref(&self.pattern),
+            )?;
+            __record
+                .serialize_optional_field("items", ::core::option::Option::as_ref(&self.items))?;
+            __record
+                .serialize_optional_field("secret", ::core::option::Option::as_ref(&self.secret))?;
+            __record.end()
+        }
+    }
+    #[automatically_derived]
+    impl<'de> __serde::Deserialize<'de> for ParameterDef {
+        fn deserialize<__D: __serde::Deserializer<'de>>(
+            __deserializer: __D,
+        ) -> ::std::result::Result<Self, __D::Error> {
+            #[doc(hidden)]
+            struct __Visitor {
+                __phantom_vars: ::core::marker::PhantomData<fn(&())>,
+            }
+            impl<'de> __serde::de::Visitor<'de> for __Visitor {
+                type Value = ParameterDef;
+                fn expecting(
+                    &self,
+                    __formatter: &mut ::core::fmt::Formatter,
+                ) -> ::core::fmt::Result {
+                    ::core::fmt::Formatter::write_str(__formatter, "record ParameterDef")
+                }
+                #[inline]
+                fn visit_seq<__A>(
+                    self,
+                    mut __seq: __A,
+                ) -> ::core::result::Result<Self::Value, __A::Error>
+                where
+                    __A: __serde::de::SeqAccess<'de>,
+                {
+                    let __field0 = match __serde::de::SeqAccess::next_element::<
+                        ::std::option::Option<ParameterType>,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(0usize, &"record with 8 fields"),
+                            );
+                        }
+                    };
+                    let __field1 = match __serde::de::SeqAccess::next_element::<
+                        ::std::option::Option<::std::string::String>,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(1usize, &"record with 8 fields"),
+                            );
+                        }
+                    };
+                    let __field2 = match __serde::de::SeqAccess::next_element::<
+                        ::std::option::Option<ParameterValue>,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(2usize, &"record with 8 fields"),
+                            );
+                        }
+                    };
+                    let __field3 = match __serde::de::SeqAccess::next_element::<
+                        ::std::option::Option<bool>,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(3usize, &"record with 8 fields"),
+                            );
+                        }
+                    };
+                    let __field4 = match __serde::de::SeqAccess::next_element::<
+                        ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(4usize, &"record with 8 fields"),
+                            );
+                        }
+                    };
+                    let __field5 = match __serde::de::SeqAccess::next_element::<
+                        ::std::option::Option<::std::string::String>,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(5usize, &"record with 8 fields"),
+                            );
+                        }
+                    };
+                    let __field6 = match __serde::de::SeqAccess::next_element::<
+                        ::std::option::Option<ParameterType>,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(6usize, &"record with 8 fields"),
+                            );
+                        }
+                    };
+                    let __field7 = match __serde::de::SeqAccess::next_element::<
+                        ::std::option::Option<bool>,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(7usize, &"record with 8 fields"),
+                            );
+                        }
+                    };
+                    ::core::result::Result::Ok(ParameterDef {
+                        ty: __field0,
+                        description: __field1,
+                        default: __field2,
+                        required: __field3,
+                        values: __field4,
+                        pattern: __field5,
+                        items: __field6,
+                        secret: __field7,
+                    })
+                }
+                #[inline]
+                fn visit_map<__A>(
+                    self,
+                    mut __map: __A,
+                ) -> ::core::result::Result<Self::Value, __A::Error>
+                where
+                    __A: __serde::de::MapAccess<'de>,
+                {
+                    #[doc(hidden)]
+                    const __IDENTIFIERS: &'static [&'static str] = &[
+                        "type",
+                        "description",
+                        "default",
+                        "required",
+                        "values",
+                        "pattern",
+                        "items",
+                        "secret",
+                    ];
+                    #[doc(hidden)]
+                    const __EXPECTING_IDENTIFIERS : & 'static str = "an identifier in [\"type\", \"description\", \"default\", \"required\", \"values\", \"pattern\", \"items\", \"secret\"]" ;
+                    #[derive(:: core :: clone :: Clone, :: core :: marker :: Copy)]
+                    #[doc(hidden)]
+                    enum __Identifier {
+                        __Identifier0,
+                        __Identifier1,
+                        __Identifier2,
+                        __Identifier3,
+                        __Identifier4,
+                        __Identifier5,
+                        __Identifier6,
+                        __Identifier7,
+                        __Unknown,
+                    }
+                    #[doc(hidden)]
+                    struct __IdentifierVisitor;
+                    impl<'de> __serde::de::Visitor<'de> for __IdentifierVisitor {
+                        type Value = __Identifier;
+                        fn expecting(
+                            &self,
+                            __formatter: &mut ::core::fmt::Formatter,
+                        ) -> ::core::fmt::Result {
+                            ::core::fmt::Formatter::write_str(__formatter, __EXPECTING_IDENTIFIERS)
+                        }
+                        fn visit_u64<__E>(
+                            self,
+                            __value: u64,
+                        ) -> ::core::result::Result<Self::Value, __E>
+                        where
+                            __E: __serde::de::Error,
+                        {
+                            match __value {
+                                0u64 => ::core::result::Result::Ok(__Identifier::__Identifier0),
+                                1u64 => ::core::result::Result::Ok(__Identifier::__Identifier1),
+                                2u64 => ::core::result::Result::Ok(__Identifier::__Identifier2),
+                                3u64 => ::core::result::Result::Ok(__Identifier::__Identifier3),
+                                4u64 => ::core::result::Result::Ok(__Identifier::__Identifier4),
+                                5u64 => ::core::result::Result::Ok(__Identifier::__Identifier5),
+                                6u64 => ::core::result::Result::Ok(__Identifier::__Identifier6),
+                                7u64 => ::core::result::Result::Ok(__Identifier::__Identifier7),
+                                _ => ::core::result::Result::Ok(__Identifier::__Unknown),
+                            }
+                        }
+                        fn visit_str<__E>(
+                            self,
+                            __value: &str,
+                        ) -> ::core::result::Result<Self::Value, __E>
+                        where
+                            __E: __serde::de::Error,
+                        {
+                            match __value {
+                                "type" => ::core::result::Result::Ok(__Identifier::__Identifier0),
+                                "description" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier1)
+                                }
+                                "default" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier2)
+                                }
+                                "required" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier3)
+                                }
+                                "values" => ::core::result::Result::Ok(__Identifier::__Identifier4),
+                                "pattern" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier5)
+                                }
+                                "items" => ::core::result::Result::Ok(__Identifier::__Identifier6),
+                                "secret" => ::core::result::Result::Ok(__Identifier::__Identifier7),
+                                _ => ::core::result::Result::Ok(__Identifier::__Unknown),
+                            }
+                        }
+                        fn visit_bytes<__E>(
+                            self,
+                            __value: &[u8],
+                        ) -> ::core::result::Result<Self::Value, __E>
+                        where
+                            __E: __serde::de::Error,
+                        {
+                            match __value {
+                                b"type" => ::core::result::Result::Ok(__Identifier::__Identifier0),
+                                b"description" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier1)
+                                }
+                                b"default" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier2)
+                                }
+                                b"required" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier3)
+                                }
+                                b"values" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier4)
+                                }
+                                b"pattern" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier5)
+                                }
+                                b"items" => ::core::result::Result::Ok(__Identifier::__Identifier6),
+                                b"secret" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier7)
+                                }
+                                _ => ::core::result::Result::Ok(__Identifier::__Unknown),
+                            }
+                        }
+                    }
+                    impl<'de> __serde::Deserialize<'de> for __Identifier {
+                        #[inline]
+                        fn deserialize<__D>(
+                            __deserializer: __D,
+                        ) -> ::core::result::Result<Self, __D::Error>
+                        where
+                            __D: __serde::Deserializer<'de>,
+                        {
+                            __serde::Deserializer::deserialize_identifier(
+                                __deserializer,
+                                __IdentifierVisitor,
+                            )
+                        }
+                    }
+                    let mut __field0: ::core::option::Option<::std::option::Option<ParameterType>> =
+                        ::core::option::Option::None;
+                    let mut __field1: ::core::option::Option<
+                        ::std::option::Option<::std::string::String>,
+                    > = ::core::option::Option::None;
+                    let mut __field2: ::core::option::Option<
+                        ::std::option::Option<ParameterValue>,
+                    > = ::core::option::Option::None;
+                    let mut __field3: ::core::option::Option<::std::option::Option<bool>> =
+                        ::core::option::Option::None;
+                    let mut __field4: ::core::option::Option<
+                        ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+                    > = ::core::option::Option::None;
+                    let mut __field5: ::core::option::Option<
+                        ::std::option::Option<::std::string::String>,
+                    > = ::core::option::Option::None;
+                    let mut __field6: ::core::option::Option<::std::option::Option<ParameterType>> =
+                        ::core::option::Option::None;
+                    let mut __field7: ::core::option::Option<::std::option::Option<bool>> =
+                        ::core::option::Option::None;
+                    while let ::core::option::Option::Some(__key) =
+                        __serde::de::MapAccess::next_key::<__Identifier>(&mut __map)?
+                    {
+                        match __key {
+                            __Identifier::__Identifier0 => {
+                                if ::core::option::Option::is_some(&__field0) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field("type"),
+                                    );
+                                }
+                                __field0 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<
+                                        ::std::option::Option<ParameterType>,
+                                    >(&mut __map)?,
+                                );
+                            }
+                            __Identifier::__Identifier1 => {
+                                if ::core::option::Option::is_some(&__field1) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field(
+                                            "description",
+                                        ),
+                                    );
+                                }
+                                __field1 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<
+                                        ::std::option::Option<::std::string::String>,
+                                    >(&mut __map)?,
+                                );
+                            }
+                            __Identifier::__Identifier2 => {
+                                if ::core::option::Option::is_some(&__field2) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field(
+                                            "default",
+                                        ),
+                                    );
+                                }
+                                __field2 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<
+                                        ::std::option::Option<ParameterValue>,
+                                    >(&mut __map)?,
+                                );
+                            }
+                            __Identifier::__Identifier3 => {
+                                if ::core::option::Option::is_some(&__field3) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field(
+                                            "required",
+                                        ),
+                                    );
+                                }
+                                __field3 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<
+                                        ::std::option::Option<bool>,
+                                    >(&mut __map)?,
+                                );
+                            }
+                            __Identifier::__Identifier4 => {
+                                if ::core::option::Option::is_some(&__field4) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field(
+                                            "values",
+                                        ),
+                                    );
+                                }
+                                __field4 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<
+                                        ::std::option::Option<
+                                            ::std::vec::Vec<::std::string::String>,
+                                        >,
+                                    >(&mut __map)?,
+                                );
+                            }
+                            __Identifier::__Identifier5 => {
+                                if ::core::option::Option::is_some(&__field5) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field(
+                                            "pattern",
+                                        ),
+                                    );
+                                }
+                                __field5 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<
+                                        ::std::option::Option<::std::string::String>,
+                                    >(&mut __map)?,
+                                );
+                            }
+                            __Identifier::__Identifier6 => {
+                                if ::core::option::Option::is_some(&__field6) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field(
+                                            "items",
+                                        ),
+                                    );
+                                }
+                                __field6 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<
+                                        ::std::option::Option<ParameterType>,
+                                    >(&mut __map)?,
+                                );
+                            }
+                            __Identifier::__Identifier7 => {
+                                if ::core::option::Option::is_some(&__field7) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field(
+                                            "secret",
+                                        ),
+                                    );
+                                }
+                                __field7 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<
+                                        ::std::option::Option<bool>,
+                                    >(&mut __map)?,
+                                );
+                            }
+                            _ => {
+                                __serde::de::MapAccess::next_value::<__serde::de::IgnoredAny>(
+                                    &mut __map,
+                                )?;
+                            }
+                        }
+                    }
+                    let __field0 = match __field0 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    };
+                    let __field1 = match __field1 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    };
+                    let __field2 = match __field2 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    };
+                    let __field3 = match __field3 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    };
+                    let __field4 = match __field4 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    };
+                    let __field5 = match __field5 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    };
+                    let __field6 = match __field6 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    };
+                    let __field7 = match __field7 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    };
+                    ::core::result::Result::Ok(ParameterDef {
+                        ty: __field0,
+                        description: __field1,
+                        default: __field2,
+                        required: __field3,
+                        values: __field4,
+                        pattern: __field5,
+                        items: __field6,
+                        secret: __field7,
+                    })
+                }
+            }
+            #[doc(hidden)]
+            const __FIELDS: &'static [&'static str] = &[
+                "type",
+                "description",
+                "default",
+                "required",
+                "values",
+                "pattern",
+                "items",
+                "secret",
+            ];
+            __serde::Deserializer::deserialize_struct(
+                __deserializer,
+                "ParameterDef",
+                __FIELDS,
+                __Visitor {
+                    __phantom_vars: ::core::marker::PhantomData,
+                },
+            )
+        }
+    }
+    #[doc = "Type of a parameter.\n"]
+    #[derive(Clone, Debug, Copy, PartialEq, Eq, Hash)]
+    pub enum ParameterType {
+        #[doc = "String.\n"]
+        String,
+        #[doc = "Boolean.\n"]
+        Bool,
+        #[doc = "Integer.\n"]
+        Int,
+        #[doc = "Float.\n"]
+        Float,
+        #[doc = "One of the allowed values.\n"]
+        Enum,
+        #[doc = "List of values.\n"]
+        List,
+    }
+    #[automatically_derived]
+    impl __serde::Serialize for ParameterType {
+        fn serialize<__S: __serde::Serializer>(
+            &self,
+            __serializer: __S,
+        ) -> ::std::result::Result<__S::Ok, __S::Error> {
+            let __serializer =
+                __sidex_serde::ser::VariantSerializer::new(__serializer, "ParameterType");
+            match self {
+                Self::String => __serializer.serialize_tag("string", 0u32),
+                Self::Bool => __serializer.serialize_tag("bool", 1u32),
+                Self::Int => __serializer.serialize_tag("int", 2u32),
+                Self::Float => __serializer.serialize_tag("float", 3u32),
+                Self::Enum => __serializer.serialize_tag("enum", 4u32),
+                Self::List => __serializer.serialize_tag("list", 5u32),
+            }
+        }
+    }
+    #[automatically_derived]
+    impl<'de> __serde::Deserialize<'de> for ParameterType {
+        fn deserialize<__D: __serde::Deserializer<'de>>(
+            __deserializer: __D,
+        ) -> ::std::result::Result<Self, __D::Error> {
+            #[doc(hidden)]
+            const __IDENTIFIERS: &'static [&'static str] =
+                &["string", "bool", "int", "float", "enum", "list"];
+            #[doc(hidden)]
+            const __EXPECTING_IDENTIFIERS: &'static str =
+                "an identifier in [\"string\", \"bool\", \"int\", \"float\", \"enum\", \"list\"]";
+            #[derive(:: core :: clone :: Clone, :: core :: marker :: Copy)]
+            #[doc(hidden)]
+            enum __Identifier {
+                __Identifier0,
+                __Identifier1,
+                __Identifier2,
+                __Identifier3,
+                __Identifier4,
+                __Identifier5,
+            }
+            #[doc(hidden)]
+            struct __IdentifierVisitor;
+            impl<'de> __serde::de::Visitor<'de> for __IdentifierVisitor {
+                type Value = __Identifier;
+                fn expecting(
+                    &self,
+                    __formatter: &mut ::core::fmt::Formatter,
+                ) -> ::core::fmt::Result {
+                    ::core::fmt::Formatter::write_str(__formatter, __EXPECTING_IDENTIFIERS)
+                }
+                fn visit_u64<__E>(self, __value: u64) -> ::core::result::Result<Self::Value, __E>
+                where
+                    __E: __serde::de::Error,
+                {
+                    match __value {
+                        0u64 => ::core::result::Result::Ok(__Identifier::__Identifier0),
+                        1u64 => ::core::result::Result::Ok(__Identifier::__Identifier1),
+                        2u64 => ::core::result::Result::Ok(__Identifier::__Identifier2),
+                        3u64 => ::core::result::Result::Ok(__Identifier::__Identifier3),
+                        4u64 => ::core::result::Result::Ok(__Identifier::__Identifier4),
+                        5u64 => ::core::result::Result::Ok(__Identifier::__Identifier5),
+                        __variant => {
+                            ::core::result::Result::Err(__serde::de::Error::invalid_value(
+                                __serde::de::Unexpected::Unsigned(__variant),
+                                &__EXPECTING_IDENTIFIERS,
+                            ))
+                        }
+                    }
+                }
+                fn visit_str<__E>(self, __value: &str) -> ::core::result::Result<Self::Value, __E>
+                where
+                    __E: __serde::de::Error,
+                {
+                    match __value {
+                        "string" => ::core::result::Result::Ok(__Identifier::__Identifier0),
+                        "bool" => ::core::result::Result::Ok(__Identifier::__Identifier1),
+                        "int" => ::core::result::Result::Ok(__Identifier::__Identifier2),
+                        "float" => ::core::result::Result::Ok(__Identifier::__Identifier3),
+                        "enum" => ::core::result::Result::Ok(__Identifier::__Identifier4),
+                        "list" => ::core::result::Result::Ok(__Identifier::__Identifier5),
+                        __variant => ::core::result::Result::Err(
+                            __serde::de::Error::unknown_variant(__variant, __IDENTIFIERS),
+                        ),
+                    }
+                }
+                fn visit_bytes<__E>(
+                    self,
+                    __value: &[u8],
+                ) -> ::core::result::Result<Self::Value, __E>
+                where
+                    __E: __serde::de::Error,
+                {
+                    match __value {
+                        b"string" => ::core::result::Result::Ok(__Identifier::__Identifier0),
+                        b"bool" => ::core::result::Result::Ok(__Identifier::__Identifier1),
+                        b"int" => ::core::result::Result::Ok(__Identifier::__Identifier2),
+                        b"float" => ::core::result::Result::Ok(__Identifier::__Identifier3),
+                        b"enum" => ::core::result::Result::Ok(__Identifier::__Identifier4),
+                        b"list" => ::core::result::Result::Ok(__Identifier::__Identifier5),
+                        __variant => {
+                            ::core::result::Result::Err(__serde::de::Error::invalid_value(
+                                __serde::de::Unexpected::Bytes(__variant),
+                                &__EXPECTING_IDENTIFIERS,
+                            ))
+                        }
+                    }
+                }
+            }
+            impl<'de> __serde::Deserialize<'de> for __Identifier {
+                #[inline]
+                fn deserialize<__D>(__deserializer: __D) -> ::core::result::Result<Self, __D::Error>
+                where
+                    __D: __serde::Deserializer<'de>,
+                {
+                    __serde::Deserializer::deserialize_identifier(
+                        __deserializer,
+                        __IdentifierVisitor,
+                    )
+                }
+            }
+            #[doc(hidden)]
+            const __VARIANTS: &'static [&'static str] =
+                &["string", "bool", "int", "float", "enum", "list"];
+            #[doc(hidden)]
+            struct __Visitor {
+                __phantom_vars: ::core::marker::PhantomData<fn(&())>,
+            }
+            impl<'de> __serde::de::Visitor<'de> for __Visitor {
+                type Value = ParameterType;
+                fn expecting(
+                    &self,
+                    __formatter: &mut ::core::fmt::Formatter,
+                ) -> ::core::fmt::Result {
+                    ::core::fmt::Formatter::write_str(__formatter, "enum ParameterType")
+                }
+                #[inline]
+                fn visit_str<__E>(self, __value: &str) -> ::core::result::Result<Self::Value, __E>
+                where
+                    __E: __serde::de::Error,
+                {
+                    let __identifier = __IdentifierVisitor.visit_str(__value)?;
+                    #[allow(unreachable_patterns)]
+                    match __identifier {
+                        __Identifier::__Identifier0 => {
+                            ::core::result::Result::Ok(ParameterType::String)
+                        }
+                        __Identifier::__Identifier1 => {
+                            ::core::result::Result::Ok(ParameterType::Bool)
+                        }
+                        __Identifier::__Identifier2 => {
+                            ::core::result::Result::Ok(ParameterType::Int)
+                        }
+                        __Identifier::__Identifier3 => {
+                            ::core::result::Result::Ok(ParameterType::Float)
+                        }
+                        __Identifier::__Identifier4 => {
+                            ::core::result::Result::Ok(ParameterType::Enum)
+                        }
+                        __Identifier::__Identifier5 => {
+                            ::core::result::Result::Ok(ParameterType::List)
+                        }
+                        _ => Err(__E::invalid_value(
+                            __serde::de::Unexpected::Str(__value),
+                            &self,
+                        )),
+                    }
+                }
+                #[inline]
+                fn visit_enum<__A>(
+                    self,
+                    __data: __A,
+                ) -> ::core::result::Result<Self::Value, __A::Error>
+                where
+                    __A: __serde::de::EnumAccess<'de>,
+                {
+                    match __serde::de::EnumAccess::variant::<__Identifier>(__data)? {
+                        (__Identifier::__Identifier0, __variant) => {
+                            __serde::de::VariantAccess::unit_variant(__variant)?;
+                            ::core::result::Result::Ok(ParameterType::String)
+                        }
+                        (__Identifier::__Identifier1, __variant) => {
+                            __serde::de::VariantAccess::unit_variant(__variant)?;
+                            ::core::result::Result::Ok(ParameterType::Bool)
+                        }
+                        (__Identifier::__Identifier2, __variant) => {
+                            __serde::de::VariantAccess::unit_variant(__variant)?;
+                            ::core::result::Result::Ok(ParameterType::Int)
+                        }
+                        (__Identifier::__Identifier3, __variant) => {
+                            __serde::de::VariantAccess::unit_variant(__variant)?;
+                            ::core::result::Result::Ok(ParameterType::Float)
+                        }
+                        (__Identifier::__Identifier4, __variant) => {
+                            __serde::de::VariantAccess::unit_variant(__variant)?;
+                            ::core::result::Result::Ok(ParameterType::Enum)
+                        }
+                        (__Identifier::__Identifier5, __variant) => {
+                            __serde::de::VariantAccess::unit_variant(__variant)?;
+                            ::core::result::Result::Ok(ParameterType::List)
+                        }
+                    }
+                }
+            }
+            __serde::Deserializer::deserialize_enum(
+                __deserializer,
+                "ParameterType",
+                __VARIANTS,
+                __Visitor {
+                    __phantom_vars: ::core::marker::PhantomData,
+                },
+            )
+        }
+    }
+    #[doc = "Value of a parameter.\n"]
+    #[derive(Clone, Debug)]
+    pub enum ParameterValue {
+        #[doc = "String.\n"]
+        String(::std::string::String),
+        #[doc = "Boolean.\n"]
+        Boolean(bool),
+        #[doc = "Integer.\n"]
+        Integer(i64),
+        #[doc = "Float.\n"]
+        Float(f64),
+        #[doc = "List.\n"]
+        List(::std::vec::Vec<ParameterValue>),
+    }
+    #[automatically_derived]
+    impl __serde::Serialize for ParameterValue {
+        fn serialize<__S: __serde::Serializer>(
+            &self,
+            __serializer: __S,
+        ) -> ::std::result::Result<__S::Ok, __S::Error> {
+            let __serializer =
+                __sidex_serde::ser::VariantSerializer::new(__serializer, "ParameterValue");
+            match self {
+                Self::String(__value) => {
+                    __serializer.serialize_implicitly_tagged("String", 0u32, __value)
+                }
+                Self::Boolean(__value) => {
+                    __serializer.serialize_implicitly_tagged("Boolean", 1u32, __value)
+                }
+                Self::Integer(__value) => {
+                    __serializer.serialize_implicitly_tagged("Integer", 2u32, __value)
+                }
+                Self::Float(__value) => {
+                    __serializer.serialize_implicitly_tagged("Float", 3u32, __value)
+                }
+                Self::List(__value) => {
+                    __serializer.serialize_implicitly_tagged("List", 4u32, __value)
+                }
+            }
+        }
+    }
+    #[automatically_derived]
+    impl<'de> __serde::Deserialize<'de> for ParameterValue {
+        fn deserialize<__D: __serde::Deserializer<'de>>(
+            __deserializer: __D,
+        ) -> ::std::result::Result<Self, __D::Error> {
+            #[doc(hidden)]
+            const __IDENTIFIERS: &'static [&'static str] =
+                &["String", "Boolean", "Integer", "Float", "List"];
+            #[doc(hidden)]
+            const __EXPECTING_IDENTIFIERS: &'static str =
+                "an identifier in [\"String\", \"Boolean\", \"Integer\", \"Float\", \"List\"]";
+            #[derive(:: core :: clone :: Clone, :: core :: marker :: Copy)]
+            #[doc(hidden)]
+            enum __Identifier {
+                __Identifier0,
+                __Identifier1,
+                __Identifier2,
+                __Identifier3,
+                __Identifier4,
+            }
+            #[doc(hidden)]
+            struct __IdentifierVisitor;
+            impl<'de> __serde::de::Visitor<'de> for __IdentifierVisitor {
+                type Value = __Identifier;
+                fn expecting(
+                    &self,
+                    __formatter: &mut ::core::fmt::Formatter,
+                ) -> ::core::fmt::Result {
+                    ::core::fmt::Formatter::write_str(__formatter, __EXPECTING_IDENTIFIERS)
+                }
+                fn visit_u64<__E>(self, __value: u64) -> ::core::result::Result<Self::Value, __E>
+                where
+                    __E: __serde::de::Error,
+                {
+                    match __value {
+                        0u64 => ::core::result::Result::Ok(__Identifier::__Identifier0),
+                        1u64 => ::core::result::Result::Ok(__Identifier::__Identifier1),
+                        2u64 => ::core::result::Result::Ok(__Identifier::__Identifier2),
+                        3u64 => ::core::result::Result::Ok(__Identifier::__Identifier3),
+                        4u64 => ::core::result::Result::Ok(__Identifier::__Identifier4),
+                        __variant => {
+                            ::core::result::Result::Err(__serde::de::Error::invalid_value(
+                                __serde::de::Unexpected::Unsigned(__variant),
+                                &__EXPECTING_IDENTIFIERS,
+                            ))
+                        }
+                    }
+                }
+                fn visit_str<__E>(self, __value: &str) -> ::core::result::Result<Self::Value, __E>
+                where
+                    __E: __serde::de::Error,
+                {
+                    match __value {
+                        "String" => ::core::result::Result::Ok(__Identifier::__Identifier0),
+                        "Boolean" => ::core::result::Result::Ok(__Identifier::__Identifier1),
+                        "Integer" => ::core::result::Result::Ok(__Identifier::__Identifier2),
+                        "Float" => ::core::result::Result::Ok(__Identifier::__Identifier3),
+                        "List" => ::core::result::Result::Ok(__Identifier::__Identifier4),
+                        __variant => ::core::result::Result::Err(
+                            __serde::de::Error::unknown_variant(__variant, __IDENTIFIERS),
+                        ),
+                    }
+                }
+                fn visit_bytes<__E>(
+                    self,
+                    __value: &[u8],
+                ) -> ::core::result::Result<Self::Value, __E>
+                where
+                    __E: __serde::de::Error,
+                {
+                    match __value {
+                        b"String" => ::core::result::Result::Ok(__Identifier::__Identifier0),
+                        b"Boolean" => ::core::result::Result::Ok(__Identifier::__Identifier1),
+                        b"Integer" => ::core::result::Result::Ok(__Identifier::__Identifier2),
+                        b"Float" => ::core::result::Result::Ok(__Identifier::__Identifier3),
+                        b"List" => ::core::result::Result::Ok(__Identifier::__Identifier4),
+                        __variant => {
+                            ::core::result::Result::Err(__serde::de::Error::invalid_value(
+                                __serde::de::Unexpected::Bytes(__variant),
+                                &__EXPECTING_IDENTIFIERS,
+                            ))
+                        }
+                    }
+                }
+            }
+            impl<'de> __serde::Deserialize<'de> for __Identifier {
+                #[inline]
+                fn deserialize<__D>(__deserializer: __D) -> ::core::result::Result<Self, __D::Error>
+                where
+                    __D: __serde::Deserializer<'de>,
+                {
+                    __serde::Deserializer::deserialize_identifier(
+                        __deserializer,
+                        __IdentifierVisitor,
+                    )
+                }
+            }
+            #[doc(hidden)]
+            const __VARIANTS: &'static [&'static str] =
+                &["String", "Boolean", "Integer", "Float", "List"];
+            if __serde::Deserializer::is_human_readable(&__deserializer) {
+                let __content =
+                    __sidex_serde::de::content::deserialize_into_content(__deserializer)?;
+                match __sidex_serde::de::content::deserialize_content_ref::<
+                    ::std::string::String,
+                    __D::Error,
+                >(&__content)
+                {
                     Ok(__value) => return Ok(ParameterValue::String(__value)),
                     Err(_) => {}
                 };
@@ -3848,6 +7240,14 @@ pub mod recipes {
                     Ok(__value) => return Ok(ParameterValue::Float(__value)),
                     Err(_) => {}
                 };
+                match __sidex_serde::de::content::deserialize_content_ref::<
+                    ::std::vec::Vec<ParameterValue>,
+                    __D::Error,
+                >(&__content)
+                {
+                    Ok(__value) => return Ok(ParameterValue::List(__value)),
+                    Err(_) => {}
+                };
                 Err(<__D::Error as __serde::de::Error>::custom(
                     "no matching variant found",
                 ))
@@ -3856,73 +7256,2294 @@ pub mod recipes {
                 struct __Visitor {
                     __phantom_vars: ::core::marker::PhantomData<fn(&())>,
                 }
-                impl<'de> __serde::de::Visitor<'de> for __Visitor {
-                    type Value = ParameterValue;
-                    fn expecting(
-                        &self,
-                        __formatter: &mut ::core::fmt::Formatter,
-                    ) -> ::core::fmt::Result {
-                        ::core::fmt::Formatter::write_str(__formatter, "enum ParameterValue")
+                impl<'de> __serde::de::Visitor<'de> for __Visitor {
+                    type Value = ParameterValue;
+                    fn expecting(
+                        &self,
+                        __formatter: &mut ::core::fmt::Formatter,
+                    ) -> ::core::fmt::Result {
+                        ::core::fmt::Formatter::write_str(__formatter, "enum ParameterValue")
+                    }
+                    #[inline]
+                    fn visit_str<__E>(
+                        self,
+                        __value: &str,
+                    ) -> ::core::result::Result<Self::Value, __E>
+                    where
+                        __E: __serde::de::Error,
+                    {
+                        let __identifier = __IdentifierVisitor.visit_str(__value)?;
+                        #[allow(unreachable_patterns)]
+                        match __identifier {
+                            _ => Err(__E::invalid_value(
+                                __serde::de::Unexpected::Str(__value),
+                                &self,
+                            )),
+                        }
+                    }
+                    #[inline]
+                    fn visit_enum<__A>(
+                        self,
+                        __data: __A,
+                    ) -> ::core::result::Result<Self::Value, __A::Error>
+                    where
+                        __A: __serde::de::EnumAccess<'de>,
+                    {
+                        match __serde::de::EnumAccess::variant::<__Identifier>(__data)? {
+                            (__Identifier::__Identifier0, __variant) => {
+                                let __value = __serde::de::VariantAccess::newtype_variant::<
+                                    ::std::string::String,
+                                >(__variant)?;
+                                ::core::result::Result::Ok(ParameterValue::String(__value))
+                            }
+                            (__Identifier::__Identifier1, __variant) => {
+                                let __value =
+                                    __serde::de::VariantAccess::newtype_variant::<bool>(__variant)?;
+                                ::core::result::Result::Ok(ParameterValue::Boolean(__value))
+                            }
+                            (__Identifier::__Identifier2, __variant) => {
+                                let __value =
+                                    __serde::de::VariantAccess::newtype_variant::<i64>(__variant)?;
+                                ::core::result::Result::Ok(ParameterValue::Integer(__value))
+                            }
+                            (__Identifier::__Identifier3, __variant) => {
+                                let __value =
+                                    __serde::de::VariantAccess::newtype_variant::<f64>(__variant)?;
+                                ::core::result::Result::Ok(ParameterValue::Float(__value))
+                            }
+                            (__Identifier::__Identifier4, __variant) => {
+                                let __value = __serde::de::VariantAccess::newtype_variant::<
+                                    ::std::vec::Vec<ParameterValue>,
+                                >(__variant)?;
+                                ::core::result::Result::Ok(ParameterValue::List(__value))
+                            }
+                        }
+                    }
+                }
+                __serde::Deserializer::deserialize_enum(
+                    __deserializer,
+                    "ParameterValue",
+                    __VARIANTS,
+                    __Visitor {
+                        __phantom_vars: ::core::marker::PhantomData,
+                    },
+                )
+            }
+        }
+    }
+    #[doc = "Configuration of a `files` step.\n"]
+    #[derive(Clone, Debug)]
+    pub struct FilesStepConfig {
+        #[doc = "Files and directories to copy into the root.\n"]
+        pub files: ::std::vec::Vec<FileEntry>,
+    }
+    impl FilesStepConfig {
+        #[doc = "Creates a new [`FilesStepConfig`]."]
+        pub fn new(files: ::std::vec::Vec<FileEntry>) -> Self {
+            Self { files }
+        }
+        #[doc = "Sets the value of `files`."]
+        pub fn set_files(&mut self, files: ::std::vec::Vec<FileEntry>) -> &mut Self {
+            self.files = files;
+            self
+        }
+        #[doc = "Sets the value of `files`."]
+        pub fn with_files(mut self, files: ::std::vec::Vec<FileEntry>) -> Self {
+            self.files = files;
+            self
+        }
+    }
+    #[automatically_derived]
+    impl __serde::Serialize for FilesStepConfig {
+        fn serialize<__S: __serde::Serializer>(
+            &self,
+            __serializer: __S,
+        ) -> ::std::result::Result<__S::Ok, __S::Error> {
+            let mut __record =
+                __sidex_serde::ser::RecordSerializer::new(__serializer, "FilesStepConfig", 1usize)?;
+            __record.serialize_field("files", &self.files)?;
+            __record.end()
+        }
+    }
+    #[automatically_derived]
+    impl<'de> __serde::Deserialize<'de> for FilesStepConfig {
+        fn deserialize<__D: __serde::Deserializer<'de>>(
+            __deserializer: __D,
+        ) -> ::std::result::Result<Self, __D::Error> {
+            #[doc(hidden)]
+            struct __Visitor {
+                __phantom_vars: ::core::marker::PhantomData<fn(&())>,
+            }
+            impl<'de> __serde::de::Visitor<'de> for __Visitor {
+                type Value = FilesStepConfig;
+                fn expecting(
+                    &self,
+                    __formatter: &mut ::core::fmt::Formatter,
+                ) -> ::core::fmt::Result {
+                    ::core::fmt::Formatter::write_str(__formatter, "record FilesStepConfig")
+                }
+                #[inline]
+                fn visit_seq<__A>(
+                    self,
+                    mut __seq: __A,
+                ) -> ::core::result::Result<Self::Value, __A::Error>
+                where
+                    __A: __serde::de::SeqAccess<'de>,
+                {
+                    let __field0 = match __serde::de::SeqAccess::next_element::<
+                        ::std::vec::Vec<FileEntry>,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(0usize, &"record with 1 fields"),
+                            );
+                        }
+                    };
+                    ::core::result::Result::Ok(FilesStepConfig { files: __field0 })
+                }
+                #[inline]
+                fn visit_map<__A>(
+                    self,
+                    mut __map: __A,
+                ) -> ::core::result::Result<Self::Value, __A::Error>
+                where
+                    __A: __serde::de::MapAccess<'de>,
+                {
+                    #[doc(hidden)]
+                    const __IDENTIFIERS: &'static [&'static str] = &["files"];
+                    #[doc(hidden)]
+                    const __EXPECTING_IDENTIFIERS: &'static str = "an identifier in [\"files\"]";
+                    #[derive(:: core :: clone :: Clone, :: core :: marker :: Copy)]
+                    #[doc(hidden)]
+                    enum __Identifier {
+                        __Identifier0,
+                        __Unknown,
+                    }
+                    #[doc(hidden)]
+                    struct __IdentifierVisitor;
+                    impl<'de> __serde::de::Visitor<'de> for __IdentifierVisitor {
+                        type Value = __Identifier;
+                        fn expecting(
+                            &self,
+                            __formatter: &mut ::core::fmt::Formatter,
+                        ) -> ::core::fmt::Result {
+                            ::core::fmt::Formatter::write_str(__formatter, __EXPECTING_IDENTIFIERS)
+                        }
+                        fn visit_u64<__E>(
+                            self,
+                            __value: u64,
+                        ) -> ::core::result::Result<Self::Value, __E>
+                        where
+                            __E: __serde::de::Error,
+                        {
+                            match __value {
+                                0u64 => ::core::result::Result::Ok(__Identifier::__Identifier0),
+                                _ => ::core::result::Result::Ok(__Identifier::__Unknown),
+                            }
+                        }
+                        fn visit_str<__E>(
+                            self,
+                            __value: &str,
+                        ) -> ::core::result::Result<Self::Value, __E>
+                        where
+                            __E: __serde::de::Error,
+                        {
+                            match __value {
+                                "files" => ::core::result::Result::Ok(__Identifier::__Identifier0),
+                                _ => ::core::result::Result::Ok(__Identifier::__Unknown),
+                            }
+                        }
+                        fn visit_bytes<__E>(
+                            self,
+                            __value: &[u8],
+                        ) -> ::core::result::Result<Self::Value, __E>
+                        where
+                            __E: __serde::de::Error,
+                        {
+                            match __value {
+                                b"files" => ::core::result::Result::Ok(__Identifier::__Identifier0),
+                                _ => ::core::result::Result::Ok(__Identifier::__Unknown),
+                            }
+                        }
+                    }
+                    impl<'de> __serde::Deserialize<'de> for __Identifier {
+                        #[inline]
+                        fn deserialize<__D>(
+                            __deserializer: __D,
+                        ) -> ::core::result::Result<Self, __D::Error>
+                        where
+                            __D: __serde::Deserializer<'de>,
+                        {
+                            __serde::Deserializer::deserialize_identifier(
+                                __deserializer,
+                                __IdentifierVisitor,
+                            )
+                        }
+                    }
+                    let mut __field0: ::core::option::Option<::std::vec::Vec<FileEntry>> =
+                        ::core::option::Option::None;
+                    while let ::core::option::Option::Some(__key) =
+                        __serde::de::MapAccess::next_key::<__Identifier>(&mut __map)?
+                    {
+                        match __key {
+                            __Identifier::__Identifier0 => {
+                                if ::core::option::Option::is_some(&__field0) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field(
+                                            "files",
+                                        ),
+                                    );
+                                }
+                                __field0 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<::std::vec::Vec<FileEntry>>(
+                                        &mut __map,
+                                    )?,
+                                );
+                            }
+                            _ => {
+                                __serde::de::MapAccess::next_value::<__serde::de::IgnoredAny>(
+                                    &mut __map,
+                                )?;
+                            }
+                        }
+                    }
+                    let __field0 = match __field0 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                <__A::Error as __serde::de::Error>::missing_field("files"),
+                            );
+                        }
+                    };
+                    ::core::result::Result::Ok(FilesStepConfig { files: __field0 })
+                }
+            }
+            #[doc(hidden)]
+            const __FIELDS: &'static [&'static str] = &["files"];
+            __serde::Deserializer::deserialize_struct(
+                __deserializer,
+                "FilesStepConfig",
+                __FIELDS,
+                __Visitor {
+                    __phantom_vars: ::core::marker::PhantomData,
+                },
+            )
+        }
+    }
+    #[doc = "File or directory copied from the recipe's `files` directory into the root.\n"]
+    #[derive(Clone, Debug)]
+    pub struct FileEntry {
+        #[doc = "Path of the file or directory relative to the recipe's `files` directory.\n"]
+        pub source: ::std::string::String,
+        #[doc = "Absolute path of the destination in the root.\n"]
+        pub destination: ::std::string::String,
+        #[doc = "Octal permission bits of copied files (defaults to those of the source).\n"]
+        pub mode: ::std::option::Option<::std::string::String>,
+        #[doc = "Octal permission bits of copied directories (defaults to `0755`).\n\nExisting directories keep their permissions and owner unless this is set.\n"]
+        pub directory_mode: ::std::option::Option<::std::string::String>,
+        #[doc = "Name or numeric id of the owner (defaults to `root`).\n"]
+        pub owner: ::std::option::Option<::std::string::String>,
+        #[doc = "Name or numeric id of the group (defaults to `root`).\n"]
+        pub group: ::std::option::Option<::std::string::String>,
+    }
+    impl FileEntry {
+        #[doc = "Creates a new [`FileEntry`]."]
+        pub fn new(source: ::std::string::String, destination: ::std::string::String) -> Self {
+            Self {
+                source,
+                destination,
+                mode: ::std::default::Default::default(),
+                directory_mode: ::std::default::Default::default(),
+                owner: ::std::default::Default::default(),
+                group: ::std::default::Default::default(),
+            }
+        }
+        #[doc = "Sets the value of `source`."]
+        pub fn set_source(&mut self, source: ::std::string::String) -> &mut Self {
+            self.source = source;
+            self
+        }
+        #[doc = "Sets the value of `source`."]
+        pub fn with_source(mut self, source: ::std::string::String) -> Self {
+            self.source = source;
+            self
+        }
+        #[doc = "Sets the value of `destination`."]
+        pub fn set_destination(&mut self, destination: ::std::string::String) -> &mut Self {
+            self.destination = destination;
+            self
+        }
+        #[doc = "Sets the value of `destination`."]
+        pub fn with_destination(mut self, destination: ::std::string::String) -> Self {
+            self.destination = destination;
+            self
+        }
+        #[doc = "Sets the value of `mode`."]
+        pub fn set_mode(
+            &mut self,
+            mode: ::std::option::Option<::std::string::String>,
+        ) -> &mut Self {
+            self.mode = mode;
+            self
+        }
+        #[doc = "Sets the value of `mode`."]
+        pub fn with_mode(mut self, mode: ::std::option::Option<::std::string::String>) -> Self {
+            self.mode = mode;
+            self
+        }
+        #[doc = "Sets the value of `directory_mode`."]
+        pub fn set_directory_mode(
+            &mut self,
+            directory_mode: ::std::option::Option<::std::string::String>,
+        ) -> &mut Self {
+            self.directory_mode = directory_mode;
+            self
+        }
+        #[doc = "Sets the value of `directory_mode`."]
+        pub fn with_directory_mode(
+            mut self,
+            directory_mode: ::std::option::Option<::std::string::String>,
+        ) -> Self {
+            self.directory_mode = directory_mode;
+            self
+        }
+        #[doc = "Sets the value of `owner`."]
+        pub fn set_owner(
+            &mut self,
+            owner: ::std::option::Option<::std::string::String>,
+        ) -> &mut Self {
+            self.owner = owner;
+            self
+        }
+        #[doc = "Sets the value of `owner`."]
+        pub fn with_owner(mut self, owner: ::std::option::Option<::std::string::String>) -> Self {
+            self.owner = owner;
+            self
+        }
+        #[doc = "Sets the value of `group`."]
+        pub fn set_group(
+            &mut self,
+            group: ::std::option::Option<::std::string::String>,
+        ) -> &mut Self {
+            self.group = group;
+            self
+        }
+        #[doc = "Sets the value of `group`."]
+        pub fn with_group(mut self, group: ::std::option::Option<::std::string::String>) -> Self {
+            self.group = group;
+            self
+        }
+    }
+    #[automatically_derived]
+    impl __serde::Serialize for FileEntry {
+        fn serialize<__S: __serde::Serializer>(
+            &self,
+            __serializer: __S,
+        ) -> ::std::result::Result<__S::Ok, __S::Error> {
+            let mut __record =
+                __sidex_serde::ser::RecordSerializer::new(__serializer, "FileEntry", 6usize)?;
+            __record.serialize_field("source", &self.source)?;
+            __record.serialize_field("destination", &self.destination)?;
+            __record
+                .serialize_optional_field("mode", ::core::option::Option::as_ref(&self.mode))?;
+            __record.serialize_optional_field(
+                "directory-mode",
+                ::core::option::Option::as_ref(&self.directory_mode),
+            )?;
+            __record
+                .serialize_optional_field("owner", ::core::option::Option::as_ref(&self.owner))?;
+            __record
+                .serialize_optional_field("group", ::core::option::Option::as_ref(&self.group))?;
+            __record.end()
+        }
+    }
+    #[automatically_derived]
+    impl<'de> __serde::Deserialize<'de> for FileEntry {
+        fn deserialize<__D: __serde::Deserializer<'de>>(
+            __deserializer: __D,
+        ) -> ::std::result::Result<Self, __D::Error> {
+            #[doc(hidden)]
+            struct __Visitor {
+                __phantom_vars: ::core::marker::PhantomData<fn(&())>,
+            }
+            impl<'de> __serde::de::Visitor<'de> for __Visitor {
+                type Value = FileEntry;
+                fn expecting(
+                    &self,
+                    __formatter: &mut ::core::fmt::Formatter,
+                ) -> ::core::fmt::Result {
+                    ::core::fmt::Formatter::write_str(__formatter, "record FileEntry")
+                }
+                #[inline]
+                fn visit_seq<__A>(
+                    self,
+                    mut __seq: __A,
+                ) -> ::core::result::Result<Self::Value, __A::Error>
+                where
+                    __A: __serde::de::SeqAccess<'de>,
+                {
+                    let __field0 = match __serde::de::SeqAccess::next_element::<
+                        ::std::string::String,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(0usize, &"record with 6 fields"),
+                            );
+                        }
+                    };
+                    let __field1 = match __serde::de::SeqAccess::next_element::<
+                        ::std::string::String,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(1usize, &"record with 6 fields"),
+                            );
+                        }
+                    };
+                    let __field2 = match __serde::de::SeqAccess::next_element::<
+                        ::std::option::Option<::std::string::String>,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(2usize, &"record with 6 fields"),
+                            );
+                        }
+                    };
+                    let __field3 = match __serde::de::SeqAccess::next_element::<
+                        ::std::option::Option<::std::string::String>,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(3usize, &"record with 6 fields"),
+                            );
+                        }
+                    };
+                    let __field4 = match __serde::de::SeqAccess::next_element::<
+                        ::std::option::Option<::std::string::String>,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(4usize, &"record with 6 fields"),
+                            );
+                        }
+                    };
+                    let __field5 = match __serde::de::SeqAccess::next_element::<
+                        ::std::option::Option<::std::string::String>,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(5usize, &"record with 6 fields"),
+                            );
+                        }
+                    };
+                    ::core::result::Result::Ok(FileEntry {
+                        source: __field0,
+                        destination: __field1,
+                        mode: __field2,
+                        directory_mode: __field3,
+                        owner: __field4,
+                        group: __field5,
+                    })
+                }
+                #[inline]
+                fn visit_map<__A>(
+                    self,
+                    mut __map: __A,
+                ) -> ::core::result::Result<Self::Value, __A::Error>
+                where
+                    __A: __serde::de::MapAccess<'de>,
+                {
+                    #[doc(hidden)]
+                    const __IDENTIFIERS: &'static [&'static str] = &[
+                        "source",
+                        "destination",
+                        "mode",
+                        "directory-mode",
+                        "owner",
+                        "group",
+                    ];
+                    #[doc(hidden)]
+                    const __EXPECTING_IDENTIFIERS : & 'static str = "an identifier in [\"source\", \"destination\", \"mode\", \"directory-mode\", \"owner\", \"group\"]" ;
+                    #[derive(:: core :: clone :: Clone, :: core :: marker :: Copy)]
+                    #[doc(hidden)]
+                    enum __Identifier {
+                        __Identifier0,
+                        __Identifier1,
+                        __Identifier2,
+                        __Identifier3,
+                        __Identifier4,
+                        __Identifier5,
+                        __Unknown,
+                    }
+                    #[doc(hidden)]
+                    struct __IdentifierVisitor;
+                    impl<'de> __serde::de::Visitor<'de> for __IdentifierVisitor {
+                        type Value = __Identifier;
+                        fn expecting(
+                            &self,
+                            __formatter: &mut ::core::fmt::Formatter,
+                        ) -> ::core::fmt::Result {
+                            ::core::fmt::Formatter::write_str(__formatter, __EXPECTING_IDENTIFIERS)
+                        }
+                        fn visit_u64<__E>(
+                            self,
+                            __value: u64,
+                        ) -> ::core::result::Result<Self::Value, __E>
+                        where
+                            __E: __serde::de::Error,
+                        {
+                            match __value {
+                                0u64 => ::core::result::Result::Ok(__Identifier::__Identifier0),
+                                1u64 => ::core::result::Result::Ok(__Identifier::__Identifier1),
+                                2u64 => ::core::result::Result::Ok(__Identifier::__Identifier2),
+                                3u64 => ::core::result::Result::Ok(__Identifier::__Identifier3),
+                                4u64 => ::core::result::Result::Ok(__Identifier::__Identifier4),
+                                5u64 => ::core::result::Result::Ok(__Identifier::__Identifier5),
+                                _ => ::core::result::Result::Ok(__Identifier::__Unknown),
+                            }
+                        }
+                        fn visit_str<__E>(
+                            self,
+                            __value: &str,
+                        ) -> ::core::result::Result<Self::Value, __E>
+                        where
+                            __E: __serde::de::Error,
+                        {
+                            match __value {
+                                "source" => ::core::result::Result::Ok(__Identifier::__Identifier0),
+                                "destination" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier1)
+                                }
+                                "mode" => ::core::result::Result::Ok(__Identifier::__Identifier2),
+                                "directory-mode" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier3)
+                                }
+                                "owner" => ::core::result::Result::Ok(__Identifier::__Identifier4),
+                                "group" => ::core::result::Result::Ok(__Identifier::__Identifier5),
+                                _ => ::core::result::Result::Ok(__Identifier::__Unknown),
+                            }
+                        }
+                        fn visit_bytes<__E>(
+                            self,
+                            __value: &[u8],
+                        ) -> ::core::result::Result<Self::Value, __E>
+                        where
+                            __E: __serde::de::Error,
+                        {
+                            match __value {
+                                b"source" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier0)
+                                }
+                                b"destination" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier1)
+                                }
+                                b"mode" => ::core::result::Result::Ok(__Identifier::__Identifier2),
+                                b"directory-mode" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier3)
+                                }
+                                b"owner" => ::core::result::Result::Ok(__Identifier::__Identifier4),
+                                b"group" => ::core::result::Result::Ok(__Identifier::__Identifier5),
+                                _ => ::core::result::Result::Ok(__Identifier::__Unknown),
+                            }
+                        }
+                    }
+                    impl<'de> __serde::Deserialize<'de> for __Identifier {
+                        #[inline]
+                        fn deserialize<__D>(
+                            __deserializer: __D,
+                        ) -> ::core::result::Result<Self, __D::Error>
+                        where
+                            __D: __serde::Deserializer<'de>,
+                        {
+                            __serde::Deserializer::deserialize_identifier(
+                                __deserializer,
+                                __IdentifierVisitor,
+                            )
+                        }
+                    }
+                    let mut __field0: ::core::option::Option<::std::string::String> =
+                        ::core::option::Option::None;
+                    let mut __field1: ::core::option::Option<::std::string::String> =
+                        ::core::option::Option::None;
+                    let mut __field2: ::core::option::Option<
+                        ::std::option::Option<::std::string::String>,
+                    > = ::core::option::Option::None;
+                    let mut __field3: ::core::option::Option<
+                        ::std::option::Option<::std::string::String>,
+                    > = ::core::option::Option::None;
+                    let mut __field4: ::core::option::Option<
+                        ::std::option::Option<::std::string::String>,
+                    > = ::core::option::Option::None;
+                    let mut __field5: ::core::option::Option<
+                        ::std::option::Option<::std::string::String>,
+                    > = ::core::option::Option::None;
+                    while let ::core::option::Option::Some(__key) =
+                        __serde::de::MapAccess::next_key::<__Identifier>(&mut __map)?
+                    {
+                        match __key {
+                            __Identifier::__Identifier0 => {
+                                if ::core::option::Option::is_some(&__field0) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field(
+                                            "source",
+                                        ),
+                                    );
+                                }
+                                __field0 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<::std::string::String>(
+                                        &mut __map,
+                                    )?,
+                                );
+                            }
+                            __Identifier::__Identifier1 => {
+                                if ::core::option::Option::is_some(&__field1) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field(
+                                            "destination",
+                                        ),
+                                    );
+                                }
+                                __field1 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<::std::string::String>(
+                                        &mut __map,
+                                    )?,
+                                );
+                            }
+                            __Identifier::__Identifier2 => {
+                                if ::core::option::Option::is_some(&__field2) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field("mode"),
+                                    );
+                                }
+                                __field2 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<
+                                        ::std::option::Option<::std::string::String>,
+                                    >(&mut __map)?,
+                                );
+                            }
+                            __Identifier::__Identifier3 => {
+                                if ::core::option::Option::is_some(&__field3) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field(
+                                            "directory-mode",
+                                        ),
+                                    );
+                                }
+                                __field3 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<
+                                        ::std::option::Option<::std::string::String>,
+                                    >(&mut __map)?,
+                                );
+                            }
+                            __Identifier::__Identifier4 => {
+                                if ::core::option::Option::is_some(&__field4) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field(
+                                            "owner",
+                                        ),
+                                    );
+                                }
+                                __field4 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<
+                                        ::std::option::Option<::std::string::String>,
+                                    >(&mut __map)?,
+                                );
+                            }
+                            __Identifier::__Identifier5 => {
+                                if ::core::option::Option::is_some(&__field5) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field(
+                                            "group",
+                                        ),
+                                    );
+                                }
+                                __field5 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<
+                                        ::std::option::Option<::std::string::String>,
+                                    >(&mut __map)?,
+                                );
+                            }
+                            _ => {
+                                __serde::de::MapAccess::next_value::<__serde::de::IgnoredAny>(
+                                    &mut __map,
+                                )?;
+                            }
+                        }
+                    }
+                    let __field0 = match __field0 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                <__A::Error as __serde::de::Error>::missing_field("source"),
+                            );
+                        }
+                    };
+                    let __field1 = match __field1 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                <__A::Error as __serde::de::Error>::missing_field("destination"),
+                            );
+                        }
+                    };
+                    let __field2 = match __field2 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    };
+                    let __field3 = match __field3 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    };
+                    let __field4 = match __field4 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    };
+                    let __field5 = match __field5 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    };
+                    ::core::result::Result::Ok(FileEntry {
+                        source: __field0,
+                        destination: __field1,
+                        mode: __field2,
+                        directory_mode: __field3,
+                        owner: __field4,
+                        group: __field5,
+                    })
+                }
+            }
+            #[doc(hidden)]
+            const __FIELDS: &'static [&'static str] = &[
+                "source",
+                "destination",
+                "mode",
+                "directory-mode",
+                "owner",
+                "group",
+            ];
+            __serde::Deserializer::deserialize_struct(
+                __deserializer,
+                "FileEntry",
+                __FIELDS,
+                __Visitor {
+                    __phantom_vars: ::core::marker::PhantomData,
+                },
+            )
+        }
+    }
+    #[doc = "Group created by a recipe.\n"]
+    #[derive(Clone, Debug)]
+    pub struct GroupConfig {
+        #[doc = "Name of the group.\n"]
+        pub name: ::std::string::String,
+        #[doc = "Numeric id of the group (allocated based on the name by default).\n"]
+        pub gid: ::std::option::Option<u32>,
+        #[doc = "Indicates whether the group is a system group.\n"]
+        pub system: ::std::option::Option<bool>,
+    }
+    impl GroupConfig {
+        #[doc = "Creates a new [`GroupConfig`]."]
+        pub fn new(name: ::std::string::String) -> Self {
+            Self {
+                name,
+                gid: ::std::default::Default::default(),
+                system: ::std::default::Default::default(),
+            }
+        }
+        #[doc = "Sets the value of `name`."]
+        pub fn set_name(&mut self, name: ::std::string::String) -> &mut Self {
+            self.name = name;
+            self
+        }
+        #[doc = "Sets the value of `name`."]
+        pub fn with_name(mut self, name: ::std::string::String) -> Self {
+            self.name = name;
+            self
+        }
+        #[doc = "Sets the value of `gid`."]
+        pub fn set_gid(&mut self, gid: ::std::option::Option<u32>) -> &mut Self {
+            self.gid = gid;
+            self
+        }
+        #[doc = "Sets the value of `gid`."]
+        pub fn with_gid(mut self, gid: ::std::option::Option<u32>) -> Self {
+            self.gid = gid;
+            self
+        }
+        #[doc = "Sets the value of `system`."]
+        pub fn set_system(&mut self, system: ::std::option::Option<bool>) -> &mut Self {
+            self.system = system;
+            self
+        }
+        #[doc = "Sets the value of `system`."]
+        pub fn with_system(mut self, system: ::std::option::Option<bool>) -> Self {
+            self.system = system;
+            self
+        }
+    }
+    #[automatically_derived]
+    impl __serde::Serialize for GroupConfig {
+        fn serialize<__S: __serde::Serializer>(
+            &self,
+            __serializer: __S,
+        ) -> ::std::result::Result<__S::Ok, __S::Error> {
+            let mut __record =
+                __sidex_serde::ser::RecordSerializer::new(__serializer, "GroupConfig", 3usize)?;
+            __record.serialize_field("name", &self.name)?;
+            __record.serialize_optional_field("gid", ::core::option::Option::as_ref(&self.gid))?;
+            __record
+                .serialize_optional_field("system", ::core::option::Option::as_ref(&self.system))?;
+            __record.end()
+        }
+    }
+    #[automatically_derived]
+    impl<'de> __serde::Deserialize<'de> for GroupConfig {
+        fn deserialize<__D: __serde::Deserializer<'de>>(
+            __deserializer: __D,
+        ) -> ::std::result::Result<Self, __D::Error> {
+            #[doc(hidden)]
+            struct __Visitor {
+                __phantom_vars: ::core::marker::PhantomData<fn(&())>,
+            }
+            impl<'de> __serde::de::Visitor<'de> for __Visitor {
+                type Value = GroupConfig;
+                fn expecting(
+                    &self,
+                    __formatter: &mut ::core::fmt::Formatter,
+                ) -> ::core::fmt::Result {
+                    ::core::fmt::Formatter::write_str(__formatter, "record GroupConfig")
+                }
+                #[inline]
+                fn visit_seq<__A>(
+                    self,
+                    mut __seq: __A,
+                ) -> ::core::result::Result<Self::Value, __A::Error>
+                where
+                    __A: __serde::de::SeqAccess<'de>,
+                {
+                    let __field0 = match __serde::de::SeqAccess::next_element::<
+                        ::std::string::String,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(0usize, &"record with 3 fields"),
+                            );
+                        }
+                    };
+                    let __field1 = match __serde::de::SeqAccess::next_element::<
+                        ::std::option::Option<u32>,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(1usize, &"record with 3 fields"),
+                            );
+                        }
+                    };
+                    let __field2 = match __serde::de::SeqAccess::next_element::<
+                        ::std::option::Option<bool>,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(2usize, &"record with 3 fields"),
+                            );
+                        }
+                    };
+                    ::core::result::Result::Ok(GroupConfig {
+                        name: __field0,
+                        gid: __field1,
+                        system: __field2,
+                    })
+                }
+                #[inline]
+                fn visit_map<__A>(
+                    self,
+                    mut __map: __A,
+                ) -> ::core::result::Result<Self::Value, __A::Error>
+                where
+                    __A: __serde::de::MapAccess<'de>,
+                {
+                    #[doc(hidden)]
+                    const __IDENTIFIERS: &'static [&'static str] = &["name", "gid", "system"];
+                    #[doc(hidden)]
+                    const __EXPECTING_IDENTIFIERS: &'static str =
+                        "an identifier in [\"name\", \"gid\", \"system\"]";
+                    #[derive(:: core :: clone :: Clone, :: core :: marker :: Copy)]
+                    #[doc(hidden)]
+                    enum __Identifier {
+                        __Identifier0,
+                        __Identifier1,
+                        __Identifier2,
+                        __Unknown,
+                    }
+                    #[doc(hidden)]
+                    struct __IdentifierVisitor;
+                    impl<'de> __serde::de::Visitor<'de> for __IdentifierVisitor {
+                        type Value = __Identifier;
+                        fn expecting(
+                            &self,
+                            __formatter: &mut ::core::fmt::Formatter,
+                        ) -> ::core::fmt::Result {
+                            ::core::fmt::Formatter::write_str(__formatter, __EXPECTING_IDENTIFIERS)
+                        }
+                        fn visit_u64<__E>(
+                            self,
+                            __value: u64,
+                        ) -> ::core::result::Result<Self::Value, __E>
+                        where
+                            __E: __serde::de::Error,
+                        {
+                            match __value {
+                                0u64 => ::core::result::Result::Ok(__Identifier::__Identifier0),
+                                1u64 => ::core::result::Result::Ok(__Identifier::__Identifier1),
+                                2u64 => ::core::result::Result::Ok(__Identifier::__Identifier2),
+                                _ => ::core::result::Result::Ok(__Identifier::__Unknown),
+                            }
+                        }
+                        fn visit_str<__E>(
+                            self,
+                            __value: &str,
+                        ) -> ::core::result::Result<Self::Value, __E>
+                        where
+                            __E: __serde::de::Error,
+                        {
+                            match __value {
+                                "name" => ::core::result::Result::Ok(__Identifier::__Identifier0),
+                                "gid" => ::core::result::Result::Ok(__Identifier::__Identifier1),
+                                "system" => ::core::result::Result::Ok(__Identifier::__Identifier2),
+                                _ => ::core::result::Result::Ok(__Identifier::__Unknown),
+                            }
+                        }
+                        fn visit_bytes<__E>(
+                            self,
+                            __value: &[u8],
+                        ) -> ::core::result::Result<Self::Value, __E>
+                        where
+                            __E: __serde::de::Error,
+                        {
+                            match __value {
+                                b"name" => ::core::result::Result::Ok(__Identifier::__Identifier0),
+                                b"gid" => ::core::result::Result::Ok(__Identifier::__Identifier1),
+                                b"system" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier2)
+                                }
+                                _ => ::core::result::Result::Ok(__Identifier::__Unknown),
+                            }
+                        }
+                    }
+                    impl<'de> __serde::Deserialize<'de> for __Identifier {
+                        #[inline]
+                        fn deserialize<__D>(
+                            __deserializer: __D,
+                        ) -> ::core::result::Result<Self, __D::Error>
+                        where
+                            __D: __serde::Deserializer<'de>,
+                        {
+                            __serde::Deserializer::deserialize_identifier(
+                                __deserializer,
+                                __IdentifierVisitor,
+                            )
+                        }
+                    }
+                    let mut __field0: ::core::option::Option<::std::string::String> =
+                        ::core::option::Option::None;
+                    let mut __field1: ::core::option::Option<::std::option::Option<u32>> =
+                        ::core::option::Option::None;
+                    let mut __field2: ::core::option::Option<::std::option::Option<bool>> =
+                        ::core::option::Option::None;
+                    while let ::core::option::Option::Some(__key) =
+                        __serde::de::MapAccess::next_key::<__Identifier>(&mut __map)?
+                    {
+                        match __key {
+                            __Identifier::__Identifier0 => {
+                                if ::core::option::Option::is_some(&__field0) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field("name"),
+                                    );
+                                }
+                                __field0 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<::std::string::String>(
+                                        &mut __map,
+                                    )?,
+                                );
+                            }
+                            __Identifier::__Identifier1 => {
+                                if ::core::option::Option::is_some(&__field1) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field("gid"),
+                                    );
+                                }
+                                __field1 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<::std::option::Option<u32>>(
+                                        &mut __map,
+                                    )?,
+                                );
+                            }
+                            __Identifier::__Identifier2 => {
+                                if ::core::option::Option::is_some(&__field2) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field(
+                                            "system",
+                                        ),
+                                    );
+                                }
+                                __field2 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<
+                                        ::std::option::Option<bool>,
+                                    >(&mut __map)?,
+                                );
+                            }
+                            _ => {
+                                __serde::de::MapAccess::next_value::<__serde::de::IgnoredAny>(
+                                    &mut __map,
+                                )?;
+                            }
+                        }
+                    }
+                    let __field0 = match __field0 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                <__A::Error as __serde::de::Error>::missing_field("name"),
+                            );
+                        }
+                    };
+                    let __field1 = match __field1 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    };
+                    let __field2 = match __field2 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    };
+                    ::core::result::Result::Ok(GroupConfig {
+                        name: __field0,
+                        gid: __field1,
+                        system: __field2,
+                    })
+                }
+            }
+            #[doc(hidden)]
+            const __FIELDS: &'static [&'static str] = &["name", "gid", "system"];
+            __serde::Deserializer::deserialize_struct(
+                __deserializer,
+                "GroupConfig",
+                __FIELDS,
+                __Visitor {
+                    __phantom_vars: ::core::marker::PhantomData,
+                },
+            )
+        }
+    }
+    #[doc = "User created by a recipe.\n"]
+    #[derive(Clone, Debug)]
+    pub struct UserConfig {
+        #[doc = "Name of the user.\n"]
+        pub name: ::std::string::String,
+        #[doc = "Numeric id of the user (allocated based on the name by default).\n"]
+        pub uid: ::std::option::Option<u32>,
+        #[doc = "Primary group of the user (defaults to a group with the user's name).\n"]
+        pub group: ::std::option::Option<::std::string::String>,
+        #[doc = "Supplementary groups of the user.\n"]
+        pub groups: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+        #[doc = "Comment, e.g., the full name of the user.\n"]
+        pub comment: ::std::option::Option<::std::string::String>,
+        #[doc = "Home directory of the user (defaults to `/home/<name>` or `/nonexistent` for system users).\n"]
+        pub home: ::std::option::Option<::std::string::String>,
+        #[doc = "Login shell of the user (defaults to `/bin/sh` or `/usr/sbin/nologin` for system users).\n"]
+        pub shell: ::std::option::Option<::std::string::String>,
+        #[doc = "Indicates whether the user is a system user.\n\nSystem users have no home directory by default.\n"]
+        pub system: ::std::option::Option<bool>,
+        #[doc = "Hashed password of the user as found in `/etc/shadow`.\n"]
+        pub password_hash: ::std::option::Option<::std::string::String>,
+        #[doc = "Indicates whether the password is locked (defaults to `true` without a hash).\n"]
+        pub locked: ::std::option::Option<bool>,
+        #[doc = "Public SSH keys authorized to log in as the user.\n"]
+        pub authorized_keys: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+    }
+    impl UserConfig {
+        #[doc = "Creates a new [`UserConfig`]."]
+        pub fn new(name: ::std::string::String) -> Self {
+            Self {
+                name,
+                uid: ::std::default::Default::default(),
+                group: ::std::default::Default::default(),
+                groups: ::std::default::Default::default(),
+                comment: ::std::default::Default::default(),
+                home: ::std::default::Default::default(),
+                shell: ::std::default::Default::default(),
+                system: ::std::default::Default::default(),
+                password_hash: ::std::default::Default::default(),
+                locked: ::std::default::Default::default(),
+                authorized_keys: ::std::default::Default::default(),
+            }
+        }
+        #[doc = "Sets the value of `name`."]
+        pub fn set_name(&mut self, name: ::std::string::String) -> &mut Self {
+            self.name = name;
+            self
+        }
+        #[doc = "Sets the value of `name`."]
+        pub fn with_name(mut self, name: ::std::string::String) -> Self {
+            self.name = name;
+            self
+        }
+        #[doc = "Sets the value of `uid`."]
+        pub fn set_uid(&mut self, uid: ::std::option::Option<u32>) -> &mut Self {
+            self.uid = uid;
+            self
+        }
+        #[doc = "Sets the value of `uid`."]
+        pub fn with_uid(mut self, uid: ::std::option::Option<u32>) -> Self {
+            self.uid = uid;
+            self
+        }
+        #[doc = "Sets the value of `group`."]
+        pub fn set_group(
+            &mut self,
+            group: ::std::option::Option<::std::string::String>,
+        ) -> &mut Self {
+            self.group = group;
+            self
+        }
+        #[doc = "Sets the value of `group`."]
+        pub fn with_group(mut self, group: ::std::option::Option<::std::string::String>) -> Self {
+            self.group = group;
+            self
+        }
+        #[doc = "Sets the value of `groups`."]
+        pub fn set_groups(
+            &mut self,
+            groups: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+        ) -> &mut Self {
+            self.groups = groups;
+            self
+        }
+        #[doc = "Sets the value of `groups`."]
+        pub fn with_groups(
+            mut self,
+            groups: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+        ) -> Self {
+            self.groups = groups;
+            self
+        }
+        #[doc = "Sets the value of `comment`."]
+        pub fn set_comment(
+            &mut self,
+            comment: ::std::option::Option<::std::string::String>,
+        ) -> &mut Self {
+            self.comment = comment;
+            self
+        }
+        #[doc = "Sets the value of `comment`."]
+        pub fn with_comment(
+            mut self,
+            comment: ::std::option::Option<::std::string::String>,
+        ) -> Self {
+            self.comment = comment;
+            self
+        }
+        #[doc = "Sets the value of `home`."]
+        pub fn set_home(
+            &mut self,
+            home: ::std::option::Option<::std::string::String>,
+        ) -> &mut Self {
+            self.home = home;
+            self
+        }
+        #[doc = "Sets the value of `home`."]
+        pub fn with_home(mut self, home: ::std::option::Option<::std::string::String>) -> Self {
+            self.home = home;
+            self
+        }
+        #[doc = "Sets the value of `shell`."]
+        pub fn set_shell(
+            &mut self,
+            shell: ::std::option::Option<::std::string::String>,
+        ) -> &mut Self {
+            self.shell = shell;
+            self
+        }
+        #[doc = "Sets the value of `shell`."]
+        pub fn with_shell(mut self, shell: ::std::option::Option<::std::string::String>) -> Self {
+            self.shell = shell;
+            self
+        }
+        #[doc = "Sets the value of `system`."]
+        pub fn set_system(&mut self, system: ::std::option::Option<bool>) -> &mut Self {
+            self.system = system;
+            self
+        }
+        #[doc = "Sets the value of `system`."]
+        pub fn with_system(mut self, system: ::std::option::Option<bool>) -> Self {
+            self.system = system;
+            self
+        }
+        #[doc = "Sets the value of `password_hash`."]
+        pub fn set_password_hash(
+            &mut self,
+            password_hash: ::std::option::Option<::std::string::String>,
+        ) -> &mut Self {
+            self.password_hash = password_hash;
+            self
+        }
+        #[doc = "Sets the value of `password_hash`."]
+        pub fn with_password_hash(
+            mut self,
+            password_hash: ::std::option::Option<::std::string::String>,
+        ) -> Self {
+            self.password_hash = password_hash;
+            self
+        }
+        #[doc = "Sets the value of `locked`."]
+        pub fn set_locked(&mut self, locked: ::std::option::Option<bool>) -> &mut Self {
+            self.locked = locked;
+            self
+        }
+        #[doc = "Sets the value of `locked`."]
+        pub fn with_locked(mut self, locked: ::std::option::Option<bool>) -> Self {
+            self.locked = locked;
+            self
+        }
+        #[doc = "Sets the value of `authorized_keys`."]
+        pub fn set_authorized_keys(
+            &mut self,
+            authorized_keys: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+        ) -> &mut Self {
+            self.authorized_keys = authorized_keys;
+            self
+        }
+        #[doc = "Sets the value of `authorized_keys`."]
+        pub fn with_authorized_keys(
+            mut self,
+            authorized_keys: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+        ) -> Self {
+            self.authorized_keys = authorized_keys;
+            self
+        }
+    }
+    #[automatically_derived]
+    impl __serde::Serialize for UserConfig {
+        fn serialize<__S: __serde::Serializer>(
+            &self,
+            __serializer: __S,
+        ) -> ::std::result::Result<__S::Ok, __S::Error> {
+            let mut __record =
+                __sidex_serde::ser::RecordSerializer::new(__serializer, "UserConfig", 11usize)?;
+            __record.serialize_field("name", &self.name)?;
+            __record.serialize_optional_field("uid", ::core::option::Option::as_ref(&self.uid))?;
+            __record
+                .serialize_optional_field("group", ::core::option::Option::as_ref(&self.group))?;
+            __record
+                .serialize_optional_field("groups", ::core::option::Option::as_ref(&self.groups))?;
+            __record.serialize_optional_field(
+                "comment",
+                ::core::option::Option::as_ref(&self.comment),
+            )?;
+            __record
+                .serialize_optional_field("home", ::core::option::Option::as_ref(&self.home))?;
+            __record
+                .serialize_optional_field("shell", ::core::option::Option::as_ref(&self.shell))?;
+            __record
+                .serialize_optional_field("system", ::core::option::Option::as_ref(&self.system))?;
+            __record.serialize_optional_field(
+                "password-hash",
+                ::core::option::Option::as_ref(&self.password_hash),
+            )?;
+            __record
+                .serialize_optional_field("locked", ::core::option::Option::as_ref(&self.locked))?;
+            __record.serialize_optional_field(
+                "authorized-keys",
+                ::core::option::Option::as_ref(&self.authorized_keys),
+            )?;
+            __record.end()
+        }
+    }
+    #[automatically_derived]
+    impl<'de> __serde::Deserialize<'de> for UserConfig {
+        fn deserialize<__D: __serde::Deserializer<'de>>(
+            __deserializer: __D,
+        ) -> ::std::result::Result<Self, __D::Error> {
+            #[doc(hidden)]
+            struct __Visitor {
+                __phantom_vars: ::core::marker::PhantomData<fn(&())>,
+            }
+            impl<'de> __serde::de::Visitor<'de> for __Visitor {
+                type Value = UserConfig;
+                fn expecting(
+                    &self,
+                    __formatter: &mut ::core::fmt::Formatter,
+                ) -> ::core::fmt::Result {
+                    ::core::fmt::Formatter::write_str(__formatter, "record UserConfig")
+                }
+                #[inline]
+                fn visit_seq<__A>(
+                    self,
+                    mut __seq: __A,
+                ) -> ::core::result::Result<Self::Value, __A::Error>
+                where
+                    __A: __serde::de::SeqAccess<'de>,
+                {
+                    let __field0 = match __serde::de::SeqAccess::next_element::<
+                        ::std::string::String,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(
+                                    0usize,
+                                    &"record with 11 fields",
+                                ),
+                            );
+                        }
+                    };
+                    let __field1 = match __serde::de::SeqAccess::next_element::<
+                        ::std::option::Option<u32>,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(
+                                    1usize,
+                                    &"record with 11 fields",
+                                ),
+                            );
+                        }
+                    };
+                    let __field2 = match __serde::de::SeqAccess::next_element::<
+                        ::std::option::Option<::std::string::String>,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(
+                                    2usize,
+                                    &"record with 11 fields",
+                                ),
+                            );
+                        }
+                    };
+                    let __field3 = match __serde::de::SeqAccess::next_element::<
+                        ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(
+                                    3usize,
+                                    &"record with 11 fields",
+                                ),
+                            );
+                        }
+                    };
+                    let __field4 = match __serde::de::SeqAccess::next_element::<
+                        ::std::option::Option<::std::string::String>,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(
+                                    4usize,
+                                    &"record with 11 fields",
+                                ),
+                            );
+                        }
+                    };
+                    let __field5 = match __serde::de::SeqAccess::next_element::<
+                        ::std::option::Option<::std::string::String>,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(
+                                    5usize,
+                                    &"record with 11 fields",
+                                ),
+                            );
+                        }
+                    };
+                    let __field6 = match __serde::de::SeqAccess::next_element::<
+                        ::std::option::Option<::std::string::String>,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(
+                                    6usize,
+                                    &"record with 11 fields",
+                                ),
+                            );
+                        }
+                    };
+                    let __field7 = match __serde::de::SeqAccess::next_element::<
+                        ::std::option::Option<bool>,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(
+                                    7usize,
+                                    &"record with 11 fields",
+                                ),
+                            );
+                        }
+                    };
+                    let __field8 = match __serde::de::SeqAccess::next_element::<
+                        ::std::option::Option<::std::string::String>,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(
+                                    8usize,
+                                    &"record with 11 fields",
+                                ),
+                            );
+                        }
+                    };
+                    let __field9 = match __serde::de::SeqAccess::next_element::<
+                        ::std::option::Option<bool>,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(
+                                    9usize,
+                                    &"record with 11 fields",
+                                ),
+                            );
+                        }
+                    };
+                    let __field10 = match __serde::de::SeqAccess::next_element::<
+                        ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(
+                                    10usize,
+                                    &"record with 11 fields",
+                                ),
+                            );
+                        }
+                    };
+                    ::core::result::Result::Ok(UserConfig {
+                        name: __field0,
+                        uid: __field1,
+                        group: __field2,
+                        groups: __field3,
+                        comment: __field4,
+                        home: __field5,
+                        shell: __field6,
+                        system: __field7,
+                        password_hash: __field8,
+                        locked: __field9,
+                        authorized_keys: __field10,
+                    })
+                }
+                #[inline]
+                fn visit_map<__A>(
+                    self,
+                    mut __map: __A,
+                ) -> ::core::result::Result<Self::Value, __A::Error>
+                where
+                    __A: __serde::de::MapAccess<'de>,
+                {
+                    #[doc(hidden)]
+                    const __IDENTIFIERS: &'static [&'static str] = &[
+                        "name",
+                        "uid",
+                        "group",
+                        "groups",
+                        "comment",
+                        "home",
+                        "shell",
+                        "system",
+                        "password-hash",
+                        "locked",
+                        "authorized-keys",
+                    ];
+                    #[doc(hidden)]
+                    const __EXPECTING_IDENTIFIERS : & 'static str = "an identifier in [\"name\", \"uid\", \"group\", \"groups\", \"comment\", \"home\", \"shell\", \"system\", \"password-hash\", \"locked\", \"authorized-keys\"]" ;
+                    #[derive(:: core :: clone :: Clone, :: core :: marker :: Copy)]
+                    #[doc(hidden)]
+                    enum __Identifier {
+                        __Identifier0,
+                        __Identifier1,
+                        __Identifier2,
+                        __Identifier3,
+                        __Identifier4,
+                        __Identifier5,
+                        __Identifier6,
+                        __Identifier7,
+                        __Identifier8,
+                        __Identifier9,
+                        __Identifier10,
+                        __Unknown,
+                    }
+                    #[doc(hidden)]
+                    struct __IdentifierVisitor;
+                    impl<'de> __serde::de::Visitor<'de> for __IdentifierVisitor {
+                        type Value = __Identifier;
+                        fn expecting(
+                            &self,
+                            __formatter: &mut ::core::fmt::Formatter,
+                        ) -> ::core::fmt::Result {
+                            ::core::fmt::Formatter::write_str(__formatter, __EXPECTING_IDENTIFIERS)
+                        }
+                        fn visit_u64<__E>(
+                            self,
+                            __value: u64,
+                        ) -> ::core::result::Result<Self::Value, __E>
+                        where
+                            __E: __serde::de::Error,
+                        {
+                            match __value {
+                                0u64 => ::core::result::Result::Ok(__Identifier::__Identifier0),
+                                1u64 => ::core::result::Result::Ok(__Identifier::__Identifier1),
+                                2u64 => ::core::result::Result::Ok(__Identifier::__Identifier2),
+                                3u64 => ::core::result::Result::Ok(__Identifier::__Identifier3),
+                                4u64 => ::core::result::Result::Ok(__Identifier::__Identifier4),
+                                5u64 => ::core::result::Result::Ok(__Identifier::__Identifier5),
+                                6u64 => ::core::result::Result::Ok(__Identifier::__Identifier6),
+                                7u64 => ::core::result::Result::Ok(__Identifier::__Identifier7),
+                                8u64 => ::core::result::Result::Ok(__Identifier::__Identifier8),
+                                9u64 => ::core::result::Result::Ok(__Identifier::__Identifier9),
+                                10u64 => ::core::result::Result::Ok(__Identifier::__Identifier10),
+                                _ => ::core::result::Result::Ok(__Identifier::__Unknown),
+                            }
+                        }
+                        fn visit_str<__E>(
+                            self,
+                            __value: &str,
+                        ) -> ::core::result::Result<Self::Value, __E>
+                        where
+                            __E: __serde::de::Error,
+                        {
+                            match __value {
+                                "name" => ::core::result::Result::Ok(__Identifier::__Identifier0),
+                                "uid" => ::core::result::Result::Ok(__Identifier::__Identifier1),
+                                "group" => ::core::result::Result::Ok(__Identifier::__Identifier2),
+                                "groups" => ::core::result::Result::Ok(__Identifier::__Identifier3),
+                                "comment" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier4)
+                                }
+                                "home" => ::core::result::Result::Ok(__Identifier::__Identifier5),
+                                "shell" => ::core::result::Result::Ok(__Identifier::__Identifier6),
+                                "system" => ::core::result::Result::Ok(__Identifier::__Identifier7),
+                                "password-hash" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier8)
+                                }
+                                "locked" => ::core::result::Result::Ok(__Identifier::__Identifier9),
+                                "authorized-keys" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier10)
+                                }
+                                _ => ::core::result::Result::Ok(__Identifier::__Unknown),
+                            }
+                        }
+                        fn visit_bytes<__E>(
+                            self,
+                            __value: &[u8],
+                        ) -> ::core::result::Result<Self::Value, __E>
+                        where
+                            __E: __serde::de::Error,
+                        {
+                            match __value {
+                                b"name" => ::core::result::Result::Ok(__Identifier::__Identifier0),
+                                b"uid" => ::core::result::Result::Ok(__Identifier::__Identifier1),
+                                b"group" => ::core::result::Result::Ok(__Identifier::__Identifier2),
+                                b"groups" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier3)
+                                }
+                                b"comment" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier4)
+                                }
+                                b"home" => ::core::result::Result::Ok(__Identifier::__Identifier5),
+                                b"shell" => ::core::result::Result::Ok(__Identifier::__Identifier6),
+                                b"system" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier7)
+                                }
+                                b"password-hash" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier8)
+                                }
+                                b"locked" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier9)
+                                }
+                                b"authorized-keys" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier10)
+                                }
+                                _ => ::core::result::Result::Ok(__Identifier::__Unknown),
+                            }
+                        }
+                    }
+                    impl<'de> __serde::Deserialize<'de> for __Identifier {
+                        #[inline]
+                        fn deserialize<__D>(
+                            __deserializer: __D,
+                        ) -> ::core::result::Result<Self, __D::Error>
+                        where
+                            __D: __serde::Deserializer<'de>,
+                        {
+                            __serde::Deserializer::deserialize_identifier(
+                                __deserializer,
+                                __IdentifierVisitor,
+                            )
+                        }
+                    }
+                    let mut __field0: ::core::option::Option<::std::string::String> =
+                        ::core::option::Option::None;
+                    let mut __field1: ::core::option::Option<::std::option::Option<u32>> =
+                        ::core::option::Option::None;
+                    let mut __field2: ::core::option::Option<
+                        ::std::option::Option<::std::string::String>,
+                    > = ::core::option::Option::None;
+                    let mut __field3: ::core::option::Option<
+                        ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+                    > = ::core::option::Option::None;
+                    let mut __field4: ::core::option::Option<
+                        ::std::option::Option<::std::string::String>,
+                    > = ::core::option::Option::None;
+                    let mut __field5: ::core::option::Option<
+                        ::std::option::Option<::std::string::String>,
+                    > = ::core::option::Option::None;
+                    let mut __field6: ::core::option::Option<
+                        ::std::option::Option<::std::string::String>,
+                    > = ::core::option::Option::None;
+                    let mut __field7: ::core::option::Option<::std::option::Option<bool>> =
+                        ::core::option::Option::None;
+                    let mut __field8: ::core::option::Option<
+                        ::std::option::Option<::std::string::String>,
+                    > = ::core::option::Option::None;
+                    let mut __field9: ::core::option::Option<::std::option::Option<bool>> =
+                        ::core::option::Option::None;
+                    let mut __field10: ::core::option::Option<
+                        ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+                    > = ::core::option::Option::None;
+                    while let ::core::option::Option::Some(__key) =
+                        __serde::de::MapAccess::next_key::<__Identifier>(&mut __map)?
+                    {
+                        match __key {
+                            __Identifier::__Identifier0 => {
+                                if ::core::option::Option::is_some(&__field0) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field("name"),
+                                    );
+                                }
+                                __field0 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<::std::string::String>(
+                                        &mut __map,
+                                    )?,
+                                );
+                            }
+                            __Identifier::__Identifier1 => {
+                                if ::core::option::Option::is_some(&__field1) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field("uid"),
+                                    );
+                                }
+                                __field1 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<::std::option::Option<u32>>(
+                                        &mut __map,
+                                    )?,
+                                );
+                            }
+                            __Identifier::__Identifier2 => {
+                                if ::core::option::Option::is_some(&__field2) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field(
+                                            "group",
+                                        ),
+                                    );
+                                }
+                                __field2 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<
+                                        ::std::option::Option<::std::string::String>,
+                                    >(&mut __map)?,
+                                );
+                            }
+                            __Identifier::__Identifier3 => {
+                                if ::core::option::Option::is_some(&__field3) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field(
+                                            "groups",
+                                        ),
+                                    );
+                                }
+                                __field3 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<
+                                        ::std::option::Option<
+                                            ::std::vec::Vec<::std::string::String>,
+                                        >,
+                                    >(&mut __map)?,
+                                );
+                            }
+                            __Identifier::__Identifier4 => {
+                                if ::core::option::Option::is_some(&__field4) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field(
+                                            "comment",
+                                        ),
+                                    );
+                                }
+                                __field4 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<
+                                        ::std::option::Option<::std::string::String>,
+                                    >(&mut __map)?,
+                                );
+                            }
+                            __Identifier::__Identifier5 => {
+                                if ::core::option::Option::is_some(&__field5) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field("home"),
+                                    );
+                                }
+                                __field5 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<
+                                        ::std::option::Option<::std::string::String>,
+                                    >(&mut __map)?,
+                                );
+                            }
+                            __Identifier::__Identifier6 => {
+                                if ::core::option::Option::is_some(&__field6) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field(
+                                            "shell",
+                                        ),
+                                    );
+                                }
+                                __field6 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<
+                                        ::std::option::Option<::std::string::String>,
+                                    >(&mut __map)?,
+                                );
+                            }
+                            __Identifier::__Identifier7 => {
+                                if ::core::option::Option::is_some(&__field7) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field(
+                                            "system",
+                                        ),
+                                    );
+                                }
+                                __field7 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<
+                                        ::std::option::Option<bool>,
+                                    >(&mut __map)?,
+                                );
+                            }
+                            __Identifier::__Identifier8 => {
+                                if ::core::option::Option::is_some(&__field8) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field(
+                                            "password-hash",
+                                        ),
+                                    );
+                                }
+                                __field8 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<
+                                        ::std::option::Option<::std::string::String>,
+                                    >(&mut __map)?,
+                                );
+                            }
+                            __Identifier::__Identifier9 => {
+                                if ::core::option::Option::is_some(&__field9) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field(
+                                            "locked",
+                                        ),
+                                    );
+                                }
+                                __field9 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<
+                                        ::std::option::Option<bool>,
+                                    >(&mut __map)?,
+                                );
+                            }
+                            __Identifier::__Identifier10 => {
+                                if ::core::option::Option::is_some(&__field10) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field(
+                                            "authorized-keys",
+                                        ),
+                                    );
+                                }
+                                __field10 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<
+                                        ::std::option::Option<
+                                            ::std::vec::Vec<::std::string::String>,
+                                        >,
+                                    >(&mut __map)?,
+                                );
+                            }
+                            _ => {
+                                __serde::de::MapAccess::next_value::<__serde::de::IgnoredAny>(
+                                    &mut __map,
+                                )?;
+                            }
+                        }
+                    }
+                    let __field0 = match __field0 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                <__A::Error as __serde::de::Error>::missing_field("name"),
+                            );
+                        }
+                    };
+                    let __field1 = match __field1 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    };
+                    let __field2 = match __field2 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    };
+                    let __field3 = match __field3 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    };
+                    let __field4 = match __field4 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    };
+                    let __field5 = match __field5 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    };
+                    let __field6 = match __field6 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    };
+                    let __field7 = match __field7 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    };
+                    let __field8 = match __field8 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    };
+                    let __field9 = match __field9 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    };
+                    let __field10 = match __field10 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    };
+                    ::core::result::Result::Ok(UserConfig {
+                        name: __field0,
+                        uid: __field1,
+                        group: __field2,
+                        groups: __field3,
+                        comment: __field4,
+                        home: __field5,
+                        shell: __field6,
+                        system: __field7,
+                        password_hash: __field8,
+                        locked: __field9,
+                        authorized_keys: __field10,
+                    })
+                }
+            }
+            #[doc(hidden)]
+            const __FIELDS: &'static [&'static str] = &[
+                "name",
+                "uid",
+                "group",
+                "groups",
+                "comment",
+                "home",
+                "shell",
+                "system",
+                "password-hash",
+                "locked",
+                "authorized-keys",
+            ];
+            __serde::Deserializer::deserialize_struct(
+                __deserializer,
+                "UserConfig",
+                __FIELDS,
+                __Visitor {
+                    __phantom_vars: ::core::marker::PhantomData,
+                },
+            )
+        }
+    }
+    #[doc = "Systemd units to enable, disable, or mask.\n"]
+    #[derive(Clone, Debug)]
+    pub struct SystemdConfig {
+        #[doc = "Units to enable.\n"]
+        pub enable: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+        #[doc = "Units to disable.\n"]
+        pub disable: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+        #[doc = "Units to mask.\n"]
+        pub mask: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+    }
+    impl SystemdConfig {
+        #[doc = "Creates a new [`SystemdConfig`]."]
+        pub fn new() -> Self {
+            Self {
+                enable: ::std::default::Default::default(),
+                disable: ::std::default::Default::default(),
+                mask: ::std::default::Default::default(),
+            }
+        }
+        #[doc = "Sets the value of `enable`."]
+        pub fn set_enable(
+            &mut self,
+            enable: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+        ) -> &mut Self {
+            self.enable = enable;
+            self
+        }
+        #[doc = "Sets the value of `enable`."]
+        pub fn with_enable(
+            mut self,
+            enable: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+        ) -> Self {
+            self.enable = enable;
+            self
+        }
+        #[doc = "Sets the value of `disable`."]
+        pub fn set_disable(
+            &mut self,
+            disable: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+        ) -> &mut Self {
+            self.disable = disable;
+            self
+        }
+        #[doc = "Sets the value of `disable`."]
+        pub fn with_disable(
+            mut self,
+            disable: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+        ) -> Self {
+            self.disable = disable;
+            self
+        }
+        #[doc = "Sets the value of `mask`."]
+        pub fn set_mask(
+            &mut self,
+            mask: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+        ) -> &mut Self {
+            self.mask = mask;
+            self
+        }
+        #[doc = "Sets the value of `mask`."]
+        pub fn with_mask(
+            mut self,
+            mask: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+        ) -> Self {
+            self.mask = mask;
+            self
+        }
+    }
+    impl ::std::default::Default for SystemdConfig {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+    #[automatically_derived]
+    impl __serde::Serialize for SystemdConfig {
+        fn serialize<__S: __serde::Serializer>(
+            &self,
+            __serializer: __S,
+        ) -> ::std::result::Result<__S::Ok, __S::Error> {
+            let mut __record =
+                __sidex_serde::ser::RecordSerializer::new(__serializer, "SystemdConfig", 3usize)?;
+            __record
+                .serialize_optional_field("enable", ::core::option::Option::as_ref(&self.enable))?;
+            __record.serialize_optional_field(
+                "disable",
+                ::core::option::Option::as_ref(&self.disable),
+            )?;
+            __record
+                .serialize_optional_field("mask", ::core::option::Option::as_ref(&self.mask))?;
+            __record.end()
+        }
+    }
+    #[automatically_derived]
+    impl<'de> __serde::Deserialize<'de> for SystemdConfig {
+        fn deserialize<__D: __serde::Deserializer<'de>>(
+            __deserializer: __D,
+        ) -> ::std::result::Result<Self, __D::Error> {
+            #[doc(hidden)]
+            struct __Visitor {
+                __phantom_vars: ::core::marker::PhantomData<fn(&())>,
+            }
+            impl<'de> __serde::de::Visitor<'de> for __Visitor {
+                type Value = SystemdConfig;
+                fn expecting(
+                    &self,
+                    __formatter: &mut ::core::fmt::Formatter,
+                ) -> ::core::fmt::Result {
+                    ::core::fmt::Formatter::write_str(__formatter, "record SystemdConfig")
+                }
+                #[inline]
+                fn visit_seq<__A>(
+                    self,
+                    mut __seq: __A,
+                ) -> ::core::result::Result<Self::Value, __A::Error>
+                where
+                    __A: __serde::de::SeqAccess<'de>,
+                {
+                    let __field0 = match __serde::de::SeqAccess::next_element::<
+                        ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(0usize, &"record with 3 fields"),
+                            );
+                        }
+                    };
+                    let __field1 = match __serde::de::SeqAccess::next_element::<
+                        ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(1usize, &"record with 3 fields"),
+                            );
+                        }
+                    };
+                    let __field2 = match __serde::de::SeqAccess::next_element::<
+                        ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+                    >(&mut __seq)?
+                    {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                __serde::de::Error::invalid_length(2usize, &"record with 3 fields"),
+                            );
+                        }
+                    };
+                    ::core::result::Result::Ok(SystemdConfig {
+                        enable: __field0,
+                        disable: __field1,
+                        mask: __field2,
+                    })
+                }
+                #[inline]
+                fn visit_map<__A>(
+                    self,
+                    mut __map: __A,
+                ) -> ::core::result::Result<Self::Value, __A::Error>
+                where
+                    __A: __serde::de::MapAccess<'de>,
+                {
+                    #[doc(hidden)]
+                    const __IDENTIFIERS: &'static [&'static str] = &["enable", "disable", "mask"];
+                    #[doc(hidden)]
+                    const __EXPECTING_IDENTIFIERS: &'static str =
+                        "an identifier in [\"enable\", \"disable\", \"mask\"]";
+                    #[derive(:: core :: clone :: Clone, :: core :: marker :: Copy)]
+                    #[doc(hidden)]
+                    enum __Identifier {
+                        __Identifier0,
+                        __Identifier1,
+                        __Identifier2,
+                        __Unknown,
                     }
-                    #[inline]
-                    fn visit_str<__E>(
-                        self,
-                        __value: &str,
-                    ) -> ::core::result::Result<Self::Value, __E>
-                    where
-                        __E: __serde::de::Error,
-                    {
-                        let __identifier = __IdentifierVisitor.visit_str(__value)?;
-                        #[allow(unreachable_patterns)]
-                        match __identifier {
-                            _ => Err(__E::invalid_value(
-                                __serde::de::Unexpected::Str(__value),
-                                &self,
-                            )),
+                    #[doc(hidden)]
+                    struct __IdentifierVisitor;
+                    impl<'de> __serde::de::Visitor<'de> for __IdentifierVisitor {
+                        type Value = __Identifier;
+                        fn expecting(
+                            &self,
+                            __formatter: &mut ::core::fmt::Formatter,
+                        ) -> ::core::fmt::Result {
+                            ::core::fmt::Formatter::write_str(__formatter, __EXPECTING_IDENTIFIERS)
+                        }
+                        fn visit_u64<__E>(
+                            self,
+                            __value: u64,
+                        ) -> ::core::result::Result<Self::Value, __E>
+                        where
+                            __E: __serde::de::Error,
+                        {
+                            match __value {
+                                0u64 => ::core::result::Result::Ok(__Identifier::__Identifier0),
+                                1u64 => ::core::result::Result::Ok(__Identifier::__Identifier1),
+                                2u64 => ::core::result::Result::Ok(__Identifier::__Identifier2),
+                                _ => ::core::result::Result::Ok(__Identifier::__Unknown),
+                            }
+                        }
+                        fn visit_str<__E>(
+                            self,
+                            __value: &str,
+                        ) -> ::core::result::Result<Self::Value, __E>
+                        where
+                            __E: __serde::de::Error,
+                        {
+                            match __value {
+                                "enable" => ::core::result::Result::Ok(__Identifier::__Identifier0),
+                                "disable" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier1)
+                                }
+                                "mask" => ::core::result::Result::Ok(__Identifier::__Identifier2),
+                                _ => ::core::result::Result::Ok(__Identifier::__Unknown),
+                            }
+                        }
+                        fn visit_bytes<__E>(
+                            self,
+                            __value: &[u8],
+                        ) -> ::core::result::Result<Self::Value, __E>
+                        where
+                            __E: __serde::de::Error,
+                        {
+                            match __value {
+                                b"enable" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier0)
+                                }
+                                b"disable" => {
+                                    ::core::result::Result::Ok(__Identifier::__Identifier1)
+                                }
+                                b"mask" => ::core::result::Result::Ok(__Identifier::__Identifier2),
+                                _ => ::core::result::Result::Ok(__Identifier::__Unknown),
+                            }
                         }
                     }
-                    #[inline]
-                    fn visit_enum<__A>(
-                        self,
-                        __data: __A,
-                    ) -> ::core::result::Result<Self::Value, __A::Error>
-                    where
-                        __A: __serde::de::EnumAccess<'de>,
+                    impl<'de> __serde::Deserialize<'de> for __Identifier {
+                        #[inline]
+                        fn deserialize<__D>(
+                            __deserializer: __D,
+                        ) -> ::core::result::Result<Self, __D::Error>
+                        where
+                            __D: __serde::Deserializer<'de>,
+                        {
+                            __serde::Deserializer::deserialize_identifier(
+                                __deserializer,
+                                __IdentifierVisitor,
+                            )
+                        }
+                    }
+                    let mut __field0: ::core::option::Option<
+                        ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+                    > = ::core::option::Option::None;
+                    let mut __field1: ::core::option::Option<
+                        ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+                    > = ::core::option::Option::None;
+                    let mut __field2: ::core::option::Option<
+                        ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+                    > = ::core::option::Option::None;
+                    while let ::core::option::Option::Some(__key) =
+                        __serde::de::MapAccess::next_key::<__Identifier>(&mut __map)?
                     {
-                        match __serde::de::EnumAccess::variant::<__Identifier>(__data)? {
-                            (__Identifier::__Identifier0, __variant) => {
-                                let __value = __serde::de::VariantAccess::newtype_variant::<
-                                    ::std::string::String,
-                                >(__variant)?;
-                                ::core::result::Result::Ok(ParameterValue::String(__value))
+                        match __key {
+                            __Identifier::__Identifier0 => {
+                                if ::core::option::Option::is_some(&__field0) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field(
+                                            "enable",
+                                        ),
+                                    );
+                                }
+                                __field0 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<
+                                        ::std::option::Option<
+                                            ::std::vec::Vec<::std::string::String>,
+                                        >,
+                                    >(&mut __map)?,
+                                );
                             }
-                            (__Identifier::__Identifier1, __variant) => {
-                                let __value =
-                                    __serde::de::VariantAccess::newtype_variant::<bool>(__variant)?;
-                                ::core::result::Result::Ok(ParameterValue::Boolean(__value))
+                            __Identifier::__Identifier1 => {
+                                if ::core::option::Option::is_some(&__field1) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field(
+                                            "disable",
+                                        ),
+                                    );
+                                }
+                                __field1 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<
+                                        ::std::option::Option<
+                                            ::std::vec::Vec<::std::string::String>,
+                                        >,
+                                    >(&mut __map)?,
+                                );
                             }
-                            (__Identifier::__Identifier2, __variant) => {
-                                let __value =
-                                    __serde::de::VariantAccess::newtype_variant::<i64>(__variant)?;
-                                ::core::result::Result::Ok(ParameterValue::Integer(__value))
+                            __Identifier::__Identifier2 => {
+                                if ::core::option::Option::is_some(&__field2) {
+                                    return ::core::result::Result::Err(
+                                        <__A::Error as __serde::de::Error>::duplicate_field("mask"),
+                                    );
+                                }
+                                __field2 = ::core::option::Option::Some(
+                                    __serde::de::MapAccess::next_value::<
+                                        ::std::option::Option<
+                                            ::std::vec::Vec<::std::string::String>,
+                                        >,
+                                    >(&mut __map)?,
+                                );
                             }
-                            (__Identifier::__Identifier3, __variant) => {
-                                let __value =
-                                    __serde::de::VariantAccess::newtype_variant::<f64>(__variant)?;
-                                ::core::result::Result::Ok(ParameterValue::Float(__value))
+                            _ => {
+                                __serde::de::MapAccess::next_value::<__serde::de::IgnoredAny>(
+                                    &mut __map,
+                                )?;
                             }
                         }
                     }
+                    let __field0 = match __field0 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    };
+                    let __field1 = match __field1 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    };
+                    let __field2 = match __field2 {
+                        ::core::option::Option::Some(__value) => __value,
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    };
+                    ::core::result::Result::Ok(SystemdConfig {
+                        enable: __field0,
+                        disable: __field1,
+                        mask: __field2,
+                    })
                 }
-                __serde::Deserializer::deserialize_enum(
-                    __deserializer,
-                    "ParameterValue",
-                    __VARIANTS,
-                    __Visitor {
-                        __phantom_vars: ::core::marker::PhantomData,
-                    },
-                )
             }
+            #[doc(hidden)]
+            const __FIELDS: &'static [&'static str] = &["enable", "disable", "mask"];
+            __serde::Deserializer::deserialize_struct(
+                __deserializer,
+                "SystemdConfig",
+                __FIELDS,
+                __Visitor {
+                    __phantom_vars: ::core::marker::PhantomData,
+                },
+            )
         }
     }
 }
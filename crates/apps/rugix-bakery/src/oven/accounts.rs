@@ -0,0 +1,437 @@
+//! Declarative creation of users and groups.
+//!
+//! Users and groups are added to the root's `/etc/passwd`, `/etc/group`, `/etc/shadow`,
+//! and `/etc/gshadow` directly, without a `chroot`. Ids which are not given explicitly
+//! are derived from the names, such that they are stable across rebuilds and do not
+//! depend on the order in which recipes create users and groups.
+
+use std::collections::HashSet;
+use std::fs;
+use std::ops::RangeInclusive;
+use std::os::unix::fs::{lchown, PermissionsExt};
+use std::path::{Path, PathBuf};
+
+use reportify::{bail, ResultExt};
+
+use crate::config::recipes::{GroupConfig, UserConfig};
+use crate::utils::caching::Hasher;
+use crate::BakeryResult;
+
+use super::files::resolve_in_root;
+
+/// Ids allocated to system users and groups.
+const SYSTEM_IDS: RangeInclusive<u32> = 100..=999;
+
+/// Ids allocated to regular users and groups.
+const REGULAR_IDS: RangeInclusive<u32> = 1000..=59999;
+
+/// Create the given groups and users in the given root.
+///
+/// Groups and users which exist already are kept as they are, however, users are
+/// added to the given supplementary groups and their authorized keys are replaced.
+pub fn create_accounts(
+    root: &Path,
+    groups: &[GroupConfig],
+    users: &[UserConfig],
+    source_date_epoch: u64,
+) -> BakeryResult<()> {
+    let mut databases = Databases::load(root)?;
+    for group in groups {
+        databases
+            .add_group(&group.name, group.gid, group.system.unwrap_or(false))
+            .with_info(|_| format!("creating group `{}`", group.name))?;
+    }
+    let mut homes = Vec::new();
+    for user in users {
+        let home = databases
+            .add_user(user, source_date_epoch)
+            .with_info(|_| format!("creating user `{}`", user.name))?;
+        homes.push(home);
+    }
+    databases.write()?;
+    for (user, (uid, gid, home)) in users.iter().zip(homes) {
+        let create_home = user.home.is_some() || !user.system.unwrap_or(false);
+        let home = resolve_in_root(root, Path::new(&home))?;
+        if create_home && !home.exists() {
+            fs::create_dir_all(&home).whatever("unable to create home directory")?;
+            fs::set_permissions(&home, fs::Permissions::from_mode(0o750))
+                .whatever("unable to set permissions of home directory")?;
+            lchown(&home, Some(uid), Some(gid))
+                .whatever("unable to set owner of home directory")?;
+        }
+        if let Some(keys) = &user.authorized_keys {
+            write_authorized_keys(&home, keys, uid, gid)
+                .with_info(|_| format!("writing authorized keys of user `{}`", user.name))?;
+        }
+    }
+    Ok(())
+}
+
+/// Write the given keys to the `.ssh/authorized_keys` file in the given home.
+fn write_authorized_keys(home: &Path, keys: &[String], uid: u32, gid: u32) -> BakeryResult<()> {
+    let ssh_dir = home.join(".ssh");
+    fs::create_dir_all(&ssh_dir).whatever("unable to create `.ssh` directory")?;
+    fs::set_permissions(&ssh_dir, fs::Permissions::from_mode(0o700))
+        .whatever("unable to set permissions of `.ssh` directory")?;
+    lchown(&ssh_dir, Some(uid), Some(gid)).whatever("unable to set owner of `.ssh` directory")?;
+    let authorized_keys = ssh_dir.join("authorized_keys");
+    let mut contents = keys.join("\n");
+    contents.push('\n');
+    fs::write(&authorized_keys, contents).whatever("unable to write authorized keys")?;
+    fs::set_permissions(&authorized_keys, fs::Permissions::from_mode(0o600))
+        .whatever("unable to set permissions of authorized keys")?;
+    lchown(&authorized_keys, Some(uid), Some(gid))
+        .whatever("unable to set owner of authorized keys")
+}
+
+/// User and group databases of a root.
+struct Databases {
+    passwd: Database,
+    shadow: Database,
+    group: Database,
+    gshadow: Database,
+}
+
+impl Databases {
+    fn load(root: &Path) -> BakeryResult<Self> {
+        Ok(Self {
+            passwd: Database::load(root, "etc/passwd", 0o644)?,
+            shadow: Database::load(root, "etc/shadow", 0o640)?,
+            group: Database::load(root, "etc/group", 0o644)?,
+            gshadow: Database::load(root, "etc/gshadow", 0o640)?,
+        })
+    }
+
+    fn write(&self) -> BakeryResult<()> {
+        self.passwd.write()?;
+        self.shadow.write()?;
+        self.group.write()?;
+        self.gshadow.write()
+    }
+
+    /// Add a group unless it exists and return its id.
+    fn add_group(&mut self, name: &str, gid: Option<u32>, system: bool) -> BakeryResult<u32> {
+        if let Some(existing) = self.group.id(name) {
+            if gid.is_some_and(|gid| gid != existing) {
+                bail!("group exists with different id {existing}");
+            }
+            return Ok(existing);
+        }
+        let used = self.group.ids();
+        let gid = match gid {
+            Some(gid) if used.contains(&gid) => bail!("group id {gid} is already in use"),
+            Some(gid) => gid,
+            None => allocate_id(name, id_range(system), &used)?,
+        };
+        self.group.push(
+            [name, "x", &gid.to_string(), ""]
+                .map(str::to_owned)
+                .to_vec(),
+        );
+        if self.gshadow.exists {
+            self.gshadow
+                .push([name, "!", "", ""].map(str::to_owned).to_vec());
+        }
+        Ok(gid)
+    }
+
+    /// Add a user unless it exists and return its ids and home directory.
+    fn add_user(
+        &mut self,
+        user: &UserConfig,
+        source_date_epoch: u64,
+    ) -> BakeryResult<(u32, u32, String)> {
+        let name = user.name.as_str();
+        let system = user.system.unwrap_or(false);
+        for group in user.groups.iter().flatten() {
+            self.add_member(group, name)?;
+        }
+        if let Some(existing) = self.passwd.find(name) {
+            let (Some(uid), Some(gid), Some(home)) = (
+                existing.get(2).and_then(|uid| uid.parse().ok()),
+                existing.get(3).and_then(|gid| gid.parse().ok()),
+                existing.get(5),
+            ) else {
+                bail!("invalid entry of existing user");
+            };
+            if user.uid.is_some_and(|expected| expected != uid) {
+                bail!("user exists with different id {uid}");
+            }
+            return Ok((uid, gid, home.clone()));
+        }
+        let used = self.passwd.ids();
+        let uid = match user.uid {
+            Some(uid) if used.contains(&uid) => bail!("user id {uid} is already in use"),
+            Some(uid) => uid,
+            None => allocate_id(name, id_range(system), &used)?,
+        };
+        let gid = match &user.group {
+            Some(group) => match self.group.id(group).or_else(|| group.parse().ok()) {
+                Some(gid) => gid,
+                None => bail!("unable to find group `{group}`"),
+            },
+            None => match self.group.id(name) {
+                Some(gid) => gid,
+                None => {
+                    // Prefer a group id matching the user id.
+                    let gid = Some(uid).filter(|uid| !self.group.ids().contains(uid));
+                    self.add_group(name, gid, system)?
+                }
+            },
+        };
+        let home = match &user.home {
+            Some(home) => home.clone(),
+            None if system => "/nonexistent".to_owned(),
+            None => format!("/home/{name}"),
+        };
+        let shell = match &user.shell {
+            Some(shell) => shell.as_str(),
+            None if system => "/usr/sbin/nologin",
+            None => "/bin/sh",
+        };
+        let password = match (&user.password_hash, user.locked) {
+            (Some(hash), Some(true)) => format!("!{hash}"),
+            (Some(hash), _) => hash.clone(),
+            (None, Some(false)) => bail!("unlocked users require a `password-hash`"),
+            (None, _) => "!".to_owned(),
+        };
+        self.passwd.push(vec![
+            name.to_owned(),
+            "x".to_owned(),
+            uid.to_string(),
+            gid.to_string(),
+            user.comment.clone().unwrap_or_default(),
+            home.clone(),
+            shell.to_owned(),
+        ]);
+        if self.shadow.exists {
+            // Days since the epoch of the last password change.
+            let last_change = source_date_epoch / 86400;
+            self.shadow.push(
+                [
+                    name,
+                    &password,
+                    &last_change.to_string(),
+                    "0",
+                    "99999",
+                    "7",
+                    "",
+                    "",
+                    "",
+                ]
+                .map(str::to_owned)
+                .to_vec(),
+            );
+        } else if user.password_hash.is_some() {
+            bail!("unable to set password without `/etc/shadow`");
+        }
+        Ok((uid, gid, home))
+    }
+
+    /// Add a user to the members of a group.
+    fn add_member(&mut self, group: &str, user: &str) -> BakeryResult<()> {
+        if self.group.find(group).is_none() {
+            bail!("unable to find group `{group}`");
+        }
+        self.group.add_member(group, user);
+        self.gshadow.add_member(group, user);
+        Ok(())
+    }
+}
+
+/// Colon-separated database like `/etc/passwd`.
+struct Database {
+    path: PathBuf,
+    mode: u32,
+    exists: bool,
+    entries: Vec<Vec<String>>,
+    modified: bool,
+}
+
+impl Database {
+    fn load(root: &Path, path: &str, mode: u32) -> BakeryResult<Self> {
+        let path = root.join(path);
+        let exists = path.exists();
+        let mut entries = Vec::new();
+        if exists {
+            let contents =
+                fs::read_to_string(&path).whatever_with(|_| format!("unable to read {path:?}"))?;
+            for line in contents.lines().filter(|line| !line.is_empty()) {
+                entries.push(line.split(':').map(str::to_owned).collect());
+            }
+        }
+        Ok(Self {
+            path,
+            mode,
+            exists,
+            entries,
+            modified: false,
+        })
+    }
+
+    fn write(&self) -> BakeryResult<()> {
+        if !self.modified {
+            return Ok(());
+        }
+        let mut contents = String::new();
+        for entry in &self.entries {
+            contents.push_str(&entry.join(":"));
+            contents.push('\n');
+        }
+        fs::write(&self.path, contents)
+            .whatever_with(|_| format!("unable to write {:?}", self.path))?;
+        if !self.exists {
+            fs::set_permissions(&self.path, fs::Permissions::from_mode(self.mode))
+                .whatever_with(|_| format!("unable to set permissions of {:?}", self.path))?;
+        }
+        Ok(())
+    }
+
+    fn push(&mut self, entry: Vec<String>) {
+        self.entries.push(entry);
+        self.modified = true;
+    }
+
+    fn find(&self, name: &str) -> Option<&Vec<String>> {
+        self.entries.iter().find(|entry| entry[0] == name)
+    }
+
+    /// Numeric id of the entry with the given name.
+    fn id(&self, name: &str) -> Option<u32> {
+        self.find(name)?.get(2)?.parse().ok()
+    }
+
+    /// Numeric ids of all entries.
+    fn ids(&self) -> HashSet<u32> {
+        self.entries
+            .iter()
+            .filter_map(|entry| entry.get(2)?.parse().ok())
+            .collect()
+    }
+
+    /// Add a user to the members of a group in `/etc/group` or `/etc/gshadow`.
+    fn add_member(&mut self, group: &str, user: &str) {
+        let Some(entry) = self.entries.iter_mut().find(|entry| entry[0] == group) else {
+            return;
+        };
+        let Some(members) = entry.get_mut(3) else {
+            return;
+        };
+        if members.split(',').any(|member| member == user) {
+            return;
+        }
+        if !members.is_empty() {
+            members.push(',');
+        }
+        members.push_str(user);
+        self.modified = true;
+    }
+}
+
+/// Range from which ids of system or regular users and groups are allocated.
+fn id_range(system: bool) -> RangeInclusive<u32> {
+    if system {
+        SYSTEM_IDS
+    } else {
+        REGULAR_IDS
+    }
+}
+
+/// Allocate an id for the given name.
+///
+/// The id is derived from a digest of the name. If it is already in use, the next free
+/// id of the range is used.
+fn allocate_id(name: &str, range: RangeInclusive<u32>, used: &HashSet<u32>) -> BakeryResult<u32> {
+    let mut hasher = Hasher::new();
+    hasher.push("name", name);
+    let digest = hasher.finalize();
+    let start = u32::from_str_radix(&digest[..8], 16).unwrap();
+    let size = range.end() - range.start() + 1;
+    for offset in 0..size {
+        let id = range.start() + (start.wrapping_add(offset)) % size;
+        if !used.contains(&id) {
+            return Ok(id);
+        }
+    }
+    bail!("no free id left for `{name}`")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn load_databases(passwd: &str, group: &str) -> (tempfile::TempDir, Databases) {
+        let root = tempfile::tempdir().unwrap();
+        fs::create_dir(root.path().join("etc")).unwrap();
+        fs::write(root.path().join("etc/passwd"), passwd).unwrap();
+        fs::write(root.path().join("etc/group"), group).unwrap();
+        let databases = Databases::load(root.path()).unwrap();
+        (root, databases)
+    }
+
+    #[test]
+    fn test_allocate_id_is_stable() {
+        let used = HashSet::new();
+        let id = allocate_id("alice", REGULAR_IDS, &used).unwrap();
+        assert!(REGULAR_IDS.contains(&id));
+        assert_eq!(allocate_id("alice", REGULAR_IDS, &used).unwrap(), id);
+        let id = allocate_id("alice", SYSTEM_IDS, &used).unwrap();
+        assert!(SYSTEM_IDS.contains(&id));
+    }
+
+    #[test]
+    fn test_allocate_id_skips_used_ids() {
+        let range = 10..=19;
+        let id = allocate_id("alice", range.clone(), &HashSet::new()).unwrap();
+        let next = if id == *range.end() {
+            *range.start()
+        } else {
+            id + 1
+        };
+        let used = HashSet::from([id]);
+        assert_eq!(allocate_id("alice", range.clone(), &used).unwrap(), next);
+        let used = range.clone().filter(|other| *other != id).collect();
+        assert_eq!(allocate_id("bob", range.clone(), &used).unwrap(), id);
+        let used = range.clone().collect();
+        assert!(allocate_id("alice", range, &used).is_err());
+    }
+
+    #[test]
+    fn test_add_user_allocates_group() {
+        let (_root, mut databases) =
+            load_databases("root:x:0:0:root:/root:/bin/sh\n", "root:x:0:\n");
+        let (uid, gid, home) = databases
+            .add_user(&UserConfig::new("alice".to_owned()), 0)
+            .unwrap();
+        assert!(REGULAR_IDS.contains(&uid));
+        assert_eq!(gid, uid);
+        assert_eq!(home, "/home/alice");
+        assert_eq!(databases.group.id("alice"), Some(gid));
+        // Adding the user again keeps the existing entry.
+        let existing = databases
+            .add_user(&UserConfig::new("alice".to_owned()), 0)
+            .unwrap();
+        assert_eq!(existing, (uid, gid, home));
+    }
+
+    #[test]
+    fn test_add_user_uses_existing_group() {
+        let (_root, mut databases) =
+            load_databases("root:x:0:0:root:/root:/bin/sh\n", "alice:x:1234:\n");
+        let (uid, gid, _) = databases
+            .add_user(&UserConfig::new("alice".to_owned()), 0)
+            .unwrap();
+        assert_eq!(gid, 1234);
+        assert!(REGULAR_IDS.contains(&uid));
+        assert_eq!(databases.group.entries.len(), 1);
+    }
+
+    #[test]
+    fn test_add_user_with_used_id() {
+        let (_root, mut databases) =
+            load_databases("root:x:0:0:root:/root:/bin/sh\n", "root:x:0:\n");
+        let user = UserConfig::new("alice".to_owned()).with_uid(Some(0));
+        assert!(databases.add_user(&user, 0).is_err());
+        assert!(databases.add_group("wheel", Some(0), true).is_err());
+    }
+}
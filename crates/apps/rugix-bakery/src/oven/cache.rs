@@ -0,0 +1,321 @@
+//! Shared cache for baked layers.
+//!
+//! Each entry of the cache is stored under the id of the layer and consists of the
+//! layer's `system.tar` and a `manifest.json` describing the inputs of the layer and
+//! the checksum of `system.tar` as well as the layer's provenance and size report. The
+//! manifest is stored last, hence, entries without a manifest are incomplete and ignored.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use reportify::{bail, ResultExt};
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+use url::Url;
+
+use crate::config::load_json;
+use crate::config::projects::LayerCacheConfig;
+use crate::project::ProjectRef;
+use crate::utils::atomic;
+use crate::utils::caching::sha256_file;
+use crate::BakeryResult;
+
+use super::customize::digest_used_files;
+use super::provenance::{LayerProvenance, PROVENANCE_FILE};
+use super::size::{SizeReport, SIZE_REPORT_FILE};
+
+/// Timeout for connecting to an HTTP cache.
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Timeout for requesting manifests from an HTTP cache.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Timeout for transferring layers from and to an HTTP cache.
+const TRANSFER_TIMEOUT: Duration = Duration::from_secs(60 * 60);
+
+/// Manifest of a cache entry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CacheManifest {
+    /// Id of the layer.
+    pub id: String,
+    /// Inputs from which the id of the layer has been derived.
+    pub inputs: Vec<(String, String)>,
+    /// SHA256 digest of the layer's `system.tar`.
+    pub sha256: String,
+    /// Size of the layer's `system.tar` in bytes.
+    pub size: u64,
+    /// Files used by the layer, i.e., the contents of `rebuild-if-changed.txt`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub used_files: Option<String>,
+    /// Digest of the files used by the layer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub used_files_digest: Option<String>,
+    /// Provenance of the layer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provenance: Option<LayerProvenance>,
+    /// Size report of the layer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size_report: Option<SizeReport>,
+}
+
+/// Shared cache for baked layers.
+#[derive(Debug)]
+pub enum LayerCache {
+    /// Cache in a local or network-mounted directory.
+    Dir(PathBuf),
+    /// Cache on an HTTP server supporting `GET` and `PUT` requests.
+    Http {
+        /// Base URL of the cache.
+        url: Url,
+        /// Client with connect and request timeouts.
+        client: reqwest::blocking::Client,
+    },
+}
+
+impl LayerCache {
+    /// Layer cache configured for the project, if any.
+    pub fn from_project(project: &ProjectRef) -> BakeryResult<Option<Self>> {
+        let Some(config) = &project.config().layer_cache else {
+            return Ok(None);
+        };
+        Ok(Some(match config {
+            LayerCacheConfig::Dir(config) => LayerCache::Dir(project.dir().join(&config.dir)),
+            LayerCacheConfig::Http(config) => {
+                let mut url = config.url.clone();
+                if !url.ends_with('/') {
+                    url.push('/');
+                }
+                LayerCache::Http {
+                    url: url.parse().whatever("unable to parse layer cache URL")?,
+                    client: reqwest::blocking::Client::builder()
+                        .connect_timeout(CONNECT_TIMEOUT)
+                        .timeout(REQUEST_TIMEOUT)
+                        .build()
+                        .whatever("unable to create HTTP client")?,
+                }
+            }
+        }))
+    }
+
+    /// Try to pull the layer with the given id into the given layer directory.
+    ///
+    /// Returns `false` if the cache does not have a usable entry for the layer or if the
+    /// cache cannot be reached in time.
+    pub fn pull(&self, project: &ProjectRef, id: &str, layer_dir: &Path) -> BakeryResult<bool> {
+        let Some(manifest) = self.manifest(id)? else {
+            return Ok(false);
+        };
+        if manifest.id != id {
+            bail!(
+                "manifest of cached layer {id} has invalid id {}",
+                manifest.id
+            );
+        }
+        fs::create_dir_all(layer_dir).whatever("unable to create layer directory")?;
+        atomic::unmark_complete(layer_dir).whatever("unable to reset layer completion marker")?;
+        let used_files = layer_dir.join("rebuild-if-changed.txt");
+        let used_files_digest = layer_dir.join("rebuild-if-changed.digest");
+        fs::remove_file(&used_files).ok();
+        fs::remove_file(&used_files_digest).ok();
+        if let Some(files) = &manifest.used_files {
+            fs::write(&used_files, files).whatever("unable to write used files")?;
+            let digest = digest_used_files(project, &used_files).ok();
+            if digest.is_none() || digest != manifest.used_files_digest {
+                info!("files used by cached layer {id} differ from local files");
+                fs::remove_file(&used_files).ok();
+                return Ok(false);
+            }
+            fs::write(&used_files_digest, digest.unwrap())
+                .whatever("unable to write digest of used files")?;
+        }
+        info!("pulling layer {id} from cache");
+        let system_tar = layer_dir.join("system.tar");
+        let partial_system_tar = atomic::partial_path(&system_tar);
+        if !self.fetch(&format!("{id}/system.tar"), &partial_system_tar)? {
+            fs::remove_file(&partial_system_tar).ok();
+            return Ok(false);
+        }
+        let (sha256, size) = sha256_file(&partial_system_tar)
+            .whatever("unable to compute checksum of cached layer")?;
+        if sha256 != manifest.sha256 || size != manifest.size {
+            fs::remove_file(&partial_system_tar).ok();
+            bail!("checksum mismatch of cached layer {id}");
+        }
+        atomic::finish(&partial_system_tar, &system_tar)
+            .whatever("unable to move layer tar file")?;
+        fs::write(
+            layer_dir.join("inputs.json"),
+            serde_json::to_string_pretty(&manifest.inputs).unwrap(),
+        )
+        .whatever("unable to write layer inputs")?;
+        match &manifest.provenance {
+            Some(provenance) => provenance.write(layer_dir)?,
+            None => {
+                fs::remove_file(layer_dir.join(PROVENANCE_FILE)).ok();
+            }
+        }
+        match &manifest.size_report {
+            Some(report) => report.write(layer_dir)?,
+            None => {
+                fs::remove_file(layer_dir.join(SIZE_REPORT_FILE)).ok();
+            }
+        }
+        atomic::mark_complete(layer_dir).whatever("unable to mark layer as complete")?;
+        Ok(true)
+    }
+
+    /// Push the layer with the given id from the given layer directory.
+    ///
+    /// Returns `false` if the cache already contains the layer.
+    pub fn push(&self, project: &ProjectRef, id: &str, layer_dir: &Path) -> BakeryResult<bool> {
+        if self.manifest(id)?.is_some() {
+            return Ok(false);
+        }
+        let inputs = serde_json::from_str(
+            &fs::read_to_string(layer_dir.join("inputs.json"))
+                .whatever("unable to read layer inputs")?,
+        )
+        .whatever("unable to parse layer inputs")?;
+        let system_tar = layer_dir.join("system.tar");
+        let (sha256, size) =
+            sha256_file(&system_tar).whatever("unable to compute checksum of layer")?;
+        let used_files = fs::read_to_string(layer_dir.join("rebuild-if-changed.txt")).ok();
+        let used_files_digest = match &used_files {
+            Some(_) => Some(digest_used_files(
+                project,
+                &layer_dir.join("rebuild-if-changed.txt"),
+            )?),
+            None => None,
+        };
+        let provenance_path = layer_dir.join(PROVENANCE_FILE);
+        let provenance = if provenance_path.exists() {
+            Some(load_json(&provenance_path)?)
+        } else {
+            None
+        };
+        let size_report = SizeReport::load(layer_dir)?;
+        let manifest = CacheManifest {
+            id: id.to_owned(),
+            inputs,
+            sha256,
+            size,
+            used_files,
+            used_files_digest,
+            provenance,
+            size_report,
+        };
+        info!("pushing layer {id} to cache");
+        self.store(&format!("{id}/system.tar"), &system_tar)?;
+        let manifest_file =
+            tempfile::NamedTempFile::new().whatever("unable to create temporary file")?;
+        fs::write(
+            manifest_file.path(),
+            serde_json::to_string_pretty(&manifest).unwrap(),
+        )
+        .whatever("unable to write manifest")?;
+        self.store(&format!("{id}/manifest.json"), manifest_file.path())?;
+        Ok(true)
+    }
+
+    /// Retrieve the manifest of the layer with the given id.
+    ///
+    /// Returns `None` if the cache does not have the layer or cannot be reached in time.
+    fn manifest(&self, id: &str) -> BakeryResult<Option<CacheManifest>> {
+        let name = format!("{id}/manifest.json");
+        let manifest = match self {
+            LayerCache::Dir(dir) => {
+                let path = dir.join(&name);
+                if !path.exists() {
+                    return Ok(None);
+                }
+                fs::read_to_string(path).whatever("unable to read manifest")?
+            }
+            LayerCache::Http { url, client } => {
+                let response = match client.get(entry_url(url, &name)?).send() {
+                    Ok(response) => response,
+                    Err(error) if is_unavailable(&error) => {
+                        warn!("layer cache is unavailable: {error}");
+                        return Ok(None);
+                    }
+                    Err(error) => return Err(error).whatever("unable to request manifest"),
+                };
+                if response.status() == reqwest::StatusCode::NOT_FOUND {
+                    return Ok(None);
+                }
+                response
+                    .error_for_status()
+                    .whatever("unable to request manifest")?
+                    .text()
+                    .whatever("unable to read manifest")?
+            }
+        };
+        serde_json::from_str(&manifest)
+            .whatever("unable to parse manifest")
+            .map(Some)
+    }
+
+    /// Fetch the file with the given name from the cache.
+    ///
+    /// Returns `false` if the cache cannot be reached in time.
+    fn fetch(&self, name: &str, dst: &Path) -> BakeryResult<bool> {
+        match self {
+            LayerCache::Dir(dir) => {
+                fs::copy(dir.join(name), dst).whatever("unable to copy file from cache")?;
+            }
+            LayerCache::Http { url, client } => {
+                let mut file = fs::File::create(dst).whatever("unable to create file")?;
+                let result = client
+                    .get(entry_url(url, name)?)
+                    .timeout(TRANSFER_TIMEOUT)
+                    .send()
+                    .and_then(|response| response.error_for_status())
+                    .and_then(|mut response| response.copy_to(&mut file));
+                match result {
+                    Ok(_) => {}
+                    Err(error) if is_unavailable(&error) => {
+                        warn!("layer cache is unavailable: {error}");
+                        return Ok(false);
+                    }
+                    Err(error) => return Err(error).whatever("unable to download file from cache"),
+                }
+            }
+        }
+        Ok(true)
+    }
+
+    /// Store the given file under the given name in the cache.
+    fn store(&self, name: &str, src: &Path) -> BakeryResult<()> {
+        match self {
+            LayerCache::Dir(dir) => {
+                let path = dir.join(name);
+                fs::create_dir_all(path.parent().unwrap())
+                    .whatever("unable to create cache directory")?;
+                let partial = atomic::partial_path(&path);
+                fs::copy(src, &partial).whatever("unable to copy file into cache")?;
+                atomic::finish(&partial, &path).whatever("unable to move file into cache")?;
+            }
+            LayerCache::Http { url, client } => {
+                let file = fs::File::open(src).whatever("unable to open file")?;
+                client
+                    .put(entry_url(url, name)?)
+                    .timeout(TRANSFER_TIMEOUT)
+                    .body(file)
+                    .send()
+                    .and_then(|response| response.error_for_status())
+                    .whatever("unable to upload file to cache")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Indicates whether the given error means that the cache cannot be reached in time.
+fn is_unavailable(error: &reqwest::Error) -> bool {
+    error.is_timeout() || error.is_connect()
+}
+
+/// URL of the entry with the given name.
+fn entry_url(base: &Url, name: &str) -> BakeryResult<Url> {
+    base.join(name).whatever("unable to construct cache URL")
+}
@@ -1,31 +1,45 @@
 //! Applies a set of recipes to a system.
 
-use std::collections::{HashMap, HashSet};
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet};
 use std::ffi::OsString;
 use std::fs;
 use std::io::{Read, Write};
 use std::ops::Deref;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
-use reportify::{bail, ResultExt};
+use reportify::{bail, whatever, ResultExt};
 use rugix_cli::{cli_msg, StatusSegmentRef};
 use rugix_common::mount::{MountStack, Mounted};
+use serde::{Deserialize, Serialize};
 use tempfile::tempdir;
 use tracing::{error, info};
-use xscript::{cmd, run, vars, Cmd, ParentEnv, Run};
+use xscript::{cmd, run, vars, Cmd, ParentEnv, Run, Vars};
 
 use crate::cli::status::CliLog;
 use crate::config::layers::LayerConfig;
-use crate::config::systems::Architecture;
-use crate::oven::layer::LayerContext;
+use crate::config::load_json;
+use crate::config::systems::{Architecture, Target};
+use crate::oven::accounts::create_accounts;
+use crate::oven::files::copy_files;
+use crate::oven::layer::{FrozenLayer, LayerContext};
+use crate::oven::packages::installed_packages;
+use crate::oven::size::{disk_usage, RecipeSize, SizeReport};
+use crate::oven::system::TargetSystem;
+use crate::oven::systemd::apply_units;
+use crate::oven::templates::TemplateRenderer;
+use crate::project::conditions::{Condition, ConditionContext};
 use crate::project::layers::Layer;
-use crate::project::library::Library;
-use crate::project::recipes::{PackageManager, Recipe, StepKind};
-use crate::project::repositories::RepositoryIdx;
-use crate::project::ProjectRef;
-use crate::utils::caching::{mtime, mtime_recursive};
+use crate::project::library::{Library, RecipeIdx};
+use crate::project::lockfile::{LayerLock, LockMode};
+use crate::project::recipes::{PackageManager, Recipe, RecipeStep, StepKind};
+use crate::project::repositories::{ProjectRepositories, RepositoryIdx};
+use crate::project::{parameters, ProjectRef};
+use crate::utils::atomic;
+use crate::utils::caching::{digest_path, touch, Hasher};
 use crate::BakeryResult;
 
 struct Logger {
@@ -80,55 +94,71 @@ pub fn customize(
     project: &ProjectRef,
     arch: Architecture,
     layer: &Layer,
-    src: Option<&Path>,
+    jobs: &[RecipeJob],
+    src: Option<&FrozenLayer>,
     target: &Path,
     layer_path: &Path,
     source_date_epoch: u64,
-) -> BakeryResult<()> {
-    let library = project.library()?;
-    // Collect the recipes to apply.
-    let config = layer.config(arch).unwrap();
-    let jobs = recipe_schedule(layer.repo, config, &library)?;
+    debug_on_failure: bool,
+) -> BakeryResult<Vec<Option<Duration>>> {
     if jobs.is_empty() {
         bail!("layer must have recipes")
     }
-    let mut last_modified = jobs
-        .iter()
-        .map(|job| job.recipe.modified)
-        .max()
-        .unwrap()
-        .max(layer.modified);
-    if let Some(src) = src {
-        last_modified = last_modified.max(mtime(src).whatever("unable to determine mtime")?);
-    }
-    let mut force_run = false;
     let used_files = project
         .dir()
         .join(layer_path.join("rebuild-if-changed.txt"));
-    if used_files.exists() {
-        for line in std::fs::read_to_string(used_files)
-            .whatever("unable to read used files")?
-            .lines()
-        {
-            if let Ok(modified) = mtime_recursive(&project.dir().join(line)) {
-                last_modified = last_modified.max(modified)
-            } else {
-                error!("error determining modification time for {line}");
-                force_run = true;
-            }
-        }
-    }
-    if target.exists()
-        && last_modified < mtime(target).whatever("unable to read `mtime` of target")?
-        && !force_run
-    {
-        return Ok(());
-    }
+    let used_files_digest = project
+        .dir()
+        .join(layer_path.join("rebuild-if-changed.digest"));
+    let target_dir = target.parent().unwrap();
+    atomic::unmark_complete(target_dir).whatever("unable to reset layer completion marker")?;
+    fs::remove_file(&used_files).ok();
+    fs::remove_file(&used_files_digest).ok();
+    let lock = match project.lock_mode() {
+        LockMode::Locked => project.lockfile().layer(&layer.name, arch).cloned(),
+        _ => None,
+    };
+    let checkpoints = checkpoint_ids(project, arch, src, lock.as_ref(), jobs)?;
+    let mut durations = vec![None; jobs.len()];
+    let mut size_deltas = vec![None; jobs.len()];
+    let resume = checkpoints
+        .iter()
+        .rposition(|id| is_up_to_date(project, &checkpoint_dir(project, id)));
     let bundle_dir = tempdir().whatever("unable to create temporary directory")?;
     let bundle_dir = bundle_dir.path();
-    if let Some(src) = src {
+    if let Some(position) = resume {
+        info!(
+            "Resuming from checkpoint after recipe `{}`.",
+            jobs[position].recipe.name
+        );
+        let checkpoint_dir = checkpoint_dir(project, &checkpoints[position]);
+        touch(&checkpoint_dir.join("bundle.tar"))
+            .whatever("unable to update last use of checkpoint")?;
+        run!([
+            "tar",
+            "-x",
+            "-f",
+            checkpoint_dir.join("bundle.tar"),
+            "-C",
+            bundle_dir
+        ])
+        .whatever("unable to extract checkpoint")?;
+        let checkpoint_used_files = checkpoint_dir.join("rebuild-if-changed.txt");
+        if checkpoint_used_files.exists() {
+            fs::copy(&checkpoint_used_files, &used_files)
+                .whatever("unable to restore used files")?;
+        }
+        // Checkpoints of earlier versions do not have statistics.
+        if let Ok(stats) = load_json::<Vec<RecipeStats>>(&checkpoint_dir.join(CHECKPOINT_STATS)) {
+            for (idx, stats) in stats.into_iter().enumerate().take(position + 1) {
+                durations[idx] = stats.duration;
+                size_deltas[idx] = stats.size_delta;
+            }
+        }
+    } else if let Some(src) = src {
         info!("Extracting layer.");
-        run!(["tar", "-x", "-f", &src, "-C", bundle_dir]).whatever("unable to extract layer")?;
+        run!(["tar", "-x", "-f", src.path(), "-C", bundle_dir])
+            .whatever("unable to extract layer")?;
     } else {
         info!("Creating empty layer.");
         std::fs::create_dir_all(&bundle_dir).whatever("unable ot create layer directory")?;
@@ -141,23 +171,69 @@ pub fn customize(
     let root_dir = bundle_dir.join("roots/system");
     std::fs::create_dir_all(&root_dir).ok();
     let logger = Logger::new(&layer.name, layer_path)?;
-    if let Err(error) = apply_recipes(
-        &layer_ctx,
-        &logger,
-        project,
-        arch,
-        &jobs,
-        &root_dir,
-        source_date_epoch,
-    ) {
-        let last_lines = logger.current_lines();
+    let start = resume.map(|position| position + 1).unwrap_or_default();
+    let mut root_size = disk_usage(&root_dir).whatever("unable to compute disk usage of root")?;
+    for (idx, job) in jobs.iter().enumerate().skip(start) {
+        let job_start = Instant::now();
+        let recipe = &job.recipe;
+        info!(
+            "[{:>2}/{}] {} {:?}",
+            idx + 1,
+            jobs.len(),
+            recipe
+                .config
+                .description
+                .as_deref()
+                .unwrap_or(recipe.name.deref()),
+            job.redacted_parameters(),
+        );
+        let mut current_step = None;
+        if let Err(error) = apply_recipe(
+            &layer_ctx,
+            &logger,
+            project,
+            arch,
+            job,
+            &root_dir,
+            lock.as_ref(),
+            source_date_epoch,
+            &mut current_step,
+        ) {
+            let last_lines = logger.current_lines();
 
-        cli_msg!("Log: {:?}", layer_path.join("build.log"));
-        for line in last_lines.lines() {
-            cli_msg!("> {line}")
-        }
+            cli_msg!("Log: {:?}", layer_path.join("build.log"));
+            for line in last_lines.lines() {
+                cli_msg!("> {line}")
+            }
+
+            if debug_on_failure {
+                debug_shell(
+                    &layer_ctx,
+                    project,
+                    arch,
+                    current_step.map(|idx| (job, &recipe.steps[idx])),
+                    &root_dir,
+                    source_date_epoch,
+                )?;
+            }
 
-        return Err(error);
+            return Err(error);
+        }
+        durations[idx] = Some(job_start.elapsed());
+        let size = disk_usage(&root_dir).whatever("unable to compute disk usage of root")?;
+        size_deltas[idx] = Some(size as i64 - root_size as i64);
+        root_size = size;
+        if let Some(id) = checkpoints.get(idx) {
+            let stats = durations[..=idx]
+                .iter()
+                .zip(&size_deltas)
+                .map(|(duration, size_delta)| RecipeStats {
+                    duration: *duration,
+                    size_delta: *size_delta,
+                })
+                .collect::<Vec<_>>();
+            save_checkpoint(project, id, bundle_dir, &used_files, &stats)?;
+        }
     }
 
     // Clean up /run and /tmp directories - we can't mount tmpfs in a user namespace,
@@ -178,7 +254,26 @@ pub fn customize(
         }
     }
 
+    // Attribute the size of the root to the recipes of this and all ancestor layers.
+    let mut recipe_sizes = match src {
+        Some(src) => SizeReport::load(src.path().parent().unwrap())?
+            .map(|report| report.recipes)
+            .unwrap_or_default(),
+        None => Vec::new(),
+    };
+    recipe_sizes.extend(
+        jobs.iter()
+            .zip(size_deltas)
+            .map(|(job, size_delta)| RecipeSize {
+                layer: layer.name.clone(),
+                recipe: job.recipe.name.to_string(),
+                size_delta,
+            }),
+    );
+    SizeReport::new(&layer.name, recipe_sizes, &root_dir)?.write(target_dir)?;
+
     info!("packing system files");
+    let partial_target = atomic::partial_path(target);
     run!([
         "tar",
         "--sort=name",
@@ -187,108 +282,543 @@ pub fn customize(
         format!("--mtime=@{source_date_epoch}"),
         "-c",
         "-f",
-        &target,
+        &partial_target,
         "-C",
         bundle_dir,
         "."
     ])
     .whatever("unable to package system files")?;
+    atomic::finish(&partial_target, target).whatever("unable to move layer tar file")?;
+    let digest = digest_used_files(project, &used_files)?;
+    fs::write(&used_files_digest, digest).whatever("unable to write digest of used files")?;
+    Ok(durations)
+}
+
+/// Compute a digest of the files listed in the given `rebuild-if-changed.txt` file.
+pub fn digest_used_files(project: &ProjectRef, used_files: &Path) -> BakeryResult<String> {
+    let mut hasher = Hasher::new();
+    if used_files.exists() {
+        let used_files = fs::read_to_string(used_files).whatever("unable to read used files")?;
+        let mut lines = used_files.lines().collect::<Vec<_>>();
+        lines.sort();
+        lines.dedup();
+        for line in lines {
+            let digest = digest_path(&project.dir().join(line))
+                .whatever_with(|_| format!("unable to compute digest of `{line}`"))?;
+            hasher.push("path", line);
+            hasher.push("digest", digest);
+        }
+    }
+    Ok(hasher.finalize())
+}
+
+/// Compute the ids of the checkpoints taken after each job except for the last one.
+///
+/// The id of a checkpoint covers the base layer and all jobs applied so far. Hence,
+/// changing a job only invalidates the checkpoints of the job and subsequent jobs.
+fn checkpoint_ids(
+    project: &ProjectRef,
+    arch: Architecture,
+    src: Option<&FrozenLayer>,
+    lock: Option<&LayerLock>,
+    jobs: &[RecipeJob],
+) -> BakeryResult<Vec<String>> {
+    let repositories = project.repositories()?;
+    let mut hasher = Hasher::new();
+    hasher.push("bakery", rugix_version::RUGIX_GIT_VERSION);
+    hasher.push("arch", arch.as_str());
+    hasher.push("base", src.map(FrozenLayer::id).unwrap_or_default());
+    // Packages are pinned to the locked versions, hence, the lock is an input.
+    if let Some(lock) = lock {
+        hasher.push("lock", lock.digest());
+    }
+    let mut previous = hasher.finalize();
+    let mut ids = Vec::new();
+    for job in jobs.iter().take(jobs.len().saturating_sub(1)) {
+        let mut hasher = Hasher::new();
+        hasher.push("previous", &previous);
+        job.push_digest(&mut hasher, &repositories);
+        previous = hasher.finalize();
+        ids.push(previous.clone());
+    }
+    Ok(ids)
+}
+
+/// Directory of the checkpoint with the given id.
+fn checkpoint_dir(project: &ProjectRef, id: &str) -> PathBuf {
+    project.dir().join(project.checkpoints_dir()).join(id)
+}
+
+/// Check whether the output in the given directory is complete and up-to-date.
+///
+/// The path of an output is derived from all inputs known upfront. In addition,
+/// recipes may declare files which they use via `rebuild-if-changed.txt`.
+pub fn is_up_to_date(project: &ProjectRef, dir: &Path) -> bool {
+    if !atomic::is_complete(dir) {
+        return false;
+    }
+    let used_files = dir.join("rebuild-if-changed.txt");
+    if !used_files.exists() {
+        return true;
+    }
+    let stored_digest = fs::read_to_string(dir.join("rebuild-if-changed.digest")).ok();
+    match digest_used_files(project, &used_files) {
+        Ok(digest) if stored_digest.as_deref() == Some(digest.as_str()) => true,
+        Ok(_) => {
+            info!("files used by {dir:?} have changed");
+            false
+        }
+        Err(error) => {
+            error!("error computing digest of used files: {error:?}");
+            false
+        }
+    }
+}
+
+/// Snapshot the bundle directory into the checkpoint with the given id.
+///
+/// The statistics of the recipes applied so far are stored alongside such that they
+/// can be restored when resuming from the checkpoint. Must only be called when nothing
+/// is mounted in the bundle directory.
+fn save_checkpoint(
+    project: &ProjectRef,
+    id: &str,
+    bundle_dir: &Path,
+    used_files: &Path,
+    stats: &[RecipeStats],
+) -> BakeryResult<()> {
+    let checkpoint_dir = checkpoint_dir(project, id);
+    fs::create_dir_all(&checkpoint_dir).whatever("unable to create checkpoint directory")?;
+    atomic::unmark_complete(&checkpoint_dir)
+        .whatever("unable to reset checkpoint completion marker")?;
+    let bundle_tar = checkpoint_dir.join("bundle.tar");
+    let partial_bundle_tar = atomic::partial_path(&bundle_tar);
+    run!([
+        "tar",
+        "-c",
+        "-f",
+        &partial_bundle_tar,
+        "-C",
+        bundle_dir,
+        "."
+    ])
+    .whatever("unable to create checkpoint")?;
+    atomic::finish(&partial_bundle_tar, &bundle_tar).whatever("unable to move checkpoint")?;
+    let checkpoint_used_files = checkpoint_dir.join("rebuild-if-changed.txt");
+    if used_files.exists() {
+        fs::copy(used_files, &checkpoint_used_files).whatever("unable to copy used files")?;
+    } else {
+        fs::remove_file(&checkpoint_used_files).ok();
+    }
+    let digest = digest_used_files(project, &checkpoint_used_files)?;
+    fs::write(checkpoint_dir.join("rebuild-if-changed.digest"), digest)
+        .whatever("unable to write digest of used files")?;
+    fs::write(
+        checkpoint_dir.join(CHECKPOINT_STATS),
+        serde_json::to_string(stats).whatever("unable to serialize recipe statistics")?,
+    )
+    .whatever("unable to write recipe statistics")?;
+    atomic::mark_complete(&checkpoint_dir).whatever("unable to mark checkpoint as complete")?;
     Ok(())
 }
 
-struct RecipeJob {
-    recipe: Arc<Recipe>,
-    parameters: HashMap<String, String>,
+/// Name of the file with the statistics of the recipes applied before a checkpoint.
+const CHECKPOINT_STATS: &str = "recipes.json";
+
+/// Statistics of an applied recipe.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct RecipeStats {
+    /// Time it took to apply the recipe.
+    duration: Option<Duration>,
+    /// Change of the root's size caused by the recipe in bytes.
+    size_delta: Option<i64>,
 }
 
-fn recipe_schedule(
-    repo: RepositoryIdx,
-    layer: &LayerConfig,
-    library: &Library,
-) -> BakeryResult<Vec<RecipeJob>> {
-    let mut stack = layer
-        .recipes
-        .as_deref()
-        .unwrap_or_default()
-        .iter()
-        .map(|name| library.try_lookup(repo, name))
-        .collect::<BakeryResult<Vec<_>>>()?;
-    let mut enabled = stack.iter().cloned().collect::<HashSet<_>>();
-    while let Some(idx) = stack.pop() {
-        let recipe = &library.recipes[idx];
-        for name in recipe.config.dependencies.as_deref().unwrap_or_default() {
-            let dependency = library.try_lookup(recipe.repository, name)?;
-            if enabled.insert(dependency) {
-                stack.push(dependency);
+pub struct RecipeJob {
+    pub recipe: Arc<Recipe>,
+    pub parameters: HashMap<String, String>,
+    /// Steps which are skipped because of their condition.
+    pub skipped_steps: Vec<String>,
+    /// System for which the recipe's templates are rendered.
+    pub system: Option<TargetSystem>,
+}
+
+impl RecipeJob {
+    /// Push the inputs of the job into the hasher.
+    pub fn push_digest(&self, hasher: &mut Hasher, repositories: &ProjectRepositories) {
+        hasher.push("recipe", self.recipe.name.as_bytes());
+        repositories[self.recipe.repository]
+            .source
+            .push_digest(hasher);
+        hasher.push("recipe-digest", &self.recipe.digest);
+        for step in &self.skipped_steps {
+            hasher.push("skipped-step", step);
+        }
+        if let Some(system) = &self.system {
+            hasher.push("system", &system.name);
+            hasher.push(
+                "system-id",
+                system.release.system_id.as_deref().unwrap_or_default(),
+            );
+            hasher.push(
+                "system-version",
+                system.release.system_version.as_deref().unwrap_or_default(),
+            );
+        }
+        let mut parameters = self.parameters.iter().collect::<Vec<_>>();
+        parameters.sort();
+        for (name, value) in parameters {
+            hasher.push("parameter", name);
+            if self.is_secret(name) {
+                // Hashers record their inputs and even an unsalted digest of a secret may
+                // be reversed, hence, changing a secret does not invalidate any outputs.
+                hasher.push("value", parameters::REDACTED);
+            } else {
+                hasher.push("value", value);
             }
         }
     }
-    for excluded in layer.exclude.as_deref().unwrap_or_default() {
-        let excluded = library.try_lookup(repo, excluded.deref())?;
-        enabled.remove(&excluded);
+
+    /// Indicates whether the parameter with the given name is secret.
+    pub fn is_secret(&self, name: &str) -> bool {
+        parameters::is_secret(self.recipe.config.parameters.as_ref(), name)
     }
-    let parameters = layer
+
+    /// Parameters of the job with the values of secret parameters redacted.
+    pub fn redacted_parameters(&self) -> BTreeMap<String, String> {
+        self.parameters
+            .iter()
+            .map(|(name, value)| {
+                let value = if self.is_secret(name) {
+                    parameters::REDACTED.to_owned()
+                } else {
+                    value.clone()
+                };
+                (name.clone(), value)
+            })
+            .collect()
+    }
+}
+
+pub fn recipe_schedule(
+    repo: RepositoryIdx,
+    layer: &LayerConfig,
+    library: &Library,
+    arch: Architecture,
+    target: Option<&Target>,
+) -> BakeryResult<Vec<RecipeJob>> {
+    let values = layer
         .parameters
         .as_ref()
         .map(|parameters| {
             parameters
                 .iter()
-                .map(|(name, parameters)| {
-                    let recipe = library.try_lookup(repo, name.deref())?;
-                    if !enabled.contains(&recipe) {
-                        bail!("recipe with name {name} is not part of the layer");
-                    }
-                    Ok((recipe, parameters))
-                })
+                .map(|(name, parameters)| Ok((library.try_lookup(repo, name.deref())?, parameters)))
                 .collect::<BakeryResult<HashMap<_, _>>>()
         })
         .transpose()?
         .unwrap_or_default();
-    let mut recipes = enabled
-        .into_iter()
-        .map(|idx| {
-            let recipe = library.recipes[idx].clone();
-            let recipe_params = parameters.get(&idx);
-            if let Some(params) = recipe_params {
-                for param_name in params.keys() {
-                    if !recipe
-                        .config
-                        .parameters
-                        .as_ref()
-                        .map(|parameters| parameters.contains_key(param_name))
-                        .unwrap_or_default()
-                    {
-                        bail!(
-                            "unknown parameter `{param_name}` of recipe `{}`",
-                            recipe.name
-                        );
+    let mut stack = Vec::new();
+    // Recipes of the layer which are not included because of their condition.
+    let mut disabled = HashSet::new();
+    for entry in layer.recipes.as_deref().unwrap_or_default() {
+        let idx = library.try_lookup(repo, entry.name())?;
+        if let Some(when) = entry.when() {
+            let recipe = &library.recipes[idx];
+            let parameters = parameters::resolve(
+                recipe.config.parameters.as_ref(),
+                values.get(&idx).copied(),
+            )
+            .with_info(|_| format!("while resolving parameters of recipe `{}`", recipe.name))?;
+            let ctx = ConditionContext {
+                arch,
+                target,
+                definitions: recipe.config.parameters.as_ref(),
+                parameters: Some(&parameters),
+            };
+            let included = Condition::parse(when)
+                .and_then(|condition| condition.evaluate(&ctx))
+                .with_info(|_| format!("while evaluating condition of recipe `{}`", recipe.name))?;
+            if !included {
+                disabled.insert(idx);
+                continue;
+            }
+        }
+        stack.push(idx);
+    }
+    let mut enabled = stack.iter().cloned().collect::<HashSet<_>>();
+    // Recipes which have been enabled by a dependency and the recipe depending on them.
+    let mut required_by = HashMap::new();
+    // Virtual names are resolved after all other dependencies such that providers which
+    // are enabled anyway are preferred over selecting another provider.
+    let mut virtual_names = BTreeSet::new();
+    loop {
+        while let Some(idx) = stack.pop() {
+            let recipe = &library.recipes[idx];
+            for name in recipe.config.dependencies.as_deref().unwrap_or_default() {
+                let Some(dependency) = library.lookup(recipe.repository, name) else {
+                    if library.providers(name).is_empty() {
+                        bail!("unable to find recipe {name}");
                     }
+                    virtual_names.insert(name.clone());
+                    continue;
+                };
+                if enabled.insert(dependency) {
+                    required_by.insert(dependency, idx);
+                    stack.push(dependency);
                 }
             }
-            let mut parameters = HashMap::new();
-            if let Some(p) = &recipe.config.parameters {
-                for (name, def) in p {
-                    if let Some(params) = recipe_params {
-                        if let Some(value) = params.get(name) {
-                            parameters.insert(name.to_owned(), value.to_string());
-                            continue;
-                        }
-                    }
-                    if let Some(default) = &def.default {
-                        parameters.insert(name.to_owned(), default.to_string());
-                        continue;
+        }
+        let Some(name) = virtual_names.pop_first() else {
+            break;
+        };
+        let providers = library.providers(&name);
+        if !providers.iter().any(|provider| enabled.contains(provider)) {
+            // Prefer providers which are not excluded by their condition.
+            let provider = providers
+                .iter()
+                .copied()
+                .find(|provider| !disabled.contains(provider))
+                .unwrap_or(providers[0]);
+            info!(
+                "selecting recipe `{}` to provide `{name}`",
+                library.recipes[provider].name
+            );
+            enabled.insert(provider);
+            stack.push(provider);
+        }
+    }
+    for excluded in layer.exclude.as_deref().unwrap_or_default() {
+        let excluded = library.try_lookup(repo, excluded.deref())?;
+        enabled.remove(&excluded);
+    }
+    for idx in &disabled {
+        if enabled.contains(idx) {
+            let recipe = &library.recipes[*idx].name;
+            match required_by.get(idx) {
+                Some(dependent) => bail!(
+                    "recipe `{recipe}` is excluded by its condition but required by recipe `{}`",
+                    library.recipes[*dependent].name
+                ),
+                None => bail!(
+                    "recipe `{recipe}` is excluded by its condition but selected as a provider"
+                ),
+            }
+        }
+    }
+    check_conflicts(library, &enabled)?;
+    for idx in values.keys() {
+        if !enabled.contains(idx) && !disabled.contains(idx) {
+            bail!(
+                "recipe with name {} is not part of the layer",
+                library.recipes[*idx].name
+            );
+        }
+    }
+    let jobs = enabled
+        .into_iter()
+        .map(|idx| {
+            let recipe = library.recipes[idx].clone();
+            let parameters = parameters::resolve(
+                recipe.config.parameters.as_ref(),
+                values.get(&idx).copied(),
+            )
+            .with_info(|_| format!("while resolving parameters of recipe `{}`", recipe.name))?;
+            let ctx = ConditionContext {
+                arch,
+                target,
+                definitions: recipe.config.parameters.as_ref(),
+                parameters: Some(&parameters),
+            };
+            let mut skipped_steps = Vec::new();
+            for step in &recipe.steps {
+                if let Some(when) = &step.when {
+                    let run = when.evaluate(&ctx).with_info(|_| {
+                        format!(
+                            "while evaluating condition of step `{}` of recipe `{}`",
+                            step.filename, recipe.name
+                        )
+                    })?;
+                    if !run {
+                        skipped_steps.push(step.filename.clone());
                     }
-                    bail!("unable to find value for parameter `{name}`");
                 }
             }
-            Ok(RecipeJob { recipe, parameters })
+            Ok((
+                idx,
+                RecipeJob {
+                    recipe,
+                    parameters,
+                    skipped_steps,
+                    system: None,
+                },
+            ))
         })
-        .collect::<Result<Vec<_>, _>>()?;
-    recipes.sort_by_key(|job| -job.recipe.config.priority.unwrap_or_default());
-    Ok(recipes)
+        .collect::<BakeryResult<Vec<_>>>()?;
+    order_jobs(library, jobs)
 }
 
-fn run_cmd(logger: &Logger, cmd: Cmd<OsString>) -> BakeryResult<()> {
+/// Check that none of the enabled recipes conflict with each other.
+fn check_conflicts(library: &Library, enabled: &HashSet<RecipeIdx>) -> BakeryResult<()> {
+    let mut recipes = enabled.iter().copied().collect::<Vec<_>>();
+    recipes.sort_by_key(|idx| library.recipes[*idx].name.to_string());
+    for idx in recipes {
+        let recipe = &library.recipes[idx];
+        for name in recipe.config.conflicts.iter().flatten() {
+            let conflicting = library
+                .lookup(recipe.repository, name)
+                .into_iter()
+                .chain(library.providers(name).iter().copied())
+                .find(|other| *other != idx && enabled.contains(other));
+            let Some(other) = conflicting else {
+                continue;
+            };
+            let other = &library.recipes[other].name;
+            if other.deref() == name {
+                bail!("recipe `{}` conflicts with recipe `{other}`", recipe.name);
+            }
+            bail!(
+                "recipe `{}` conflicts with recipe `{other}`, which provides `{name}`",
+                recipe.name
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Order the given jobs such that recipes run after their dependencies.
+///
+/// Recipes may additionally declare to run `after` or `before` other recipes. Among
+/// the recipes whose constraints are satisfied, the priority and then the name of the
+/// recipes determine the order.
+fn order_jobs(
+    library: &Library,
+    jobs: Vec<(RecipeIdx, RecipeJob)>,
+) -> BakeryResult<Vec<RecipeJob>> {
+    let positions = jobs
+        .iter()
+        .enumerate()
+        .map(|(position, (idx, _))| (*idx, position))
+        .collect::<HashMap<_, _>>();
+    // For each job, the jobs which must run after it.
+    let mut successors = vec![Vec::new(); jobs.len()];
+    for (position, (_, job)) in jobs.iter().enumerate() {
+        let recipe = &job.recipe;
+        let lookup = |name: &String| {
+            library.lookup_all(recipe.repository, name).map(|others| {
+                others
+                    .into_iter()
+                    .filter_map(|idx| positions.get(&idx).copied())
+                    .collect::<Vec<_>>()
+            })
+        };
+        let config = &recipe.config;
+        for name in config
+            .dependencies
+            .iter()
+            .chain(config.after.iter())
+            .flatten()
+        {
+            for other in lookup(name)? {
+                successors[other].push(position);
+            }
+        }
+        for name in config.before.iter().flatten() {
+            for other in lookup(name)? {
+                successors[position].push(other);
+            }
+        }
+    }
+    let order = order_positions(&successors, |position| {
+        let recipe = &jobs[position].1.recipe;
+        (
+            recipe.config.priority.unwrap_or_default(),
+            Reverse(recipe.name.to_string()),
+        )
+    })
+    .map_err(|cycle| {
+        let cycle = cycle
+            .into_iter()
+            .map(|position| jobs[position].1.recipe.name.to_string())
+            .collect::<Vec<_>>();
+        whatever!("recipes must run in a cycle: {}", cycle.join(" -> "))
+    })?;
+    let mut jobs = jobs
+        .into_iter()
+        .map(|(_, job)| Some(job))
+        .collect::<Vec<_>>();
+    Ok(order
+        .into_iter()
+        .map(|position| jobs[position].take().unwrap())
+        .collect())
+}
+
+/// Order the jobs such that each job runs before its successors.
+///
+/// Among the jobs which are ready, those with the greatest key run first. Returns a
+/// cycle if there is no such order.
+fn order_positions<K: Ord>(
+    successors: &[Vec<usize>],
+    key: impl Fn(usize) -> K,
+) -> Result<Vec<usize>, Vec<usize>> {
+    let mut in_degree = vec![0; successors.len()];
+    for successor in successors.iter().flatten() {
+        in_degree[*successor] += 1;
+    }
+    let entry = |position: usize| (key(position), Reverse(position));
+    let mut ready = (0..successors.len())
+        .filter(|position| in_degree[*position] == 0)
+        .map(entry)
+        .collect::<BinaryHeap<_>>();
+    let mut order = Vec::with_capacity(successors.len());
+    while let Some((_, Reverse(position))) = ready.pop() {
+        order.push(position);
+        for successor in &successors[position] {
+            in_degree[*successor] -= 1;
+            if in_degree[*successor] == 0 {
+                ready.push(entry(*successor));
+            }
+        }
+    }
+    if order.len() < successors.len() {
+        return Err(find_cycle(successors, &in_degree));
+    }
+    Ok(order)
+}
+
+/// Find a cycle among the jobs which could not be ordered.
+///
+/// Each job of the returned cycle must run before the next one.
+fn find_cycle(successors: &[Vec<usize>], in_degree: &[usize]) -> Vec<usize> {
+    let mut predecessors = vec![Vec::new(); successors.len()];
+    for (position, successors) in successors.iter().enumerate() {
+        for successor in successors {
+            predecessors[*successor].push(position);
+        }
+    }
+    // Jobs which could not be ordered still have predecessors which could not be
+    // ordered. Following them backwards eventually closes a cycle.
+    let mut path = Vec::new();
+    let mut current = (0..in_degree.len())
+        .find(|position| in_degree[*position] > 0)
+        .unwrap();
+    loop {
+        if let Some(start) = path.iter().position(|position| *position == current) {
+            let mut cycle = path.split_off(start);
+            cycle.reverse();
+            cycle.push(cycle[0]);
+            return cycle;
+        }
+        path.push(current);
+        current = *predecessors[current]
+            .iter()
+            .find(|position| in_degree[**position] > 0)
+            .unwrap();
+    }
+}
+
+/// Convert the given command into a standard library command.
+fn std_command(cmd: &Cmd<OsString>) -> Command {
     let mut command = Command::new(cmd.prog());
     command.args(cmd.args());
     if let Some(vars) = cmd.vars() {
@@ -303,6 +833,11 @@ fn run_cmd(logger: &Logger, cmd: Cmd<OsString>) -> BakeryResult<()> {
             }
         }
     }
+    command
+}
+
+fn run_cmd(logger: &Logger, cmd: Cmd<OsString>) -> BakeryResult<()> {
+    let mut command = std_command(&cmd);
     command
         .stdin(Stdio::null())
         .stdout(Stdio::piped())
@@ -336,173 +871,568 @@ fn run_cmd(logger: &Logger, cmd: Cmd<OsString>) -> BakeryResult<()> {
     Ok(())
 }
 
-fn apply_recipes(
+/// Apply a single recipe to the given root directory.
+///
+/// All mounts are undone when this function returns.
+///
+/// The index of the step being applied is stored in `current_step` such that the
+/// failing step is known in case of an error. While users, groups, and systemd units
+/// are applied, it is `None`.
+fn apply_recipe(
     layer_ctx: &LayerContext,
     logger: &Logger,
     project: &ProjectRef,
     arch: Architecture,
-    jobs: &[RecipeJob],
+    job: &RecipeJob,
     root_dir_path: &Path,
+    lock: Option<&LayerLock>,
     source_date_epoch: u64,
+    current_step: &mut Option<usize>,
 ) -> BakeryResult<()> {
     let mut mount_stack = MountStack::new();
 
-    fn mount_all(
-        project: &ProjectRef,
-        root_dir_path: &Path,
-        stack: &mut MountStack,
-    ) -> BakeryResult<()> {
-        stack.push(
-            Mounted::bind_recursive("/dev", root_dir_path.join("dev"))
-                .whatever("unable to mount /dev")?,
-        );
-        stack.push(
-            Mounted::bind_recursive("/sys", root_dir_path.join("sys"))
-                .whatever("unable to mount /sys")?,
-        );
-        stack.push(
-            Mounted::bind_recursive("/proc", root_dir_path.join("proc"))
-                .whatever("unable to mount /proc")?,
-        );
+    let project_dir = root_dir_path.join("run/rugix/bakery/project");
 
-        let project_dir = root_dir_path.join("run/rugix/bakery/project");
-        fs::create_dir_all(&project_dir).whatever("unable to create project directory")?;
+    let recipe = &job.recipe;
+    if recipe.config.groups.is_some() || recipe.config.users.is_some() {
+        info!("    - (accounts)");
+        create_accounts(
+            root_dir_path,
+            recipe.config.groups.as_deref().unwrap_or_default(),
+            recipe.config.users.as_deref().unwrap_or_default(),
+            source_date_epoch,
+        )
+        .with_info(|_| "creating users and groups")?;
+    }
+    for (idx, step) in recipe.steps.iter().enumerate() {
+        *current_step = Some(idx);
+        if job.skipped_steps.contains(&step.filename) {
+            info!("    - {} (skipped)", step.filename);
+            continue;
+        }
+        info!("    - {}", step.filename);
+        match &step.kind {
+            StepKind::Packages { packages, manager } => {
+                if mount_stack.is_empty() {
+                    mount_all(project, root_dir_path, &mut mount_stack)?;
+                }
+                let chroot_manager = if root_dir_path.join("usr/bin/apt-get").exists() {
+                    PackageManager::Apt
+                } else if root_dir_path.join("sbin/apk").exists() {
+                    PackageManager::Apk
+                } else if root_dir_path.join("usr/bin/dnf").exists() {
+                    PackageManager::Dnf
+                } else if root_dir_path.join("usr/bin/pacman").exists() {
+                    PackageManager::Pacman
+                } else if root_dir_path.join("bin/opkg").exists()
+                    || root_dir_path.join("usr/bin/opkg").exists()
+                {
+                    PackageManager::Opkg
+                } else {
+                    bail!("unable to determine package manager")
+                };
+                let manager = manager.unwrap_or(chroot_manager);
+                if manager == chroot_manager {
+                    let specs = match lock {
+                        Some(lock) => pin_packages(root_dir_path, packages, manager, lock)?,
+                        None => packages.clone(),
+                    };
+                    let mut cmd = match manager {
+                        PackageManager::Apt => {
+                            cmd!("chroot", root_dir_path, "apt-get", "install", "-y")
+                        }
+                        PackageManager::Apk => {
+                            cmd!("chroot", root_dir_path, "apk", "add", "--no-interactive")
+                        }
+                        // Contrary to apt and apk, whose package lists are updated by the
+                        // `pkg-update` recipe, the other package managers sync their
+                        // databases before installing packages.
+                        PackageManager::Dnf => cmd!(
+                            "chroot",
+                            root_dir_path,
+                            "dnf",
+                            "install",
+                            "-y",
+                            "--refresh",
+                            "--setopt=cachedir=/var/cache/dnf"
+                        ),
+                        PackageManager::Pacman => cmd!(
+                            "chroot",
+                            root_dir_path,
+                            "pacman",
+                            "-Sy",
+                            "--noconfirm",
+                            "--noprogressbar",
+                            "--needed",
+                            "--cachedir",
+                            "/var/cache/pacman/pkg"
+                        ),
+                        PackageManager::Opkg => {
+                            ParentEnv
+                                .run(cmd!("chroot", root_dir_path, "opkg", "update"))
+                                .whatever("unable to update package lists")?;
+                            cmd!("chroot", root_dir_path, "opkg", "install")
+                        }
+                    };
+                    cmd.extend_args(&specs);
+                    let apt_pins = root_dir_path.join(APT_PINS);
+                    let apt_lock = lock.filter(|_| manager == PackageManager::Apt);
+                    if let Some(lock) = apt_lock {
+                        write_apt_pins(&apt_pins, lock)?;
+                    }
+                    let result = ParentEnv
+                        .run(cmd.with_vars(vars! {
+                            DEBIAN_FRONTEND = "noninteractive",
+                            SOURCE_DATE_EPOCH = source_date_epoch.to_string(),
+                        }))
+                        .whatever("unable to install packages");
+                    if apt_lock.is_some() {
+                        fs::remove_file(&apt_pins).whatever("unable to remove apt pins")?;
+                    }
+                    result?;
+                    if manager == PackageManager::Apk {
+                        // Dependencies pinned explicitly must not end up in the world file.
+                        unmark_apk_packages(root_dir_path, &specs[packages.len()..])?;
+                    }
+                }
+            }
+            StepKind::Files { files } => {
+                let templates = TemplateRenderer::new(&job.parameters, arch, job.system.as_ref());
+                copy_files(root_dir_path, files, &templates)?;
+            }
+            StepKind::Install => {
+                if mount_stack.is_empty() {
+                    mount_all(project, root_dir_path, &mut mount_stack)?;
+                }
+                let _mounted = mount_install(layer_ctx, Some(recipe.as_ref()), root_dir_path)?;
+                let script = format!("/run/rugix/bakery/recipe/steps/{}", step.filename);
+                let vars = install_vars(
+                    layer_ctx,
+                    project,
+                    arch,
+                    Some((job, &script)),
+                    source_date_epoch,
+                );
+                run_cmd(
+                    logger,
+                    Cmd::new("chroot")
+                        .add_arg(root_dir_path)
+                        .add_arg(&script)
+                        .clone()
+                        .with_vars(vars),
+                )?;
+            }
+            StepKind::Run => {
+                let script = recipe.path.join("steps").join(&step.filename);
+                let mut vars = vars! {
+                    DEBIAN_FRONTEND = "noninteractive",
+                    RUGIX_LAYER_DIR = &layer_ctx.build_dir,
+                    RUGIX_ARTIFACTS_DIR = layer_ctx.build_dir.join("artifacts"),
+                    RUGIX_ROOT_DIR = root_dir_path,
+                    RUGIX_CONTEXT_DIR = "/run/rugix/bakery/context",
+                    RUGIX_CACHE_DIR = Path::new("/run/rugix/bakery/cache").join(project.local_id().as_str()),
+                    RUGIX_PROJECT_DIR = &project_dir,
+                    RUGIX_ARCH = arch.as_str(),
+                    LAYER_REBUILD_IF_CHANGED = project_dir.join(&layer_ctx.output_dir).join("rebuild-if-changed.txt"),
+                    RECIPE_DIR = &recipe.path,
+                    RECIPE_STEP_PATH = &script,
+                    SOURCE_DATE_EPOCH = source_date_epoch.to_string(),
+                };
+                for (name, value) in &job.parameters {
+                    vars.set(format!("RECIPE_PARAM_{}", name.to_uppercase()), value);
+                }
+                run_cmd(logger, Cmd::new(&script).with_vars(vars))?;
+            }
+        }
+    }
 
-        let resolved_resolv = root_dir_path.join("run/systemd/resolve/stub-resolv.conf");
-        fs::create_dir_all(resolved_resolv.parent().unwrap())
-            .whatever("unable to create `systemd/resolve` directory")?;
-        let resolv_conf =
-            fs::read("/etc/resolv.conf").whatever("unable to read `/etc/resolv.conf")?;
-        fs::write(resolved_resolv, resolv_conf).whatever("unable to write `resolv.conf`")?;
+    if let Some(systemd) = &recipe.config.systemd {
+        *current_step = None;
+        info!("    - (systemd)");
+        apply_units(root_dir_path, systemd)?;
+    }
 
-        stack.push(
-            Mounted::bind(project.dir(), &project_dir)
-                .whatever("unable to bind mount project directory")?,
-        );
+    Ok(())
+}
 
-        Ok(())
+/// Path of the apt preferences pinning the locked packages relative to the root.
+const APT_PINS: &str = "etc/apt/preferences.d/rugix-bakery-lock";
+
+/// Package specifications installing the given packages with their locked versions.
+///
+/// For apt, all locked packages, including dependencies, are pinned with preferences
+/// written by [`write_apt_pins`]. As apk and dnf lack such a mechanism, the installation
+/// is simulated and all locked packages it would install are added to the returned
+/// specifications. Pacman and opkg cannot install specific versions, hence, layers
+/// using them cannot be baked with a lock.
+fn pin_packages(
+    root_dir_path: &Path,
+    packages: &[String],
+    manager: PackageManager,
+    lock: &LayerLock,
+) -> BakeryResult<Vec<String>> {
+    let pin = |package: &str| match lock.package(package, manager) {
+        Some(locked) if manager == PackageManager::Dnf => {
+            format!("{package}-{}", locked.version)
+        }
+        Some(locked) => format!("{package}={}", locked.version),
+        None => package.to_owned(),
+    };
+    let mut specs = packages
+        .iter()
+        .map(|package| pin(package))
+        .collect::<Vec<_>>();
+    match manager {
+        // Apt packages are pinned with preferences.
+        PackageManager::Apt => {}
+        PackageManager::Apk | PackageManager::Dnf => {
+            let installed = installed_packages(root_dir_path)?
+                .into_iter()
+                .filter(|package| package.manager == manager)
+                .map(|package| package.name)
+                .collect::<HashSet<_>>();
+            for name in simulate_install(root_dir_path, packages, manager)? {
+                if !packages.contains(&name)
+                    && !installed.contains(&name)
+                    && lock.package(&name, manager).is_some()
+                {
+                    specs.push(pin(&name));
+                }
+            }
+        }
+        PackageManager::Pacman | PackageManager::Opkg => {
+            bail!(
+                "package manager `{}` cannot pin package versions, which `--locked` requires",
+                manager.as_str()
+            );
+        }
     }
+    Ok(specs)
+}
+
+/// Names of the packages which installing the given packages would install.
+fn simulate_install(
+    root_dir_path: &Path,
+    packages: &[String],
+    manager: PackageManager,
+) -> BakeryResult<Vec<String>> {
+    let mut command = Command::new("chroot");
+    command.arg(root_dir_path);
+    match manager {
+        PackageManager::Apk => command.args(["apk", "add", "--simulate", "--no-interactive"]),
+        PackageManager::Dnf => command.args(["dnf", "install", "--assumeno"]),
+        _ => unreachable!("simulation is only supported for apk and dnf"),
+    };
+    let output = command
+        .args(packages)
+        .stderr(Stdio::inherit())
+        .output()
+        .whatever("unable to simulate package installation")?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut names = Vec::new();
+    if manager == PackageManager::Apk {
+        if !output.status.success() {
+            bail!("unable to simulate package installation");
+        }
+        // Each package is listed as `(1/3) Installing musl (1.2.5-r0)`.
+        for line in stdout.lines() {
+            if let Some((name, _)) = line
+                .split_once(" Installing ")
+                .and_then(|(_, package)| package.split_once(" ("))
+            {
+                names.push(name.to_owned());
+            }
+        }
+    } else {
+        // With `--assumeno`, dnf always fails after printing the transaction as a table
+        // with sections like `Installing dependencies:` listing one package per line.
+        let mut installing = false;
+        for line in stdout.lines() {
+            if !line.starts_with(' ') {
+                installing = line.starts_with("Installing");
+            } else if installing {
+                names.extend(line.split_whitespace().next().map(str::to_owned));
+            }
+        }
+    }
+    Ok(names)
+}
+
+/// Write apt preferences pinning all locked apt packages to their versions.
+fn write_apt_pins(path: &Path, lock: &LayerLock) -> BakeryResult<()> {
+    let mut pins = String::new();
+    let mut pinned = HashSet::new();
+    for package in &lock.packages {
+        if package.manager == PackageManager::Apt
+            && pinned.insert((&package.name, &package.version))
+        {
+            pins.push_str(&format!(
+                "Package: {}\nPin: version {}\nPin-Priority: 1001\n\n",
+                package.name, package.version
+            ));
+        }
+    }
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).whatever("unable to create apt preferences directory")?;
+    }
+    fs::write(path, pins).whatever("unable to write apt pins")
+}
+
+/// Remove the given package specifications from apk's world file.
+fn unmark_apk_packages(root_dir_path: &Path, specs: &[String]) -> BakeryResult<()> {
+    if specs.is_empty() {
+        return Ok(());
+    }
+    let world_path = root_dir_path.join("etc/apk/world");
+    let world = fs::read_to_string(&world_path).whatever("unable to read apk world")?;
+    let world = world
+        .lines()
+        .filter(|line| !specs.iter().any(|spec| spec == line))
+        .map(|line| format!("{line}\n"))
+        .collect::<String>();
+    fs::write(&world_path, world).whatever("unable to write apk world")
+}
+
+/// Mount `/dev`, `/sys`, `/proc`, and the project directory into the given root.
+fn mount_all(
+    project: &ProjectRef,
+    root_dir_path: &Path,
+    stack: &mut MountStack,
+) -> BakeryResult<()> {
+    stack.push(
+        Mounted::bind_recursive("/dev", root_dir_path.join("dev"))
+            .whatever("unable to mount /dev")?,
+    );
+    stack.push(
+        Mounted::bind_recursive("/sys", root_dir_path.join("sys"))
+            .whatever("unable to mount /sys")?,
+    );
+    stack.push(
+        Mounted::bind_recursive("/proc", root_dir_path.join("proc"))
+            .whatever("unable to mount /proc")?,
+    );
 
     let project_dir = root_dir_path.join("run/rugix/bakery/project");
+    fs::create_dir_all(&project_dir).whatever("unable to create project directory")?;
 
-    for (idx, job) in jobs.iter().enumerate() {
-        let recipe = &job.recipe;
-        info!(
-            "[{:>2}/{}] {} {:?}",
-            idx + 1,
-            jobs.len(),
-            recipe
-                .config
-                .description
-                .as_deref()
-                .unwrap_or(recipe.name.deref()),
-            &job.parameters,
+    let resolved_resolv = root_dir_path.join("run/systemd/resolve/stub-resolv.conf");
+    fs::create_dir_all(resolved_resolv.parent().unwrap())
+        .whatever("unable to create `systemd/resolve` directory")?;
+    let resolv_conf = fs::read("/etc/resolv.conf").whatever("unable to read `/etc/resolv.conf")?;
+    fs::write(resolved_resolv, resolv_conf).whatever("unable to write `resolv.conf`")?;
+
+    stack.push(
+        Mounted::bind(project.dir(), &project_dir)
+            .whatever("unable to bind mount project directory")?,
+    );
+
+    Ok(())
+}
+
+/// Mount the recipe, the layer directory, and the build environment into the given
+/// root, as required by `install` steps.
+fn mount_install(
+    layer_ctx: &LayerContext,
+    recipe: Option<&Recipe>,
+    root_dir_path: &Path,
+) -> BakeryResult<Vec<Mounted>> {
+    let mut mounted = Vec::new();
+    if let Some(recipe) = recipe {
+        let bakery_recipe_path = root_dir_path.join("run/rugix/bakery/recipe");
+        fs::create_dir_all(&bakery_recipe_path).whatever("unable to create recipe directory")?;
+        mounted.push(
+            Mounted::bind(&recipe.path, &bakery_recipe_path)
+                .whatever("unable to bind mount recipe")?,
         );
+    }
+    let chroot_layer_dir = root_dir_path.join("run/rugix/bakery/layer");
+    fs::create_dir_all(&chroot_layer_dir).whatever("unable to create layer bundle directory")?;
+    mounted.push(
+        Mounted::bind(&layer_ctx.build_dir, &chroot_layer_dir)
+            .whatever("unable to bind mount layer")?,
+    );
+    let build_env_path = root_dir_path.join("run/rugix/bakery/build-env");
+    fs::create_dir_all(&build_env_path).whatever("unable to create recipe directory")?;
+    mounted.push(
+        Mounted::bind_recursive("/run/rugix/bakery", &build_env_path)
+            .whatever("unable to bind mount recipe")?,
+    );
+    Ok(mounted)
+}
 
-        for step in &recipe.steps {
-            info!("    - {}", step.filename);
-            match &step.kind {
-                StepKind::Packages { packages, manager } => {
-                    if mount_stack.is_empty() {
-                        mount_all(project, root_dir_path, &mut mount_stack)?;
-                    }
-                    let chroot_manager = if root_dir_path.join("usr/bin/apt-get").exists() {
-                        PackageManager::Apt
-                    } else if root_dir_path.join("sbin/apk").exists() {
-                        PackageManager::Apk
-                    } else {
-                        bail!("unable to determine package manager")
-                    };
-                    let manager = manager.unwrap_or(chroot_manager);
-                    if manager == chroot_manager {
-                        let mut cmd = match manager {
-                            PackageManager::Apt => {
-                                cmd!("chroot", root_dir_path, "apt-get", "install", "-y")
-                            }
-                            PackageManager::Apk => {
-                                cmd!("chroot", root_dir_path, "apk", "add", "--no-interactive")
-                            }
-                        };
-                        cmd.extend_args(packages);
-                        ParentEnv
-                            .run(cmd.with_vars(vars! {
-                                DEBIAN_FRONTEND = "noninteractive",
-                                SOURCE_DATE_EPOCH = source_date_epoch.to_string(),
-                            }))
-                            .whatever("unable to install packages")?;
-                    }
-                }
-                StepKind::Install => {
-                    if mount_stack.is_empty() {
-                        mount_all(project, root_dir_path, &mut mount_stack)?;
-                    }
-                    let bakery_recipe_path = root_dir_path.join("run/rugix/bakery/recipe");
-                    fs::create_dir_all(&bakery_recipe_path)
-                        .whatever("unable to create recipe directory")?;
-                    let _mounted_recipe = Mounted::bind(&recipe.path, &bakery_recipe_path)
-                        .whatever("unable to bind mount recipe")?;
-                    let chroot_layer_dir = root_dir_path.join("run/rugix/bakery/layer");
-                    fs::create_dir_all(&chroot_layer_dir)
-                        .whatever("unable to create layer bundle directory")?;
-                    let _mounted_layer_dir = Mounted::bind(&layer_ctx.build_dir, &chroot_layer_dir)
-                        .whatever("unable to bind mount layer")?;
-                    let build_env_path = root_dir_path.join("run/rugix/bakery/build-env");
-                    fs::create_dir_all(&build_env_path)
-                        .whatever("unable to create recipe directory")?;
-                    let _mounted_build_env =
-                        Mounted::bind_recursive("/run/rugix/bakery", &build_env_path)
-                            .whatever("unable to bind mount recipe")?;
-                    let script = format!("/run/rugix/bakery/recipe/steps/{}", step.filename);
-                    let mut vars = vars! {
-                        DEBIAN_FRONTEND = "noninteractive",
-                        RUGIX_LAYER_DIR = "/run/rugix/bakery/layer",
-                        RUGIX_ARTIFACTS_DIR = "/run/rugix/bakery/layer/artifacts",
-                        RUGIX_CONTEXT_DIR = "/run/rugix/bakery/build-env/context",
-                        RUGIX_CACHE_DIR = Path::new("/run/rugix/bakery/build-env/cache").join(project.local_id().as_str()),
-                        RUGIX_ROOT_DIR = "/",
-                        RUGIX_PROJECT_DIR = "/run/rugix/bakery/project",
-                        RUGIX_ARCH = arch.as_str(),
-                        LAYER_REBUILD_IF_CHANGED = Path::new("/run/rugix/bakery/project").join(&layer_ctx.output_dir).join("rebuild-if-changed.txt"),
-                        RECIPE_DIR = "/run/rugix/bakery/recipe",
-                        RECIPE_STEP_PATH = &script,
-                        SOURCE_DATE_EPOCH = source_date_epoch.to_string(),
-                    };
-                    for (name, value) in &job.parameters {
-                        vars.set(format!("RECIPE_PARAM_{}", name.to_uppercase()), value);
-                    }
-                    run_cmd(
-                        logger,
-                        Cmd::new("chroot")
-                            .add_arg(root_dir_path)
-                            .add_arg(&script)
-                            .clone()
-                            .with_vars(vars),
-                    )?;
-                }
-                StepKind::Run => {
-                    let script = recipe.path.join("steps").join(&step.filename);
-                    let mut vars = vars! {
-                        DEBIAN_FRONTEND = "noninteractive",
-                        RUGIX_LAYER_DIR = &layer_ctx.build_dir,
-                        RUGIX_ARTIFACTS_DIR = layer_ctx.build_dir.join("artifacts"),
-                        RUGIX_ROOT_DIR = root_dir_path,
-                        RUGIX_CONTEXT_DIR = "/run/rugix/bakery/context",
-                        RUGIX_CACHE_DIR = Path::new("/run/rugix/bakery/cache").join(project.local_id().as_str()),
-                        RUGIX_PROJECT_DIR = &project_dir,
-                        RUGIX_ARCH = arch.as_str(),
-                        LAYER_REBUILD_IF_CHANGED = project_dir.join(&layer_ctx.output_dir).join("rebuild-if-changed.txt"),
-                        RECIPE_DIR = &recipe.path,
-                        RECIPE_STEP_PATH = &script,
-                        SOURCE_DATE_EPOCH = source_date_epoch.to_string(),
-                    };
-                    for (name, value) in &job.parameters {
-                        vars.set(format!("RECIPE_PARAM_{}", name.to_uppercase()), value);
-                    }
-                    run_cmd(logger, Cmd::new(&script).with_vars(vars))?;
-                }
-            }
+/// Environment variables of `install` steps.
+///
+/// Recipe-specific variables are only set if a job and the path of its step are given.
+fn install_vars(
+    layer_ctx: &LayerContext,
+    project: &ProjectRef,
+    arch: Architecture,
+    step: Option<(&RecipeJob, &str)>,
+    source_date_epoch: u64,
+) -> Vars {
+    let mut vars = vars! {
+        DEBIAN_FRONTEND = "noninteractive",
+        RUGIX_LAYER_DIR = "/run/rugix/bakery/layer",
+        RUGIX_ARTIFACTS_DIR = "/run/rugix/bakery/layer/artifacts",
+        RUGIX_CONTEXT_DIR = "/run/rugix/bakery/build-env/context",
+        RUGIX_CACHE_DIR = Path::new("/run/rugix/bakery/build-env/cache").join(project.local_id().as_str()),
+        RUGIX_ROOT_DIR = "/",
+        RUGIX_PROJECT_DIR = "/run/rugix/bakery/project",
+        RUGIX_ARCH = arch.as_str(),
+        LAYER_REBUILD_IF_CHANGED = Path::new("/run/rugix/bakery/project").join(&layer_ctx.output_dir).join("rebuild-if-changed.txt"),
+        SOURCE_DATE_EPOCH = source_date_epoch.to_string(),
+    };
+    if let Some((job, script)) = step {
+        vars.set("RECIPE_DIR", "/run/rugix/bakery/recipe");
+        vars.set("RECIPE_STEP_PATH", script);
+        for (name, value) in &job.parameters {
+            vars.set(format!("RECIPE_PARAM_{}", name.to_uppercase()), value);
         }
     }
+    vars
+}
 
+/// Drop into an interactive shell within the given root directory.
+///
+/// The shell runs with the same mounts and environment variables as `install` steps. If
+/// a job and one of its steps are given, the recipe's variables are set as well.
+pub fn debug_shell(
+    layer_ctx: &LayerContext,
+    project: &ProjectRef,
+    arch: Architecture,
+    step: Option<(&RecipeJob, &RecipeStep)>,
+    root_dir_path: &Path,
+    source_date_epoch: u64,
+) -> BakeryResult<()> {
+    let mut mount_stack = MountStack::new();
+    mount_all(project, root_dir_path, &mut mount_stack)?;
+    let _mounted = mount_install(
+        layer_ctx,
+        step.map(|(job, _)| job.recipe.as_ref()),
+        root_dir_path,
+    )?;
+    let script = step.map(|(_, step)| format!("/run/rugix/bakery/recipe/steps/{}", step.filename));
+    let vars = install_vars(
+        layer_ctx,
+        project,
+        arch,
+        step.zip(script.as_deref())
+            .map(|((job, _), script)| (job, script)),
+        source_date_epoch,
+    );
+    let shell = if root_dir_path.join("bin/bash").exists() {
+        "/bin/bash"
+    } else {
+        "/bin/sh"
+    };
+    let mut command = std_command(
+        &Cmd::new("chroot")
+            .add_arg(root_dir_path)
+            .add_arg(shell)
+            .clone()
+            .with_vars(vars),
+    );
+    let status = rugix_cli::suspend(|| {
+        match step {
+            Some((job, step)) => eprintln!(
+                "Debug shell at step `{}` of recipe `{}`, changes are discarded on exit.",
+                step.filename, job.recipe.name
+            ),
+            None => eprintln!("Debug shell, changes are discarded on exit."),
+        }
+        command.status()
+    })
+    .whatever("unable to run debug shell")?;
+    if !status.success() {
+        info!("debug shell exited with {status}");
+    }
     Ok(())
 }
+
+/// Extract the given layer and drop into an interactive shell within its root.
+pub fn debug_layer(
+    project: &ProjectRef,
+    arch: Architecture,
+    layer: &FrozenLayer,
+    layer_path: &Path,
+    source_date_epoch: u64,
+) -> BakeryResult<()> {
+    let bundle_dir = tempdir().whatever("unable to create temporary directory")?;
+    let bundle_dir = bundle_dir.path();
+    run!(["tar", "-x", "-f", layer.path(), "-C", bundle_dir])
+        .whatever("unable to extract layer")?;
+    let layer_ctx = LayerContext {
+        project: project.clone(),
+        build_dir: bundle_dir.to_path_buf(),
+        output_dir: layer_path.to_path_buf(),
+    };
+    let root_dir = bundle_dir.join("roots/system");
+    debug_shell(
+        &layer_ctx,
+        project,
+        arch,
+        None,
+        &root_dir,
+        source_date_epoch,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Check that the given positions form a cycle of the given graph.
+    fn assert_cycle(successors: &[Vec<usize>], cycle: &[usize]) {
+        assert!(cycle.len() > 1);
+        assert_eq!(cycle.first(), cycle.last());
+        for pair in cycle.windows(2) {
+            assert!(successors[pair[0]].contains(&pair[1]), "{cycle:?}");
+        }
+    }
+
+    #[test]
+    fn test_order_dependencies() {
+        let successors = vec![vec![2], vec![2], vec![], vec![0]];
+        let order = order_positions(&successors, |_| ()).unwrap();
+        assert_eq!(order, vec![1, 3, 0, 2]);
+    }
+
+    #[test]
+    fn test_order_priority() {
+        let priorities = [0, 5, 0, -1];
+        let successors = vec![vec![]; priorities.len()];
+        let order = order_positions(&successors, |position| priorities[position]).unwrap();
+        assert_eq!(order, vec![1, 0, 2, 3]);
+        // Constraints take precedence over priorities.
+        let successors = vec![vec![1], vec![], vec![], vec![]];
+        let order = order_positions(&successors, |position| priorities[position]).unwrap();
+        assert_eq!(order, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_order_name() {
+        let names = ["b", "a", "c"];
+        let successors = vec![vec![]; names.len()];
+        let order = order_positions(&successors, |position| Reverse(names[position])).unwrap();
+        assert_eq!(order, vec![1, 0, 2]);
+    }
+
+    #[test]
+    fn test_order_cycle() {
+        let successors = vec![vec![1], vec![2], vec![0, 4], vec![], vec![]];
+        let cycle = order_positions(&successors, |_| ()).unwrap_err();
+        assert_eq!(cycle.len(), 4);
+        assert_cycle(&successors, &cycle);
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        let successors = vec![vec![1], vec![0], vec![]];
+        assert_eq!(find_cycle(&successors, &[1, 1, 0]), vec![1, 0, 1]);
+        // Jobs which only depend on a cycle are not part of it.
+        let successors = vec![vec![1], vec![2], vec![1]];
+        assert_eq!(find_cycle(&successors, &[0, 1, 1]), vec![2, 1, 2]);
+        let successors = vec![vec![0]];
+        assert_eq!(find_cycle(&successors, &[1]), vec![0, 0]);
+    }
+}
@@ -0,0 +1,279 @@
+//! Declarative copying of files into a root.
+//!
+//! Files are copied from the host without a `chroot`. Paths are resolved within the
+//! root, i.e., symbolic links in the root are followed as if the root was `/`, and
+//! owners and groups are looked up in the root's `/etc/passwd` and `/etc/group`.
+//! Templates are rendered instead of being copied verbatim.
+
+use std::collections::VecDeque;
+use std::ffi::OsString;
+use std::fs;
+use std::os::unix::fs::{lchown, symlink, PermissionsExt};
+use std::path::{Component, Path, PathBuf};
+
+use reportify::{bail, whatever, ResultExt};
+
+use crate::oven::templates::{template_name, TemplateRenderer};
+use crate::project::recipes::FileCopy;
+use crate::BakeryResult;
+
+/// Permission bits of created directories without an explicit directory mode.
+const DEFAULT_DIRECTORY_MODE: u32 = 0o755;
+
+/// Maximal number of symbolic links followed when resolving a path.
+const MAX_SYMLINKS: usize = 40;
+
+/// Copy the given files into the given root.
+pub fn copy_files(
+    root: &Path,
+    files: &[FileCopy],
+    templates: &TemplateRenderer,
+) -> BakeryResult<()> {
+    for file in files {
+        let uid = lookup_id(root, "etc/passwd", &file.owner)?;
+        let gid = lookup_id(root, "etc/group", &file.group)?;
+        let copier = Copier {
+            root,
+            file,
+            templates,
+            uid,
+            gid,
+        };
+        copier
+            .copy(&file.source, &file.destination)
+            .with_info(|_| format!("copying {:?} to {:?}", file.source, file.destination))?;
+    }
+    Ok(())
+}
+
+/// Copies a file or directory with the settings of a [`FileCopy`].
+struct Copier<'c> {
+    root: &'c Path,
+    file: &'c FileCopy,
+    templates: &'c TemplateRenderer,
+    uid: u32,
+    gid: u32,
+}
+
+impl Copier<'_> {
+    fn copy(&self, source: &Path, destination: &Path) -> BakeryResult<()> {
+        let metadata = fs::symlink_metadata(source).whatever("unable to read source")?;
+        let target = if metadata.is_dir() {
+            // Directories are copied into symbolic links to directories, e.g., `/lib` on
+            // systems with a merged `/usr`.
+            resolve_all_in_root(self.root, destination)?
+        } else {
+            resolve_in_root(self.root, destination)?
+        };
+        let existing = fs::symlink_metadata(&target).ok();
+        if metadata.is_dir() {
+            // Existing directories, e.g., `/tmp`, keep their permissions and owner unless
+            // a directory mode is set explicitly.
+            let update = match existing {
+                Some(existing) if existing.is_dir() => self.file.directory_mode.is_some(),
+                Some(_) => bail!("destination {destination:?} exists and is not a directory"),
+                None => {
+                    create_parent_dirs(&target)?;
+                    fs::create_dir(&target).whatever("unable to create directory")?;
+                    true
+                }
+            };
+            if update {
+                let mode = self.file.directory_mode.unwrap_or(DEFAULT_DIRECTORY_MODE);
+                fs::set_permissions(&target, fs::Permissions::from_mode(mode))
+                    .whatever("unable to set permissions of directory")?;
+                lchown(&target, Some(self.uid), Some(self.gid))
+                    .whatever("unable to set owner of directory")?;
+            }
+            let mut entries = fs::read_dir(source)
+                .whatever("unable to read source directory")?
+                .collect::<Result<Vec<_>, _>>()
+                .whatever("unable to read source directory")?;
+            entries.sort_by_key(|entry| entry.file_name());
+            for entry in entries {
+                let file_name = entry.file_name();
+                let is_dir = entry
+                    .file_type()
+                    .whatever("unable to read source directory")?
+                    .is_dir();
+                // Rendered templates are installed without the template suffix.
+                let name = match template_name(&file_name) {
+                    Some(name) if !is_dir => name,
+                    _ => &file_name,
+                };
+                self.copy(&entry.path(), &destination.join(name))?;
+            }
+            return Ok(());
+        }
+        match existing {
+            Some(existing) if existing.is_dir() => {
+                bail!("destination {destination:?} exists and is a directory")
+            }
+            Some(_) => fs::remove_file(&target).whatever("unable to remove existing file")?,
+            None => create_parent_dirs(&target)?,
+        }
+        if metadata.is_symlink() {
+            let link = fs::read_link(source).whatever("unable to read symbolic link")?;
+            symlink(link, &target).whatever("unable to create symbolic link")?;
+        } else {
+            if source.file_name().and_then(template_name).is_some() {
+                fs::write(&target, self.templates.render(source)?)
+                    .whatever("unable to write rendered template")?;
+            } else {
+                fs::copy(source, &target).whatever("unable to copy file")?;
+            }
+            let mode = self
+                .file
+                .mode
+                .unwrap_or(metadata.permissions().mode() & 0o7777);
+            fs::set_permissions(&target, fs::Permissions::from_mode(mode))
+                .whatever("unable to set permissions of file")?;
+        }
+        lchown(&target, Some(self.uid), Some(self.gid)).whatever("unable to set owner of file")
+    }
+}
+
+/// Create the missing parent directories of the given path.
+fn create_parent_dirs(path: &Path) -> BakeryResult<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).whatever("unable to create parent directories")?;
+    }
+    Ok(())
+}
+
+/// Resolve the given absolute path within the given root as if the root was `/`.
+///
+/// Symbolic links are followed within the root except for the final component.
+pub fn resolve_in_root(root: &Path, path: &Path) -> BakeryResult<PathBuf> {
+    let mut remaining = path_components(path);
+    let mut resolved = PathBuf::new();
+    let mut followed = 0;
+    while let Some(component) = remaining.pop_front() {
+        if component == ".." {
+            resolved.pop();
+            continue;
+        }
+        let candidate = resolved.join(&component);
+        if remaining.is_empty() {
+            resolved = candidate;
+            break;
+        }
+        let candidate_path = root.join(&candidate);
+        match fs::symlink_metadata(&candidate_path) {
+            Ok(metadata) if metadata.is_symlink() => {
+                followed += 1;
+                if followed > MAX_SYMLINKS {
+                    bail!("too many symbolic links while resolving {path:?}");
+                }
+                let link = fs::read_link(&candidate_path)
+                    .whatever_with(|_| format!("unable to read symbolic link {candidate:?}"))?;
+                if link.is_absolute() {
+                    resolved = PathBuf::new();
+                }
+                let mut components = path_components(&link);
+                components.extend(remaining);
+                remaining = components;
+            }
+            _ => resolved = candidate,
+        }
+    }
+    Ok(root.join(resolved))
+}
+
+/// Resolve the given absolute path within the given root as if the root was `/`.
+///
+/// In contrast to [`resolve_in_root`], symbolic links are also followed for the final
+/// component.
+fn resolve_all_in_root(root: &Path, path: &Path) -> BakeryResult<PathBuf> {
+    let mut current = path.to_path_buf();
+    for _ in 0..MAX_SYMLINKS {
+        let resolved = resolve_in_root(root, &current)?;
+        let Ok(link) = fs::read_link(&resolved) else {
+            return Ok(resolved);
+        };
+        // Relative links are relative to the already resolved parent directory.
+        let parent = resolved
+            .strip_prefix(root)
+            .ok()
+            .and_then(Path::parent)
+            .unwrap_or(Path::new(""));
+        current = Path::new("/").join(parent).join(link);
+    }
+    bail!("too many symbolic links while resolving {path:?}")
+}
+
+/// Normal and parent directory components of the given path.
+fn path_components(path: &Path) -> VecDeque<OsString> {
+    path.components()
+        .filter_map(|component| match component {
+            Component::Normal(name) => Some(name.to_owned()),
+            Component::ParentDir => Some(OsString::from("..")),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Look up the numeric id of the given user or group name in the given database.
+///
+/// Numeric ids are used as is and `root` falls back to `0` if it has no entry.
+fn lookup_id(root: &Path, database: &str, name: &str) -> BakeryResult<u32> {
+    if let Ok(id) = name.parse() {
+        return Ok(id);
+    }
+    let path = root.join(database);
+    if path.exists() {
+        let entries =
+            fs::read_to_string(&path).whatever_with(|_| format!("unable to read `/{database}`"))?;
+        for line in entries.lines() {
+            let mut fields = line.split(':');
+            if fields.next() == Some(name) {
+                if let Some(id) = fields.nth(1).and_then(|id| id.parse().ok()) {
+                    return Ok(id);
+                }
+            }
+        }
+    }
+    if name == "root" {
+        return Ok(0);
+    }
+    Err(whatever!("unable to find `{name}` in `/{database}`"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::os::unix::fs::MetadataExt;
+
+    use super::*;
+
+    use crate::config::systems::Architecture;
+
+    #[test]
+    fn test_copy_into_symlinked_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().join("root");
+        fs::create_dir_all(root.join("usr/lib")).unwrap();
+        symlink("usr/lib", root.join("lib")).unwrap();
+        let source = dir.path().join("files/lib");
+        fs::create_dir_all(source.join("modules-load.d")).unwrap();
+        fs::write(source.join("modules-load.d/rugix.conf"), "overlay\n").unwrap();
+        // Use the current owner such that the test does not require root privileges.
+        let metadata = fs::metadata(dir.path()).unwrap();
+        let file = FileCopy {
+            source,
+            destination: PathBuf::from("/lib"),
+            mode: None,
+            directory_mode: None,
+            owner: metadata.uid().to_string(),
+            group: metadata.gid().to_string(),
+        };
+        let parameters = HashMap::new();
+        let templates = TemplateRenderer::new(&parameters, Architecture::Amd64, None);
+        copy_files(&root, &[file], &templates).unwrap();
+        assert!(fs::symlink_metadata(root.join("lib")).unwrap().is_symlink());
+        assert_eq!(
+            fs::read_to_string(root.join("usr/lib/modules-load.d/rugix.conf")).unwrap(),
+            "overlay\n"
+        );
+    }
+}
@@ -0,0 +1,216 @@
+//! Garbage collection of the local cache.
+//!
+//! The local cache consists of baked layers (`.rugix/layers`), recipe checkpoints
+//! (`.rugix/checkpoints`), and downloaded images (`.rugix/cache`). Entries are evicted in
+//! least recently used order, where the modification time of an entry's main file is
+//! its last use (see [`touch`][crate::utils::caching::touch]). Layers and downloads that
+//! are reachable from the project's systems are never evicted. As recipe templates may
+//! refer to the release information, reachability depends on it.
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use reportify::ResultExt;
+
+use crate::project::ProjectRef;
+use crate::utils::caching::download_cache_path;
+use crate::BakeryResult;
+
+use super::system::{ReleaseInfo, TargetSystem};
+use super::{size, LayerBakery, LayerSource};
+
+/// Kind of a cache entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheEntryKind {
+    Layer,
+    Checkpoint,
+    Download,
+}
+
+impl CacheEntryKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CacheEntryKind::Layer => "layer",
+            CacheEntryKind::Checkpoint => "checkpoint",
+            CacheEntryKind::Download => "download",
+        }
+    }
+}
+
+/// Entry of the local cache.
+#[derive(Debug)]
+pub struct CacheEntry {
+    /// Kind of the entry.
+    pub kind: CacheEntryKind,
+    /// Paths belonging to the entry.
+    pub paths: Vec<PathBuf>,
+    /// Size of the entry in bytes.
+    pub size: u64,
+    /// Time when the entry has last been used.
+    pub last_used: SystemTime,
+}
+
+impl CacheEntry {
+    /// Remove the entry.
+    pub fn remove(&self) -> BakeryResult<()> {
+        for path in &self.paths {
+            if path.is_dir() {
+                fs::remove_dir_all(path)
+            } else {
+                fs::remove_file(path)
+            }
+            .whatever_with(|_| format!("unable to remove {path:?}"))?;
+        }
+        Ok(())
+    }
+}
+
+/// Limits of the local cache.
+#[derive(Debug, Default)]
+pub struct GcLimits {
+    /// Maximal size of the cache in bytes.
+    pub max_size: Option<u64>,
+    /// Maximal time since the last use of an entry.
+    pub max_age: Option<Duration>,
+}
+
+/// Result of a garbage collection.
+#[derive(Debug)]
+pub struct GcPlan {
+    /// Entries to evict in the order of their last use.
+    pub evict: Vec<CacheEntry>,
+    /// Total size of the cache in bytes.
+    pub total_size: u64,
+}
+
+/// Determine the cache entries to evict such that the cache stays within the limits.
+pub fn plan_gc(
+    project: &ProjectRef,
+    release_info: &ReleaseInfo,
+    limits: &GcLimits,
+) -> BakeryResult<GcPlan> {
+    let (protected_layers, protected_downloads) = reachable_entries(project, release_info)?;
+    let mut entries = Vec::new();
+    let layers_dir = project.dir().join(project.layers_dir());
+    for (name, path) in read_entries(&layers_dir)? {
+        if !protected_layers.contains(&name) {
+            entries.push(dir_entry(CacheEntryKind::Layer, path, "system.tar")?);
+        }
+    }
+    let checkpoints_dir = project.dir().join(project.checkpoints_dir());
+    for (_, path) in read_entries(&checkpoints_dir)? {
+        entries.push(dir_entry(CacheEntryKind::Checkpoint, path, "bundle.tar")?);
+    }
+    let downloads_dir = project.dir().join(".rugix/cache");
+    for (name, path) in read_entries(&downloads_dir)? {
+        if name.ends_with(".meta.json") || protected_downloads.contains(&name) {
+            continue;
+        }
+        let mut metadata_name = name.clone();
+        metadata_name.push_str(".meta.json");
+        let metadata_path = downloads_dir.join(metadata_name);
+        let mut entry = CacheEntry {
+            kind: CacheEntryKind::Download,
+            size: disk_usage(&path)?,
+            last_used: last_modified(&path)?,
+            paths: vec![path],
+        };
+        if metadata_path.exists() {
+            entry.size += disk_usage(&metadata_path)?;
+            entry.paths.push(metadata_path);
+        }
+        entries.push(entry);
+    }
+    let total_size =
+        disk_usage(&layers_dir)? + disk_usage(&checkpoints_dir)? + disk_usage(&downloads_dir)?;
+    entries.sort_by_key(|entry| entry.last_used);
+    let now = SystemTime::now();
+    let mut remaining_size = total_size;
+    let mut evict = Vec::new();
+    for entry in entries {
+        let expired = limits.max_age.is_some_and(|max_age| {
+            now.duration_since(entry.last_used).unwrap_or_default() > max_age
+        });
+        let exceeded = limits
+            .max_size
+            .is_some_and(|max_size| remaining_size > max_size);
+        if expired || exceeded {
+            remaining_size -= entry.size.min(remaining_size);
+            evict.push(entry);
+        }
+    }
+    Ok(GcPlan { evict, total_size })
+}
+
+/// Layers and downloads reachable from the project's systems.
+fn reachable_entries(
+    project: &ProjectRef,
+    release_info: &ReleaseInfo,
+) -> BakeryResult<(HashSet<String>, HashSet<String>)> {
+    let mut layers = HashSet::new();
+    let mut downloads = HashSet::new();
+    for (system, system_config) in project.config().systems.iter().flatten() {
+        let bakery = LayerBakery::new(project, system_config.architecture)
+            .with_target(system_config.target.clone())
+            .with_system(TargetSystem {
+                name: system.clone(),
+                release: release_info.clone(),
+            });
+        let plan = bakery.plan_root(&system_config.layer)?;
+        for plan in plan.ancestors() {
+            layers.insert(plan.id.clone());
+            if let LayerSource::Url(image) = &plan.source {
+                if image.url.scheme().starts_with("http") {
+                    let path = download_cache_path(&image.url)?;
+                    downloads.insert(path.file_name().unwrap().to_string_lossy().into_owned());
+                }
+            }
+        }
+    }
+    Ok((layers, downloads))
+}
+
+/// Read the entries of the given directory, if it exists.
+fn read_entries(dir: &Path) -> BakeryResult<Vec<(String, PathBuf)>> {
+    let mut entries = Vec::new();
+    if dir.exists() {
+        for entry in fs::read_dir(dir).whatever("unable to read cache directory")? {
+            let entry = entry.whatever("unable to read cache directory")?;
+            entries.push((
+                entry.file_name().to_string_lossy().into_owned(),
+                entry.path(),
+            ));
+        }
+    }
+    Ok(entries)
+}
+
+/// Cache entry for the given directory whose last use is tracked by the given file.
+fn dir_entry(kind: CacheEntryKind, path: PathBuf, main_file: &str) -> BakeryResult<CacheEntry> {
+    let main_file = path.join(main_file);
+    let last_used = if main_file.exists() {
+        last_modified(&main_file)?
+    } else {
+        last_modified(&path)?
+    };
+    Ok(CacheEntry {
+        kind,
+        size: disk_usage(&path)?,
+        last_used,
+        paths: vec![path],
+    })
+}
+
+/// Modification time of the given path.
+fn last_modified(path: &Path) -> BakeryResult<SystemTime> {
+    fs::symlink_metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .whatever_with(|_| format!("unable to read modification time of {path:?}"))
+}
+
+/// Size of the given file or directory in bytes.
+fn disk_usage(path: &Path) -> BakeryResult<u64> {
+    size::disk_usage(path).whatever_with(|_| format!("unable to compute disk usage of {path:?}"))
+}
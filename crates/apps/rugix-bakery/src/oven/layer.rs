@@ -7,27 +7,27 @@ use xscript::{run, Run};
 use reportify::ResultExt;
 
 use crate::project::ProjectRef;
-use crate::utils::caching::{mtime, ModificationTime};
 use crate::BakeryResult;
 
 #[derive(Debug)]
 pub struct FrozenLayer {
     name: String,
+    id: String,
     path: PathBuf,
 }
 
 impl FrozenLayer {
-    pub fn new(name: String, path: PathBuf) -> Self {
-        Self { name, path }
+    pub fn new(name: String, id: String, path: PathBuf) -> Self {
+        Self { name, id, path }
     }
 
-    pub fn last_modified(&self) -> BakeryResult<ModificationTime> {
-        mtime(&self.path).whatever_with(|_| {
-            format!(
-                "unable to determine modification time of layer {}",
-                self.name
-            )
-        })
+    /// Content-derived id of the layer.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
     }
 
     pub fn unfreeze(&self) -> BakeryResult<Layer> {
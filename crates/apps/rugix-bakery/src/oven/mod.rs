@@ -1,30 +1,54 @@
 //! Functionality for baking layers and images.
 
+use std::collections::HashSet;
 use std::fs;
+use std::io::{self, Read};
+use std::os::unix::fs::FileExt;
 use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
 
 use clap::Args;
 use layer::FrozenLayer;
 use reportify::{bail, whatever, ResultExt};
 use rugix_bundle::manifest::{self, BundleManifest, ChunkerAlgorithm};
 use rugix_common::img_extract::extract_image_partitions;
-use system::ReleaseInfo;
+use serde::Deserialize;
+use system::{ReleaseInfo, TargetSystem};
 use tempfile::tempdir;
-use tracing::info;
+use tracing::{error, info};
 use url::Url;
-use xscript::{run, Run};
+use xscript::{read_str, run, ParentEnv, Run};
 
 use crate::config::images::PartitionTableType;
+use crate::config::layers::{ImportPartition, ImportRoot};
 use crate::config::systems::{Architecture, Target};
+use crate::oven::cache::LayerCache;
+use crate::oven::customize::RecipeJob;
+use crate::oven::provenance::LayerProvenance;
+use crate::oven::size::SizeReport;
 use crate::project::library::LayerIdx;
+use crate::project::lockfile::{LayerLock, LockMode, LockedPackage};
 use crate::project::ProjectRef;
-use crate::utils::caching::{download, Hasher};
+use crate::utils::atomic;
+use crate::utils::caching::{digest_path, download, download_metadata_path, ContentDigest, Hasher};
+use crate::utils::compression::{detect_compression, open_decompressed};
 use crate::BakeryResult;
 
+pub mod accounts;
+pub mod cache;
 pub mod customize;
+pub mod files;
+pub mod gc;
 pub mod layer;
+pub mod oci;
+pub mod packages;
+pub mod provenance;
+pub mod reproducible;
+pub mod size;
 pub mod system;
+pub mod systemd;
 pub mod targets;
+pub mod templates;
 
 pub fn bake_system(
     project: &ProjectRef,
@@ -38,9 +62,13 @@ pub fn bake_system(
         .get_system_config(system)
         .ok_or_else(|| whatever!("unable to find image {system}"))?;
     info!("baking image `{system}`");
-    let layer_bakery = LayerBakery::new(project, system_config.architecture);
-    let baked_layer = layer_bakery.bake_root(&system_config.layer, source_date_epoch)?;
-    let frozen = FrozenLayer::new(system_config.layer.clone(), baked_layer);
+    let layer_bakery = LayerBakery::new(project, system_config.architecture)
+        .with_target(system_config.target.clone())
+        .with_system(TargetSystem {
+            name: system.to_owned(),
+            release: release_info.clone(),
+        });
+    let frozen = layer_bakery.bake_root(&system_config.layer, source_date_epoch)?;
     system::make_system(
         system_config,
         release_info,
@@ -51,148 +79,798 @@ pub fn bake_system(
     )
 }
 
+/// Bake the root of the given system and export it as an OCI image.
+pub fn bake_system_oci(
+    project: &ProjectRef,
+    release_info: &ReleaseInfo,
+    system: &str,
+    output: &Path,
+    source_date_epoch: u64,
+) -> BakeryResult<()> {
+    let system_config = project.config().resolve_system_config(system)?;
+    let layer_bakery = LayerBakery::new(project, system_config.architecture)
+        .with_target(system_config.target.clone())
+        .with_system(TargetSystem {
+            name: system.to_owned(),
+            release: release_info.clone(),
+        });
+    let frozen = layer_bakery.bake_root(&system_config.layer, source_date_epoch)?;
+    let layer = frozen.unfreeze()?;
+    let system_dir = layer.path().join("roots/system");
+    system::write_system_info(&system_dir, system, release_info, source_date_epoch)?;
+    oci::export_oci(
+        &system_dir,
+        system_config.architecture,
+        system,
+        output,
+        source_date_epoch,
+    )
+}
+
+/// Bake the given layer and export its system root as an OCI image.
+pub fn bake_layer_oci(
+    project: &ProjectRef,
+    arch: Architecture,
+    layer: &str,
+    output: &Path,
+    source_date_epoch: u64,
+) -> BakeryResult<()> {
+    let frozen = LayerBakery::new(project, arch).bake_root(layer, source_date_epoch)?;
+    let unfrozen = frozen.unfreeze()?;
+    oci::export_oci(
+        &unfrozen.path().join("roots/system"),
+        arch,
+        layer,
+        output,
+        source_date_epoch,
+    )
+}
+
 pub struct LayerBakery<'p> {
     project: &'p ProjectRef,
     arch: Architecture,
+    debug_on_failure: bool,
+    use_layer_cache: bool,
+    target: Option<Target>,
+    system: Option<TargetSystem>,
+}
+
+/// Plan for baking a layer.
+///
+/// The plan is derived from the inputs of the layer without baking anything.
+pub struct LayerPlan {
+    /// The layer.
+    pub layer: LayerIdx,
+    /// Content-derived id of the layer.
+    pub id: String,
+    /// Path of the layer directory relative to the project directory.
+    path: PathBuf,
+    /// Inputs from which the id of the layer has been derived.
+    pub inputs: Vec<(String, String)>,
+    /// Source from which the layer is baked.
+    pub source: LayerSource,
+    /// Recipes to apply to the source.
+    pub jobs: Vec<RecipeJob>,
+}
+
+impl LayerPlan {
+    /// Path of the layer directory relative to the project directory.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Iterator over the plan and the plans of all its ancestors.
+    pub fn ancestors(&self) -> impl Iterator<Item = &LayerPlan> {
+        std::iter::successors(Some(self), |plan| match &plan.source {
+            LayerSource::Parent(parent) => Some(parent.as_ref()),
+            _ => None,
+        })
+    }
+}
+
+/// Image from which a layer is extracted.
+pub struct ImageSource {
+    /// URL of the image.
+    pub url: Url,
+    /// Mirrors from which the image can be downloaded.
+    pub mirrors: Vec<Url>,
+    /// Expected digest of the image.
+    pub digest: Option<ContentDigest>,
+    /// Partitions to import if the image is a disk image.
+    pub partitions: Vec<ImportPartition>,
+}
+
+/// Source from which a layer is baked.
+pub enum LayerSource {
+    /// Layer is extracted from an image.
+    Url(ImageSource),
+    /// Layer is customized based on a parent layer.
+    Parent(Box<LayerPlan>),
+    /// Layer is customized based on an empty root.
+    Root,
 }
 
 impl<'p> LayerBakery<'p> {
     pub fn new(project: &'p ProjectRef, arch: Architecture) -> Self {
-        Self { project, arch }
+        Self {
+            project,
+            arch,
+            debug_on_failure: false,
+            use_layer_cache: true,
+            target: None,
+            system: None,
+        }
+    }
+
+    /// Evaluate recipe and step conditions for the given system target.
+    pub fn with_target(mut self, target: Option<Target>) -> Self {
+        self.target = target;
+        self
+    }
+
+    /// Render the templates of recipes for the given system.
+    pub fn with_system(mut self, system: TargetSystem) -> Self {
+        self.system = Some(system);
+        self
     }
 
-    pub fn bake_root(&self, layer: &str, source_date_epoch: u64) -> BakeryResult<PathBuf> {
+    /// Pull layers from the project's layer cache, if one is configured.
+    pub fn with_layer_cache(mut self, use_layer_cache: bool) -> Self {
+        self.use_layer_cache = use_layer_cache;
+        self
+    }
+
+    /// Drop into a debug shell at the failing step if a recipe fails.
+    pub fn with_debug_on_failure(mut self, debug_on_failure: bool) -> Self {
+        self.debug_on_failure = debug_on_failure;
+        self
+    }
+
+    pub fn bake_root(&self, layer: &str, source_date_epoch: u64) -> BakeryResult<FrozenLayer> {
+        let plan = self.plan_root(layer)?;
+        self.bake_plan(&plan, source_date_epoch)
+    }
+
+    /// Bake the given layer and drop into a debug shell within its root.
+    pub fn debug_root(&self, layer: &str, source_date_epoch: u64) -> BakeryResult<()> {
+        let plan = self.plan_root(layer)?;
+        let frozen = self.bake_plan(&plan, source_date_epoch)?;
+        customize::debug_layer(
+            self.project,
+            self.arch,
+            &frozen,
+            plan.path(),
+            source_date_epoch,
+        )
+    }
+
+    pub fn plan_root(&self, layer: &str) -> BakeryResult<LayerPlan> {
         let library = self.project.library()?;
         let Some(layer) = library.lookup_layer(library.repositories.root_repository, layer) else {
             bail!("unable to find layer {layer}");
         };
-        self.bake(layer, source_date_epoch)
+        self.plan(layer)
     }
 
-    pub fn bake(&self, layer: LayerIdx, source_date_epoch: u64) -> BakeryResult<PathBuf> {
-        let repositories = &self.project.repositories()?.repositories;
+    pub fn plan(&self, layer_idx: LayerIdx) -> BakeryResult<LayerPlan> {
+        let repositories = self.project.repositories()?;
         let library = self.project.library()?;
-        let layer = &library.layers[layer];
-        info!("baking layer `{}`", layer.name);
+        let layer = &library.layers[layer_idx];
         let Some(config) = layer.config(self.arch) else {
             bail!("no layer configuration for architecture `{}`", self.arch);
         };
-        let mut layer_id = Hasher::new();
+        // The layer id is derived from the contents of all inputs of the layer.
+        let mut layer_id = Hasher::recording();
+        layer_id.push("bakery", rugix_version::RUGIX_GIT_VERSION);
         layer_id.push("layer", &layer.name);
-        layer_id.push("repository", repositories[layer.repo].source.id.as_str());
+        repositories[layer.repo].source.push_digest(&mut layer_id);
         layer_id.push("arch", self.arch.as_str());
-        if let Some(url) = &config.url {
+        if let Some(digest) = layer.config_digest(self.arch) {
+            layer_id.push("config", digest);
+        }
+        let source = if let Some(url) = &config.url {
             layer_id.push("url", url);
-            let layer_id = layer_id.finalize();
-            let system_tar = self
-                .project
-                .dir()
-                .join(format!(".rugix/layers/{layer_id}/system.tar"));
-            if !system_tar.exists() {
-                extract(self.project, url, &system_tar)?;
+            let image_url = url.parse::<Url>().whatever("unable to parse image URL")?;
+            if let Some(image_path) = local_image_path(self.project, &image_url) {
+                let digest = digest_path(&image_path)
+                    .whatever_with(|_| format!("unable to compute digest of {image_path:?}"))?;
+                layer_id.push("image", digest);
             }
-            Ok(system_tar)
+            let digest = match &config.digest {
+                Some(digest) => {
+                    if matches!(image_url.scheme(), "oci" | "oci-archive") {
+                        bail!(
+                            "digests are not supported for OCI images, use a digest reference \
+                            `#sha256:<hex>` in the URL instead"
+                        );
+                    }
+                    let digest = ContentDigest::parse(digest)?;
+                    layer_id.push("digest", digest.to_string());
+                    Some(digest)
+                }
+                None => None,
+            };
+            let mirrors = config
+                .mirrors
+                .iter()
+                .flatten()
+                .map(|mirror| mirror.parse::<Url>().whatever("unable to parse mirror URL"))
+                .collect::<BakeryResult<_>>()?;
+            let partitions = match &config.partitions {
+                Some(partitions) => {
+                    for partition in partitions {
+                        let selectors = [
+                            partition.number.is_some(),
+                            partition.label.is_some(),
+                            partition.ty.is_some(),
+                        ];
+                        if selectors.into_iter().filter(|selected| *selected).count() != 1 {
+                            bail!("partitions must be selected by exactly one of `number`, `label`, or `type`");
+                        }
+                    }
+                    partitions.clone()
+                }
+                None => default_partitions(),
+            };
+            LayerSource::Url(ImageSource {
+                url: image_url,
+                mirrors,
+                digest,
+                partitions,
+            })
+        } else if config.digest.is_some() {
+            bail!("layer digest requires a URL");
         } else if let Some(parent) = &config.parent {
-            layer_id.push("parent", parent);
             let Some(parent) = library.lookup_layer(layer.repo, parent) else {
                 bail!("unable to find layer `{parent}`");
             };
-            let src = self.bake(parent, source_date_epoch)?;
-            let layer_id = layer_id.finalize();
-            let layer_path = PathBuf::from(format!(".rugix/layers/{layer_id}"));
-            let target = self.project.dir().join(&layer_path).join("system.tar");
-            fs::create_dir_all(target.parent().unwrap()).ok();
-            customize::customize(
-                self.project,
-                self.arch,
-                layer,
-                Some(&src),
-                &target,
-                &layer_path,
-                source_date_epoch,
-            )?;
-            Ok(target)
+            let parent = self.plan(parent)?;
+            layer_id.push("parent", &parent.id);
+            LayerSource::Parent(Box::new(parent))
         } else if config.root.unwrap_or(false) {
             layer_id.push("bare", "true");
-            let layer_id = layer_id.finalize();
-            let layer_path = PathBuf::from(format!(".rugix/layers/{layer_id}"));
-            let target = self.project.dir().join(&layer_path).join("system.tar");
-            fs::create_dir_all(target.parent().unwrap()).ok();
-            customize::customize(
-                self.project,
-                self.arch,
-                layer,
-                None,
-                &target,
-                &layer_path,
-                source_date_epoch,
-            )?;
-            Ok(target)
+            LayerSource::Root
         } else {
             bail!("invalid layer configuration")
+        };
+        let mut jobs = if matches!(source, LayerSource::Url(..)) {
+            Vec::new()
+        } else {
+            customize::recipe_schedule(
+                layer.repo,
+                config,
+                &library,
+                self.arch,
+                self.target.as_ref(),
+            )
+            .with_info(|_| {
+                format!(
+                    "while scheduling recipes of layer `{}` ({:?})",
+                    layer.name,
+                    layer.config_path(self.arch).unwrap_or(Path::new("")),
+                )
+            })?
+        };
+        for job in &mut jobs {
+            // Templates may refer to the system, hence, it is an input of their recipes.
+            if job.recipe.has_templates {
+                job.system = self.system.clone();
+            }
+            job.push_digest(&mut layer_id, &repositories);
+        }
+        // Locked bakes pin packages to the locked versions, hence, the lock is an input.
+        if self.project.lock_mode() == LockMode::Locked {
+            if let Some(lock) = self.project.lockfile().layer(&layer.name, self.arch) {
+                layer_id.push("lock", lock.digest());
+            }
         }
+        let inputs = layer_id.recorded().to_vec();
+        let id = layer_id.finalize();
+        Ok(LayerPlan {
+            layer: layer_idx,
+            path: self.project.layers_dir().join(&id),
+            id,
+            inputs,
+            source,
+            jobs,
+        })
     }
-}
 
-fn extract(project: &ProjectRef, image_url: &str, layer_path: &Path) -> BakeryResult<()> {
-    let image_url = image_url
-        .parse::<Url>()
-        .whatever("unable to parse image URL")?;
-    let mut image_path = match image_url.scheme() {
-        "file" => {
-            let mut image_path = project.dir().to_path_buf();
-            image_path.push(image_url.path().strip_prefix('/').unwrap());
-            image_path
+    pub fn bake_plan(&self, plan: &LayerPlan, source_date_epoch: u64) -> BakeryResult<FrozenLayer> {
+        let library = self.project.library()?;
+        let layer = &library.layers[plan.layer];
+        info!("baking layer `{}`", layer.name);
+        let layer_path = plan.path();
+        let layer_dir = self.project.dir().join(layer_path);
+        let target = layer_dir.join("system.tar");
+        let frozen = FrozenLayer::new(layer.name.clone(), plan.id.clone(), target.clone());
+        if customize::is_up_to_date(self.project, &layer_dir) {
+            info!("layer is up-to-date, skipping build");
+            touch(&target).whatever("unable to update last use of layer")?;
+            self.lock_packages(&layer.name, &layer_dir)?;
+            return Ok(frozen);
         }
-        _ => download(&image_url)?,
-    };
-    if image_path.extension() == Some("xz".as_ref()) {
-        let decompressed_image_path = image_path.with_extension("");
-        if !decompressed_image_path.is_file() {
-            info!("decompressing XZ image");
-            run!(["xz", "-d", "-k", image_path]).whatever("unable to decompress image")?;
+        fs::create_dir_all(&layer_dir).whatever("unable to create layer directory")?;
+        fs::write(
+            layer_dir.join("inputs.json"),
+            serde_json::to_string_pretty(&plan.inputs).unwrap(),
+        )
+        .whatever("unable to write layer inputs")?;
+        if let Some(cache) =
+            LayerCache::from_project(self.project)?.filter(|_| self.use_layer_cache)
+        {
+            if cache.pull(self.project, &plan.id, &layer_dir)? {
+                self.lock_packages(&layer.name, &layer_dir)?;
+                return Ok(frozen);
+            }
         }
-        image_path = decompressed_image_path;
+        let src = match &plan.source {
+            LayerSource::Parent(parent) => Some(self.bake_plan(parent, source_date_epoch)?),
+            _ => None,
+        };
+        let started = jiff::Timestamp::now();
+        let durations = match &plan.source {
+            LayerSource::Url(image) => {
+                extract(self.project, self.arch, image, &target)?;
+                Vec::new()
+            }
+            _ => customize::customize(
+                self.project,
+                self.arch,
+                layer,
+                &plan.jobs,
+                src.as_ref(),
+                &target,
+                layer_path,
+                source_date_epoch,
+                self.debug_on_failure,
+            )?,
+        };
+        let repositories = self.project.repositories()?;
+        LayerProvenance::new(
+            plan,
+            &layer.name,
+            self.arch,
+            &repositories[layer.repo].source,
+            &repositories,
+            &durations,
+            started,
+        )?
+        .write(&layer_dir)?;
+        self.lock_packages(&layer.name, &layer_dir)?;
+        atomic::mark_complete(&layer_dir).whatever("unable to mark layer as complete")?;
+        Ok(frozen)
     }
-    if image_path.extension() == Some("gz".as_ref()) {
-        let decompressed_image_path = image_path.with_extension("");
-        if !decompressed_image_path.is_file() {
-            info!("decompressing GZ image");
-            run!(["gzip", "-d", "-k", image_path]).whatever("unable to decompress image")?;
+
+    /// Record the packages installed in the given layer or check them against the lock.
+    ///
+    /// Without `--locked` or `--update-lock`, the lockfile is left untouched.
+    fn lock_packages(&self, layer: &str, layer_dir: &Path) -> BakeryResult<()> {
+        if self.project.lock_mode() == LockMode::Unlocked {
+            return Ok(());
         }
-        image_path = decompressed_image_path;
-    }
-    if let Some(parent) = layer_path.parent() {
-        if !parent.exists() {
-            fs::create_dir_all(parent).whatever("unable to create layer path")?;
+        // Only customized layers have a size report listing their installed packages.
+        let Some(report) = SizeReport::load(layer_dir)? else {
+            return Ok(());
+        };
+        let packages = report
+            .packages
+            .into_iter()
+            .map(|package| LockedPackage {
+                name: package.name,
+                version: package.version,
+                arch: package.arch,
+                manager: package.manager,
+            })
+            .collect::<Vec<_>>();
+        let mut lockfile = self.project.lockfile();
+        match self.project.lock_mode() {
+            LockMode::Locked => {
+                let Some(lock) = lockfile.layer(layer, self.arch) else {
+                    bail!(
+                        "layer `{layer}` is not locked for `{}`, use `--update-lock`",
+                        self.arch
+                    );
+                };
+                let differences = lock.differences(&packages);
+                if !differences.is_empty() {
+                    for difference in &differences {
+                        error!("{difference}");
+                    }
+                    bail!("packages of layer `{layer}` differ from the lockfile");
+                }
+            }
+            LockMode::Unlocked => {}
+            LockMode::Update => {
+                lockfile.set_layer(LayerLock::new(layer, self.arch, packages));
+                lockfile.write(self.project.dir())?;
+            }
         }
+        Ok(())
     }
+}
+
+/// Local path of the image, if the URL refers to a path within the project.
+fn local_image_path(project: &ProjectRef, image_url: &Url) -> Option<PathBuf> {
+    if !matches!(image_url.scheme(), "file" | "oci" | "oci-archive") {
+        return None;
+    }
+    let mut image_path = project.dir().to_path_buf();
+    image_path.push(image_url.path().trim_start_matches('/'));
+    Some(image_path)
+}
+
+/// Extract the layer from the given image.
+///
+/// Supported are disk images and root filesystem tarballs (`file://` and HTTP URLs),
+/// local root filesystem directories (`file://`), and OCI image layouts (`oci:`) and
+/// archives (`oci-archive:`). For OCI images, a specific image can be selected via the
+/// fragment of the URL, e.g., `oci:images/vendor#v1.0` or
+/// `oci:images/vendor#sha256:<hex>` for a digest reference. If a digest is given, disk
+/// images and tarballs are verified against it before extraction.
+fn extract(
+    project: &ProjectRef,
+    arch: Architecture,
+    image: &ImageSource,
+    layer_path: &Path,
+) -> BakeryResult<()> {
+    let image_url = &image.url;
+    let layer_dir = layer_path.parent().unwrap();
+    fs::create_dir_all(layer_dir).whatever("unable to create layer path")?;
+    atomic::unmark_complete(layer_dir).whatever("unable to reset layer completion marker")?;
+    let partial_layer_path = atomic::partial_path(layer_path);
     let temp_dir = tempdir().whatever("unable to create temporary directory")?;
     let temp_dir_path = temp_dir.path();
     let system_dir = temp_dir_path.join("roots/system");
     let boot_dir = temp_dir_path.join("roots/boot");
     std::fs::create_dir_all(&system_dir).whatever("unable to create system directory")?;
     std::fs::create_dir_all(&boot_dir).whatever("unable to create boot directory")?;
-    if image_path.extension() == Some("tar".as_ref()) {
+    match image_url.scheme() {
+        "oci" => {
+            let layout_dir = local_image_path(project, image_url).unwrap();
+            oci::import_oci(&layout_dir, image_url.fragment(), arch, &system_dir)?;
+        }
+        "oci-archive" => {
+            let archive = local_image_path(project, image_url).unwrap();
+            let layout_dir = tempdir().whatever("unable to create temporary directory")?;
+            info!("extracting OCI archive {archive:?}");
+            run!(["tar", "-x", "-f", &archive, "-C", layout_dir.path()])
+                .whatever("unable to extract OCI archive")?;
+            oci::import_oci(layout_dir.path(), image_url.fragment(), arch, &system_dir)?;
+        }
+        _ => {
+            let image_path = match local_image_path(project, image_url) {
+                Some(image_path) => {
+                    if let Some(digest) = &image.digest {
+                        if image_path.is_dir() {
+                            bail!("digests are not supported for root filesystem directories");
+                        }
+                        digest.verify(&image_path)?;
+                    }
+                    image_path
+                }
+                None => {
+                    let image_path = download(image_url, &image.mirrors)?;
+                    if let Some(digest) = &image.digest {
+                        if let Err(error) = digest.verify(&image_path) {
+                            // Remove the cached file such that it is downloaded again.
+                            fs::remove_file(download_metadata_path(&image_path)).ok();
+                            fs::remove_file(&image_path).ok();
+                            return Err(error);
+                        }
+                    }
+                    image_path
+                }
+            };
+            if image_path.is_dir() {
+                info!("copying root filesystem from {image_path:?}");
+                let mut src = image_path.into_os_string();
+                src.push("/.");
+                run!(["cp", "-a", src, &system_dir]).whatever("unable to copy root filesystem")?;
+            } else {
+                extract_image(&image_path, &image.partitions, temp_dir_path)?;
+            }
+        }
+    }
+    run!([
+        "tar",
+        "-c",
+        "-f",
+        &partial_layer_path,
+        "-C",
+        temp_dir_path,
+        "."
+    ])
+    .whatever("unable to create layer tar file")?;
+    atomic::finish(&partial_layer_path, layer_path).whatever("unable to move layer tar file")?;
+    Ok(())
+}
+
+/// Extract a disk image or root filesystem tarball into the given bundle directory.
+///
+/// Compressed images are decompressed while extracting them. Tarballs are streamed
+/// directly into `tar`. Compressed disk images are decompressed into a sparse temporary
+/// file in the bundle directory, which only contains the imported partitions (see
+/// [`decompress_partitions`]). The file is removed before this function returns.
+fn extract_image(
+    image_path: &Path,
+    partitions: &[ImportPartition],
+    bundle_dir: &Path,
+) -> BakeryResult<()> {
+    let compression = detect_compression(image_path).whatever("unable to read image")?;
+    if let Some(compression) = compression {
+        info!("decompressing {} image", compression.as_str());
+    }
+    let mut image = open_decompressed(image_path).whatever("unable to open image")?;
+    // Tarballs are recognized by the `ustar` magic in the header of the first entry.
+    let mut header = Vec::with_capacity(512);
+    (&mut image)
+        .take(512)
+        .read_to_end(&mut header)
+        .whatever("unable to read image")?;
+    let is_tar = header.get(257..262) == Some(b"ustar".as_slice());
+    let mut image = io::Cursor::new(header).chain(image);
+    let system_dir = bundle_dir.join("roots/system");
+    let boot_dir = bundle_dir.join("roots/boot");
+    if is_tar {
         info!("Copying root filesystem {image_path:?}");
-        run!(["tar", "-x", "-f", &image_path, "-C", system_dir])
-            .whatever("unable to extract root file system")?;
-        run!(["tar", "-c", "-f", &layer_path, "-C", temp_dir_path, "."])
-            .whatever("unable to create layer tar file")?;
+        let mut tar = Command::new("tar")
+            .args(["-x", "-f", "-", "-C"])
+            .arg(&system_dir)
+            .stdin(Stdio::piped())
+            .spawn()
+            .whatever("unable to run `tar`")?;
+        let copied = io::copy(&mut image, tar.stdin.as_mut().unwrap());
+        drop(tar.stdin.take());
+        let status = tar.wait().whatever("unable to run `tar`")?;
+        copied.whatever("unable to extract root file system")?;
+        if !status.success() {
+            bail!("unable to extract root file system, `tar` exited with {status}");
+        }
     } else {
         info!("extracting partitions from disk image");
-        extract_image_partitions(
-            &image_path,
-            &[(1, boot_dir.as_path()), (2, system_dir.as_path())],
-            temp_dir_path,
-        )
-        .whatever("unable to extract partitions from disk image")?;
-        run!(["tar", "-c", "-f", &layer_path, "-C", temp_dir_path, "."])
-            .whatever("unable to create layer tar file")?;
+        let decompressed = match compression {
+            Some(_) => {
+                // Downloaded images are in the shared cache directory, which must not be
+                // littered with temporary files of concurrent or interrupted builds.
+                let decompressed = tempfile::NamedTempFile::new_in(bundle_dir)
+                    .whatever("unable to create temporary file")?;
+                decompress_partitions(&mut image, partitions, decompressed.path())?;
+                Some(decompressed)
+            }
+            None => None,
+        };
+        let disk_image_path = decompressed
+            .as_ref()
+            .map(|decompressed| decompressed.path())
+            .unwrap_or(image_path);
+        let table = read_partition_table(disk_image_path)?;
+        let mut targets = Vec::new();
+        for partition in partitions {
+            let number = select_partition(&table, partition)?;
+            let root_dir = match partition.root.unwrap_or(ImportRoot::System) {
+                ImportRoot::System => &system_dir,
+                ImportRoot::Boot => &boot_dir,
+            };
+            let mut target = root_dir.clone();
+            if let Some(path) = &partition.path {
+                target.push(path.trim_start_matches('/'));
+            }
+            targets.push((number, target));
+        }
+        // Partitions mounted at nested paths must be extracted after their parents.
+        targets.sort_by_key(|(_, target)| target.components().count());
+        for (number, target) in &targets {
+            info!("extracting partition {number} to {target:?}");
+            fs::create_dir_all(target).whatever("unable to create partition directory")?;
+            extract_image_partitions(
+                disk_image_path,
+                &[(
+                    (*number).try_into().whatever("invalid partition number")?,
+                    target.as_path(),
+                )],
+                bundle_dir,
+            )
+            .whatever("unable to extract partition from disk image")?;
+        }
     }
     Ok(())
 }
 
+/// Size of the head of disk images from which partition tables are read.
+///
+/// The head covers MBR and GPT partition tables including all GPT entries.
+const IMAGE_HEAD_SIZE: u64 = 1 << 20;
+
+/// Preliminary size of decompressed disk images while reading their partition table.
+///
+/// The actual size is only known after decompressing the entire image. As the file is
+/// sparse, this does not occupy any disk space.
+const PRELIMINARY_IMAGE_SIZE: u64 = 1 << 40;
+
+/// Decompress a disk image into a sparse file omitting partitions that are not imported.
+///
+/// The partition table is read from the head of the decompressed stream. The contents of
+/// partitions that are not imported are skipped while writing the remaining stream, such
+/// that they do not occupy disk space. Everything else, including partition tables and
+/// the imported partitions, is written at its original offset. Logical partitions are
+/// only known after decompressing their extended partition, hence, extended partitions
+/// are always written entirely.
+fn decompress_partitions(
+    image: &mut dyn Read,
+    partitions: &[ImportPartition],
+    path: &Path,
+) -> BakeryResult<()> {
+    let file = fs::File::create(path).whatever("unable to create decompressed image")?;
+    let mut head = Vec::new();
+    (&mut *image)
+        .take(IMAGE_HEAD_SIZE)
+        .read_to_end(&mut head)
+        .whatever("unable to decompress image")?;
+    file.write_all_at(&head, 0)
+        .whatever("unable to write decompressed image")?;
+    file.set_len(PRELIMINARY_IMAGE_SIZE)
+        .whatever("unable to write decompressed image")?;
+    let table = read_partition_table(path)?;
+    let has_extended = table.partitions.iter().any(SfdiskPartition::is_extended);
+    let mut imported = HashSet::new();
+    for partition in partitions {
+        match select_partition(&table, partition) {
+            Ok(number) => {
+                imported.insert(number);
+            }
+            // The partition may be a logical partition, which is selected later.
+            Err(_) if has_extended => {}
+            Err(error) => return Err(error),
+        }
+    }
+    let mut skipped = Vec::new();
+    for (number, entry) in numbered_partitions(&table)? {
+        if !imported.contains(&number) && !entry.is_extended() {
+            let start = entry.start * table.sectorsize;
+            skipped.push(start..start + entry.size * table.sectorsize);
+        }
+    }
+    let mut position = head.len() as u64;
+    let mut buffer = vec![0; 1 << 20];
+    loop {
+        let read = image
+            .read(&mut buffer)
+            .whatever("unable to decompress image")?;
+        if read == 0 {
+            break;
+        }
+        let end = position + read as u64;
+        let mut offset = position;
+        while offset < end {
+            if let Some(range) = skipped.iter().find(|range| range.contains(&offset)) {
+                offset = range.end.min(end);
+                continue;
+            }
+            let next = skipped
+                .iter()
+                .map(|range| range.start)
+                .filter(|start| *start > offset)
+                .fold(end, u64::min);
+            let chunk = &buffer[(offset - position) as usize..(next - position) as usize];
+            file.write_all_at(chunk, offset)
+                .whatever("unable to write decompressed image")?;
+            offset = next;
+        }
+        position = end;
+    }
+    file.set_len(position)
+        .whatever("unable to write decompressed image")?;
+    Ok(())
+}
+
+/// Partitions imported from disk images by default.
+///
+/// This mapping fits Raspberry Pi OS images.
+fn default_partitions() -> Vec<ImportPartition> {
+    vec![
+        ImportPartition::new()
+            .with_number(Some(1))
+            .with_root(Some(ImportRoot::Boot)),
+        ImportPartition::new()
+            .with_number(Some(2))
+            .with_root(Some(ImportRoot::System)),
+    ]
+}
+
+/// Partition table as reported by `sfdisk --json`.
+#[derive(Debug, Deserialize)]
+struct SfdiskOutput {
+    partitiontable: SfdiskTable,
+}
+
+#[derive(Debug, Deserialize)]
+struct SfdiskTable {
+    device: String,
+    #[serde(default = "default_sector_size")]
+    sectorsize: u64,
+    #[serde(default)]
+    partitions: Vec<SfdiskPartition>,
+}
+
+/// Sector size assumed by `sfdisk` versions which do not report it.
+fn default_sector_size() -> u64 {
+    512
+}
+
+#[derive(Debug, Deserialize)]
+struct SfdiskPartition {
+    node: String,
+    start: u64,
+    size: u64,
+    #[serde(rename = "type")]
+    ty: String,
+    name: Option<String>,
+}
+
+impl SfdiskPartition {
+    /// Indicates whether the partition is an extended MBR partition.
+    fn is_extended(&self) -> bool {
+        ["5", "f", "85"]
+            .iter()
+            .any(|ty| self.ty.eq_ignore_ascii_case(ty))
+    }
+}
+
+/// Read the partition table of the given disk image.
+fn read_partition_table(image_path: &Path) -> BakeryResult<SfdiskTable> {
+    let output = read_str!(ParentEnv, ["sfdisk", "--json", image_path])
+        .whatever("unable to read partition table of disk image")?;
+    let output: SfdiskOutput =
+        serde_json::from_str(&output).whatever("unable to parse partition table of disk image")?;
+    Ok(output.partitiontable)
+}
+
+/// Partitions of the partition table with their numbers.
+fn numbered_partitions(table: &SfdiskTable) -> BakeryResult<Vec<(u32, &SfdiskPartition)>> {
+    let mut numbered = Vec::new();
+    for entry in &table.partitions {
+        // Partition nodes are named after the device with an optional `p` separator.
+        let Some(number) = entry
+            .node
+            .strip_prefix(&table.device)
+            .map(|suffix| suffix.trim_start_matches('p'))
+            .and_then(|suffix| suffix.parse::<u32>().ok())
+        else {
+            bail!("unable to determine number of partition {}", entry.node);
+        };
+        numbered.push((number, entry));
+    }
+    Ok(numbered)
+}
+
+/// Select the number of the given partition from the partition table.
+fn select_partition(table: &SfdiskTable, partition: &ImportPartition) -> BakeryResult<u32> {
+    let numbered = numbered_partitions(table)?;
+    let found = if let Some(number) = partition.number {
+        numbered.iter().find(|(n, _)| *n == number)
+    } else if let Some(label) = &partition.label {
+        numbered
+            .iter()
+            .find(|(_, entry)| entry.name.as_deref() == Some(label.as_str()))
+    } else if let Some(ty) = &partition.ty {
+        numbered
+            .iter()
+            .find(|(_, entry)| entry.ty.eq_ignore_ascii_case(ty))
+    } else {
+        None
+    };
+    let Some((number, _)) = found else {
+        bail!(
+            "unable to find partition (number: {:?}, label: {:?}, type: {:?}) in disk image",
+            partition.number,
+            partition.label,
+            partition.ty
+        );
+    };
+    Ok(*number)
+}
+
 /// Bundle options.
 #[derive(Args, Clone, Debug)]
 pub struct BundleOpts {
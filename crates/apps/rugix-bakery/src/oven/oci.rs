@@ -0,0 +1,459 @@
+//! Export and import of OCI images.
+//!
+//! The exported image consists of a single uncompressed layer with the contents of the
+//! system root. All timestamps are derived from `SOURCE_DATE_EPOCH` such that the
+//! export is reproducible.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use reportify::{bail, ResultExt};
+use serde::Deserialize;
+use serde_json::json;
+use sha2::{Digest, Sha256};
+use tempfile::tempdir;
+use tracing::info;
+use xscript::{read_str, run, ParentEnv, Run};
+
+use crate::config::load_json;
+use crate::config::systems::Architecture;
+use crate::utils::atomic;
+use crate::utils::caching::sha256_file;
+use crate::BakeryResult;
+
+use super::files::resolve_in_root;
+
+/// Media type of OCI image indices.
+const INDEX_MEDIA_TYPE: &str = "application/vnd.oci.image.index.v1+json";
+/// Media type of OCI image manifests.
+const MANIFEST_MEDIA_TYPE: &str = "application/vnd.oci.image.manifest.v1+json";
+/// Media type of OCI image configurations.
+const CONFIG_MEDIA_TYPE: &str = "application/vnd.oci.image.config.v1+json";
+/// Media type of uncompressed OCI image layers.
+const LAYER_MEDIA_TYPE: &str = "application/vnd.oci.image.layer.v1.tar";
+
+/// OCI platform of the given architecture as pair of architecture and variant.
+pub fn oci_platform(arch: Architecture) -> (&'static str, Option<&'static str>) {
+    match arch {
+        Architecture::Amd64 => ("amd64", None),
+        Architecture::Arm64 => ("arm64", Some("v8")),
+        Architecture::Armv7 => ("arm", Some("v7")),
+        Architecture::Armhf => ("arm", Some("v6")),
+        Architecture::Arm => ("arm", Some("v6")),
+    }
+}
+
+/// Export the given system root as an OCI image.
+///
+/// If the output path ends with `.tar`, an OCI archive is created. Otherwise, the output
+/// path is an OCI image layout directory.
+pub fn export_oci(
+    root_dir: &Path,
+    arch: Architecture,
+    name: &str,
+    output: &Path,
+    source_date_epoch: u64,
+) -> BakeryResult<()> {
+    info!("exporting OCI image to {output:?}");
+    let created = jiff::Timestamp::from_second(source_date_epoch as i64)
+        .whatever("invalid source date epoch")?
+        .to_string();
+    let layout_dir = tempdir().whatever("unable to create temporary directory")?;
+    let layout_dir = layout_dir.path();
+    let blobs_dir = layout_dir.join("blobs/sha256");
+    fs::create_dir_all(&blobs_dir).whatever("unable to create blobs directory")?;
+
+    let layer_tar = layout_dir.join("layer.tar");
+    run!([
+        "tar",
+        "--sort=name",
+        "--numeric-owner",
+        "--pax-option=exthdr.name=%d/PaxHeaders/%f,delete=atime,delete=ctime",
+        "--clamp-mtime",
+        format!("--mtime=@{source_date_epoch}"),
+        "-c",
+        "-f",
+        &layer_tar,
+        "-C",
+        root_dir,
+        "."
+    ])
+    .whatever("unable to create image layer")?;
+    let (layer_digest, layer_size) =
+        sha256_file(&layer_tar).whatever("unable to compute digest of image layer")?;
+    fs::rename(&layer_tar, blobs_dir.join(&layer_digest)).whatever("unable to move image layer")?;
+
+    let (architecture, variant) = oci_platform(arch);
+    let mut platform = json!({
+        "architecture": architecture,
+        "os": "linux",
+    });
+    if let Some(variant) = variant {
+        platform["variant"] = json!(variant);
+    }
+
+    let mut config = platform.clone();
+    config["created"] = json!(created);
+    config["config"] = json!({});
+    config["rootfs"] = json!({
+        "type": "layers",
+        "diff_ids": [format!("sha256:{layer_digest}")],
+    });
+    config["history"] = json!([{
+        "created": created,
+        "created_by": format!("rugix-bakery {}", rugix_version::RUGIX_GIT_VERSION),
+    }]);
+    let config = write_blob(&blobs_dir, &config)?;
+
+    let manifest = json!({
+        "schemaVersion": 2,
+        "mediaType": MANIFEST_MEDIA_TYPE,
+        "config": {
+            "mediaType": CONFIG_MEDIA_TYPE,
+            "digest": format!("sha256:{}", config.0),
+            "size": config.1,
+        },
+        "layers": [{
+            "mediaType": LAYER_MEDIA_TYPE,
+            "digest": format!("sha256:{layer_digest}"),
+            "size": layer_size,
+        }],
+        "annotations": {
+            "org.opencontainers.image.created": created,
+        },
+    });
+    let manifest = write_blob(&blobs_dir, &manifest)?;
+
+    let index = json!({
+        "schemaVersion": 2,
+        "mediaType": INDEX_MEDIA_TYPE,
+        "manifests": [{
+            "mediaType": MANIFEST_MEDIA_TYPE,
+            "digest": format!("sha256:{}", manifest.0),
+            "size": manifest.1,
+            "platform": platform,
+            "annotations": {
+                "org.opencontainers.image.ref.name": name,
+            },
+        }],
+    });
+    fs::write(
+        layout_dir.join("index.json"),
+        serde_json::to_vec(&index).unwrap(),
+    )
+    .whatever("unable to write image index")?;
+    fs::write(
+        layout_dir.join("oci-layout"),
+        serde_json::to_vec(&json!({ "imageLayoutVersion": "1.0.0" })).unwrap(),
+    )
+    .whatever("unable to write image layout")?;
+
+    if let Some(parent) = output.parent() {
+        fs::create_dir_all(parent).whatever("unable to create output directory")?;
+    }
+    let partial_output = atomic::partial_path(output);
+    if output.extension() == Some("tar".as_ref()) {
+        run!([
+            "tar",
+            "--sort=name",
+            "--numeric-owner",
+            "--owner=0",
+            "--group=0",
+            format!("--mtime=@{source_date_epoch}"),
+            "-c",
+            "-f",
+            &partial_output,
+            "-C",
+            layout_dir,
+            "index.json",
+            "oci-layout",
+            "blobs"
+        ])
+        .whatever("unable to create OCI archive")?;
+    } else {
+        fs::remove_dir_all(&partial_output).ok();
+        rugix_fs::Copier::new()
+            .copy_dir(layout_dir, &partial_output)
+            .whatever("unable to copy OCI image layout")?;
+        if output.exists() {
+            fs::remove_dir_all(output).whatever("unable to remove previous OCI image")?;
+        }
+    }
+    atomic::finish(&partial_output, output).whatever("unable to move OCI image into place")?;
+    Ok(())
+}
+
+/// Write the given JSON value as a blob and return its digest and size.
+fn write_blob(blobs_dir: &Path, value: &serde_json::Value) -> BakeryResult<(String, u64)> {
+    let bytes = serde_json::to_vec(value).unwrap();
+    let digest = hex::encode(Sha256::digest(&bytes));
+    fs::write(blobs_dir.join(&digest), &bytes).whatever("unable to write blob")?;
+    Ok((digest, bytes.len() as u64))
+}
+
+/// OCI content descriptor.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Descriptor {
+    media_type: String,
+    digest: String,
+    #[serde(default)]
+    platform: Option<Platform>,
+    #[serde(default)]
+    annotations: HashMap<String, String>,
+}
+
+/// OCI platform.
+#[derive(Debug, Deserialize)]
+struct Platform {
+    architecture: String,
+    #[serde(default)]
+    variant: Option<String>,
+}
+
+/// OCI image index.
+#[derive(Debug, Deserialize)]
+struct Index {
+    manifests: Vec<Descriptor>,
+}
+
+/// OCI image manifest.
+#[derive(Debug, Deserialize)]
+struct Manifest {
+    layers: Vec<Descriptor>,
+}
+
+/// Import the image from the given OCI image layout into the given root directory.
+///
+/// The layers of the image are flattened and whiteouts are applied. If the layout
+/// contains multiple images, the image is selected by the given reference and the
+/// platform matching the architecture. The reference is either a reference name or the
+/// digest of an image manifest or index, e.g., `sha256:<hex>`.
+pub fn import_oci(
+    layout_dir: &Path,
+    reference: Option<&str>,
+    arch: Architecture,
+    root_dir: &Path,
+) -> BakeryResult<()> {
+    info!("importing OCI image from {layout_dir:?}");
+    if !layout_dir.join("oci-layout").exists() {
+        bail!("{layout_dir:?} is not an OCI image layout");
+    }
+    let index: Index = load_json(&layout_dir.join("index.json"))?;
+    let mut candidates = index.manifests;
+    if let Some(reference) = reference {
+        let by_digest = reference.starts_with("sha256:");
+        candidates.retain(|descriptor| {
+            if by_digest {
+                descriptor.digest == reference
+            } else {
+                descriptor
+                    .annotations
+                    .get("org.opencontainers.image.ref.name")
+                    .is_some_and(|name| name == reference)
+            }
+        });
+        if candidates.is_empty() {
+            bail!("no image with reference `{reference}` found");
+        }
+    }
+    let manifest = loop {
+        let descriptor = select_manifest(candidates, arch)?;
+        let blob = blob_path(layout_dir, &descriptor.digest)?;
+        verify_blob(&blob, &descriptor.digest)?;
+        if is_index(&descriptor.media_type) {
+            let index: Index = load_json(&blob)?;
+            candidates = index.manifests;
+        } else {
+            break load_json::<Manifest>(&blob)?;
+        }
+    };
+    for layer in &manifest.layers {
+        let blob = blob_path(layout_dir, &layer.digest)?;
+        verify_blob(&blob, &layer.digest)?;
+        apply_whiteouts(&blob, root_dir)?;
+        // GNU tar automatically detects compressed layers.
+        run!([
+            "tar",
+            "--numeric-owner",
+            "--exclude=.wh.*",
+            "-x",
+            "-f",
+            &blob,
+            "-C",
+            root_dir
+        ])
+        .whatever("unable to extract image layer")?;
+    }
+    Ok(())
+}
+
+/// Select the manifest matching the given architecture.
+fn select_manifest(candidates: Vec<Descriptor>, arch: Architecture) -> BakeryResult<Descriptor> {
+    let (architecture, variant) = oci_platform(arch);
+    let mut matching = candidates
+        .into_iter()
+        .filter(|descriptor| match &descriptor.platform {
+            Some(platform) => {
+                platform.architecture == architecture
+                    && (platform.variant.is_none()
+                        || variant.is_none()
+                        || platform.variant.as_deref() == variant)
+            }
+            None => true,
+        })
+        .collect::<Vec<_>>();
+    match matching.len() {
+        0 => bail!("no image for architecture `{arch}` found"),
+        1 => Ok(matching.pop().unwrap()),
+        _ => bail!("multiple images for architecture `{arch}` found, please select one"),
+    }
+}
+
+/// Check whether the media type is an image index.
+fn is_index(media_type: &str) -> bool {
+    media_type == INDEX_MEDIA_TYPE
+        || media_type == "application/vnd.docker.distribution.manifest.list.v2+json"
+}
+
+/// Path of the blob with the given digest.
+fn blob_path(layout_dir: &Path, digest: &str) -> BakeryResult<PathBuf> {
+    let Some((algorithm, encoded)) = digest.split_once(':') else {
+        bail!("invalid digest `{digest}`");
+    };
+    if !encoded.chars().all(|c| c.is_ascii_alphanumeric()) {
+        bail!("invalid digest `{digest}`");
+    }
+    Ok(layout_dir.join("blobs").join(algorithm).join(encoded))
+}
+
+/// Verify the digest of the given blob.
+fn verify_blob(blob: &Path, digest: &str) -> BakeryResult<()> {
+    if let Some(expected) = digest.strip_prefix("sha256:") {
+        let (actual, _) = sha256_file(blob).whatever("unable to compute digest of blob")?;
+        if actual != expected {
+            bail!("digest mismatch of blob {blob:?}");
+        }
+    }
+    Ok(())
+}
+
+/// Apply the whiteouts of the given layer to the root directory.
+///
+/// Whiteouts only affect lower layers, hence, they must be applied before the layer
+/// is extracted.
+fn apply_whiteouts(blob: &Path, root_dir: &Path) -> BakeryResult<()> {
+    let entries =
+        read_str!(ParentEnv, ["tar", "-t", "-f", blob]).whatever("unable to list image layer")?;
+    for entry in entries.lines() {
+        let entry = Path::new(entry);
+        let Some(name) = entry.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        let Some(target) = name.strip_prefix(".wh.") else {
+            continue;
+        };
+        if entry.components().any(|component| {
+            matches!(
+                component,
+                Component::ParentDir | Component::RootDir | Component::Prefix(_)
+            )
+        }) {
+            bail!("invalid path {entry:?} in image layer");
+        }
+        // Symbolic links of lower layers may point anywhere, hence, they must be
+        // resolved within the root. The whiteout itself is never followed.
+        let path = resolve_in_root(root_dir, &Path::new("/").join(entry))?;
+        let parent = path.parent().unwrap_or(root_dir);
+        if target == ".wh..opq" {
+            // Opaque whiteout, remove all contents of the directory.
+            if let Ok(children) = fs::read_dir(parent) {
+                for child in children {
+                    let child = child.whatever("unable to read directory")?;
+                    remove_path(&child.path())?;
+                }
+            }
+        } else {
+            remove_path(&parent.join(target))?;
+        }
+    }
+    Ok(())
+}
+
+/// Remove the file or directory at the given path, if it exists.
+fn remove_path(path: &Path) -> BakeryResult<()> {
+    let Ok(metadata) = fs::symlink_metadata(path) else {
+        return Ok(());
+    };
+    if metadata.is_dir() {
+        fs::remove_dir_all(path)
+    } else {
+        fs::remove_file(path)
+    }
+    .whatever_with(|_| format!("unable to remove {path:?}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::os::unix::fs::symlink;
+    use std::process::Command;
+
+    use super::*;
+
+    #[test]
+    fn test_whiteouts_stay_within_root() {
+        let dir = tempdir().unwrap();
+        let outside = dir.path().join("outside");
+        fs::create_dir_all(outside.join("opaque")).unwrap();
+        fs::write(outside.join("foo"), "").unwrap();
+        fs::write(outside.join("opaque/bar"), "").unwrap();
+        let root = dir.path().join("root");
+        fs::create_dir_all(root.join("usr/lib")).unwrap();
+        fs::write(root.join("usr/lib/foo"), "").unwrap();
+        symlink(&outside, root.join("lib")).unwrap();
+        symlink("/usr/lib", root.join("lib64")).unwrap();
+        let layer = dir.path().join("layer");
+        fs::create_dir_all(layer.join("lib/opaque")).unwrap();
+        fs::create_dir_all(layer.join("lib64")).unwrap();
+        fs::write(layer.join("lib/.wh.foo"), "").unwrap();
+        fs::write(layer.join("lib/opaque/.wh..wh..opq"), "").unwrap();
+        fs::write(layer.join("lib64/.wh.foo"), "").unwrap();
+        let blob = dir.path().join("layer.tar");
+        let status = Command::new("tar")
+            .arg("-c")
+            .arg("-f")
+            .arg(&blob)
+            .arg("-C")
+            .arg(&layer)
+            .args(["lib", "lib64"])
+            .status()
+            .unwrap();
+        assert!(status.success());
+        apply_whiteouts(&blob, &root).unwrap();
+        assert!(outside.join("foo").exists());
+        assert!(outside.join("opaque/bar").exists());
+        assert!(!root.join("usr/lib/foo").exists());
+    }
+
+    #[test]
+    fn test_import_by_digest() {
+        let dir = tempdir().unwrap();
+        let root = dir.path().join("root");
+        fs::create_dir_all(root.join("etc")).unwrap();
+        fs::write(root.join("etc/hostname"), "rugix\n").unwrap();
+        let image = dir.path().join("image");
+        export_oci(&root, Architecture::Arm64, "rugix", &image, 0).unwrap();
+        let index: Index = load_json(&image.join("index.json")).unwrap();
+        let digest = &index.manifests[0].digest;
+        let imported = dir.path().join("imported");
+        fs::create_dir_all(&imported).unwrap();
+        import_oci(&image, Some(digest), Architecture::Arm64, &imported).unwrap();
+        assert_eq!(
+            fs::read_to_string(imported.join("etc/hostname")).unwrap(),
+            "rugix\n"
+        );
+        let unknown = format!("sha256:{}", "0".repeat(64));
+        assert!(import_oci(&image, Some(&unknown), Architecture::Arm64, &imported).is_err());
+    }
+}
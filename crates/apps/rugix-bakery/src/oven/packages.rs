@@ -0,0 +1,177 @@
+//! Queries of the package databases of root filesystems.
+//!
+//! Supported are the databases of dpkg (apt), apk, opkg, pacman, and rpm (dnf).
+
+use std::fs;
+use std::path::Path;
+
+use reportify::ResultExt;
+use serde::{Deserialize, Serialize};
+use xscript::{read_str, ParentEnv, Run};
+
+use crate::project::recipes::PackageManager;
+use crate::BakeryResult;
+
+/// Package installed in a root filesystem.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InstalledPackage {
+    /// Name of the package.
+    pub name: String,
+    /// Installed version of the package.
+    pub version: String,
+    /// Architecture of the package.
+    #[serde(default)]
+    pub arch: String,
+    /// Installed size of the package in bytes.
+    pub size: u64,
+    /// Package manager which installed the package.
+    pub manager: PackageManager,
+}
+
+/// Query the package databases of the given root for installed packages.
+///
+/// Roots without a package database have no installed packages.
+pub fn installed_packages(root: &Path) -> BakeryResult<Vec<InstalledPackage>> {
+    let mut packages = Vec::new();
+    // dpkg gives installed sizes in KiB while opkg gives them in bytes.
+    for (status, manager, unit) in [
+        ("var/lib/dpkg/status", PackageManager::Apt, 1024),
+        ("usr/lib/opkg/status", PackageManager::Opkg, 1),
+        ("var/lib/opkg/status", PackageManager::Opkg, 1),
+    ] {
+        let status = root.join(status);
+        if status.exists() {
+            packages.extend(status_packages(&status, manager, unit)?);
+        }
+    }
+    let apk_installed = root.join("lib/apk/db/installed");
+    if apk_installed.exists() {
+        let installed =
+            fs::read_to_string(&apk_installed).whatever("unable to read apk database")?;
+        for stanza in stanzas(&installed) {
+            let field = |name: &str| {
+                stanza
+                    .iter()
+                    .find_map(|line| line.strip_prefix(name)?.strip_prefix(':'))
+            };
+            let Some(name) = field("P") else {
+                continue;
+            };
+            packages.push(InstalledPackage {
+                name: name.to_owned(),
+                version: field("V").unwrap_or_default().to_owned(),
+                arch: field("A").unwrap_or_default().to_owned(),
+                size: field("I")
+                    .and_then(|size| size.parse().ok())
+                    .unwrap_or_default(),
+                manager: PackageManager::Apk,
+            });
+        }
+    }
+    let pacman_local = root.join("var/lib/pacman/local");
+    if pacman_local.exists() {
+        for entry in fs::read_dir(&pacman_local).whatever("unable to read pacman database")? {
+            let desc_path = entry
+                .whatever("unable to read pacman database")?
+                .path()
+                .join("desc");
+            if !desc_path.exists() {
+                continue;
+            }
+            let desc = fs::read_to_string(&desc_path).whatever("unable to read pacman database")?;
+            // Each section consists of a `%NAME%` header followed by the values.
+            let field = |name: &str| {
+                stanzas(&desc)
+                    .find(|section| section.first() == Some(&name))
+                    .and_then(|section| section.get(1).copied())
+            };
+            let Some(name) = field("%NAME%") else {
+                continue;
+            };
+            packages.push(InstalledPackage {
+                name: name.to_owned(),
+                version: field("%VERSION%").unwrap_or_default().to_owned(),
+                arch: field("%ARCH%").unwrap_or_default().to_owned(),
+                size: field("%SIZE%")
+                    .and_then(|size| size.parse().ok())
+                    .unwrap_or_default(),
+                manager: PackageManager::Pacman,
+            });
+        }
+    }
+    if root.join("usr/lib/sysimage/rpm").exists() || root.join("var/lib/rpm").exists() {
+        // The RPM database is not a plain text file, hence, we query it with `rpm`.
+        let installed = read_str!(
+            ParentEnv,
+            [
+                "chroot",
+                root,
+                "rpm",
+                "-qa",
+                "--queryformat",
+                "%{NAME}\\t%{VERSION}-%{RELEASE}\\t%{ARCH}\\t%{SIZE}\\n"
+            ]
+        )
+        .whatever("unable to query rpm database")?;
+        for line in installed.lines() {
+            let mut fields = line.split('\t');
+            let (Some(name), Some(version), Some(arch), Some(size)) =
+                (fields.next(), fields.next(), fields.next(), fields.next())
+            else {
+                continue;
+            };
+            packages.push(InstalledPackage {
+                name: name.to_owned(),
+                version: version.to_owned(),
+                arch: arch.to_owned(),
+                size: size.parse().unwrap_or_default(),
+                manager: PackageManager::Dnf,
+            });
+        }
+    }
+    Ok(packages)
+}
+
+/// Read the installed packages from a dpkg-style status file.
+fn status_packages(
+    path: &Path,
+    manager: PackageManager,
+    unit: u64,
+) -> BakeryResult<Vec<InstalledPackage>> {
+    let status = fs::read_to_string(path)
+        .whatever_with(|_| format!("unable to read package status {path:?}"))?;
+    let mut packages = Vec::new();
+    for stanza in stanzas(&status) {
+        let field = |name: &str| {
+            stanza
+                .iter()
+                .find_map(|line| line.strip_prefix(name)?.strip_prefix(':'))
+                .map(str::trim)
+        };
+        if !field("Status").is_some_and(|status| status.ends_with(" installed")) {
+            continue;
+        }
+        let Some(name) = field("Package") else {
+            continue;
+        };
+        packages.push(InstalledPackage {
+            name: name.to_owned(),
+            version: field("Version").unwrap_or_default().to_owned(),
+            arch: field("Architecture").unwrap_or_default().to_owned(),
+            size: field("Installed-Size")
+                .and_then(|size| size.parse::<u64>().ok())
+                .unwrap_or_default()
+                * unit,
+            manager,
+        });
+    }
+    Ok(packages)
+}
+
+/// Split a package database into its stanzas separated by empty lines.
+fn stanzas(database: &str) -> impl Iterator<Item = Vec<&str>> {
+    database
+        .split("\n\n")
+        .map(|stanza| stanza.lines().collect::<Vec<_>>())
+        .filter(|stanza| !stanza.is_empty())
+}
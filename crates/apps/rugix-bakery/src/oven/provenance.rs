@@ -0,0 +1,209 @@
+//! Provenance of baked layers.
+//!
+//! Every layer directory contains a `provenance.json` file recording how the layer has
+//! been baked, i.e., the recipes with their parameters and step files, the repositories
+//! they came from, the parent layer or base image, and when the layer has been baked.
+//! Systems embed the provenance of all layers they have been built from.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use reportify::ResultExt;
+use serde::{Deserialize, Serialize};
+
+use crate::config::load_json;
+use crate::config::repositories::SourceConfig;
+use crate::config::systems::Architecture;
+use crate::project::repositories::{ProjectRepositories, Source};
+use crate::utils::caching::sha256_file;
+use crate::BakeryResult;
+
+use super::customize::RecipeJob;
+use super::{LayerPlan, LayerSource};
+
+/// Name of the provenance file in layer and system output directories.
+pub const PROVENANCE_FILE: &str = "provenance.json";
+
+/// Provenance of a layer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LayerProvenance {
+    /// Id of the layer.
+    pub id: String,
+    /// Name of the layer.
+    pub layer: String,
+    /// Architecture of the layer.
+    pub arch: String,
+    /// Version of Rugix Bakery which baked the layer.
+    pub bakery_version: String,
+    /// Repository of the layer.
+    pub repository: RepositoryProvenance,
+    /// Id of the parent layer, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<String>,
+    /// Image the layer has been extracted from, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image: Option<ImageProvenance>,
+    /// Recipes applied to the layer in the order of their application.
+    pub recipes: Vec<RecipeProvenance>,
+    /// Time when baking the layer started.
+    pub started: jiff::Timestamp,
+    /// Time when baking the layer finished.
+    pub finished: jiff::Timestamp,
+}
+
+impl LayerProvenance {
+    /// Record the provenance of the layer baked according to the given plan.
+    ///
+    /// The durations of the recipes must be given in the order of the plan's jobs.
+    /// Recipes restored from a checkpoint have no duration.
+    pub fn new(
+        plan: &LayerPlan,
+        layer: &str,
+        arch: Architecture,
+        repository: &Source,
+        repositories: &ProjectRepositories,
+        durations: &[Option<Duration>],
+        started: jiff::Timestamp,
+    ) -> BakeryResult<Self> {
+        let (parent, image) = match &plan.source {
+            LayerSource::Url(image) => (
+                None,
+                Some(ImageProvenance {
+                    url: image.url.to_string(),
+                    digest: image.digest.as_ref().map(ToString::to_string),
+                }),
+            ),
+            LayerSource::Parent(parent) => (Some(parent.id.clone()), None),
+            LayerSource::Root => (None, None),
+        };
+        let recipes = plan
+            .jobs
+            .iter()
+            .enumerate()
+            .map(|(idx, job)| {
+                RecipeProvenance::new(job, repositories, durations.get(idx).copied().flatten())
+            })
+            .collect::<BakeryResult<_>>()?;
+        Ok(Self {
+            id: plan.id.clone(),
+            layer: layer.to_owned(),
+            arch: arch.as_str().to_owned(),
+            bakery_version: rugix_version::RUGIX_GIT_VERSION.to_owned(),
+            repository: RepositoryProvenance::new(repository),
+            parent,
+            image,
+            recipes,
+            started,
+            finished: jiff::Timestamp::now(),
+        })
+    }
+
+    /// Write the provenance into the given layer directory.
+    pub fn write(&self, layer_dir: &Path) -> BakeryResult<()> {
+        fs::write(
+            layer_dir.join(PROVENANCE_FILE),
+            serde_json::to_string_pretty(self).unwrap(),
+        )
+        .whatever("unable to write layer provenance")
+    }
+}
+
+/// Provenance of a repository.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepositoryProvenance {
+    /// Id of the repository's source.
+    pub source: String,
+    /// Git URL or path of the repository.
+    pub location: String,
+    /// Commit which has been checked out, if the repository is a Git repository.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit: Option<String>,
+}
+
+impl RepositoryProvenance {
+    fn new(source: &Source) -> Self {
+        let location = match &source.config {
+            SourceConfig::Git(config) => config.url.clone(),
+            SourceConfig::Path(config) => config.path.clone(),
+        };
+        Self {
+            source: source.id.as_str().to_owned(),
+            location,
+            commit: source.commit.clone(),
+        }
+    }
+}
+
+/// Provenance of a base image.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImageProvenance {
+    /// URL of the image.
+    pub url: String,
+    /// Expected digest of the image, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub digest: Option<String>,
+}
+
+/// Provenance of an applied recipe.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecipeProvenance {
+    /// Name of the recipe.
+    pub name: String,
+    /// Repository of the recipe.
+    pub repository: RepositoryProvenance,
+    /// Digest of the recipe's files.
+    pub digest: String,
+    /// Parameters of the recipe.
+    pub parameters: BTreeMap<String, String>,
+    /// SHA256 digests of the recipe's step files.
+    pub steps: BTreeMap<String, String>,
+    /// Time it took to apply the recipe in seconds, if it has been applied.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration: Option<f64>,
+}
+
+impl RecipeProvenance {
+    fn new(
+        job: &RecipeJob,
+        repositories: &ProjectRepositories,
+        duration: Option<Duration>,
+    ) -> BakeryResult<Self> {
+        let recipe = &job.recipe;
+        let mut steps = BTreeMap::new();
+        for step in &recipe.steps {
+            let path = recipe.path.join("steps").join(&step.filename);
+            let (sha256, _) = sha256_file(&path)
+                .whatever_with(|_| format!("unable to compute digest of {path:?}"))?;
+            steps.insert(step.filename.clone(), sha256);
+        }
+        Ok(Self {
+            name: recipe.name.to_string(),
+            repository: RepositoryProvenance::new(&repositories[recipe.repository].source),
+            digest: recipe.digest.clone(),
+            parameters: job.redacted_parameters(),
+            steps,
+            duration: duration.map(|duration| duration.as_secs_f64()),
+        })
+    }
+}
+
+/// Load the provenance of the given layer directory and all its ancestors.
+///
+/// The provenance of the layer comes first, followed by the provenance of its parent.
+/// Ancestors without provenance end the chain.
+pub fn load_chain(layer_dir: &Path) -> BakeryResult<Vec<LayerProvenance>> {
+    let mut chain = Vec::new();
+    let mut current = layer_dir.to_path_buf();
+    while current.join(PROVENANCE_FILE).exists() {
+        let provenance = load_json::<LayerProvenance>(&current.join(PROVENANCE_FILE))?;
+        let parent = provenance.parent.clone();
+        chain.push(provenance);
+        let Some(parent) = parent else {
+            break;
+        };
+        current = layer_dir.parent().unwrap().join(parent);
+    }
+    Ok(chain)
+}
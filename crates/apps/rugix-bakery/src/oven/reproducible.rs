@@ -0,0 +1,205 @@
+//! Verification of reproducible builds.
+//!
+//! A system is baked twice into separate build directories and the resulting layer tars,
+//! filesystem images, and system images are compared. For artifacts which differ, their
+//! contents are extracted and compared file by file.
+
+use std::fs;
+use std::io::{self, BufReader, Read};
+use std::path::Path;
+
+use reportify::{bail, ResultExt};
+use tempfile::tempdir;
+use tracing::info;
+use xscript::{read_str, run, ParentEnv, Run};
+
+use crate::oven::system::{make_system, ReleaseInfo, TargetSystem};
+use crate::oven::LayerBakery;
+use crate::project::ProjectRef;
+use crate::utils::caching::sha256_file;
+use crate::utils::fsdiff::{self, FileChange};
+use crate::BakeryResult;
+
+/// Result of comparing an artifact of both builds.
+#[derive(Debug)]
+pub struct ArtifactComparison {
+    /// Name of the artifact.
+    pub name: String,
+    /// Outcome of the comparison.
+    pub outcome: Outcome,
+}
+
+/// Outcome of comparing an artifact of both builds.
+#[derive(Debug)]
+pub enum Outcome {
+    /// The artifact is bit-by-bit identical.
+    Identical,
+    /// The artifact differs in the given files.
+    Differs(Vec<FileChange>),
+    /// The artifact differs, starting at the given byte offset.
+    DiffersAt(u64),
+}
+
+/// Bake the given system with both projects and compare the results.
+///
+/// Both projects must refer to the same project directory with separate build
+/// directories.
+pub fn verify_reproducible(
+    first: &ProjectRef,
+    second: &ProjectRef,
+    system: &str,
+    source_date_epoch: u64,
+) -> BakeryResult<Vec<ArtifactComparison>> {
+    let system_config = first.config().resolve_system_config(system)?;
+    let mut outputs = Vec::new();
+    for (idx, project) in [first, second].into_iter().enumerate() {
+        info!("baking system `{system}` ({}/2)", idx + 1);
+        // Layers must not be pulled from the layer cache as they would be identical.
+        let frozen = LayerBakery::new(project, system_config.architecture)
+            .with_target(system_config.target.clone())
+            .with_system(TargetSystem {
+                name: system.to_owned(),
+                release: ReleaseInfo::default(),
+            })
+            .with_layer_cache(false)
+            .bake_root(&system_config.layer, source_date_epoch)?;
+        let output = project.dir().join(project.build_dir()).join("system");
+        make_system(
+            system_config,
+            &ReleaseInfo::default(),
+            system,
+            &frozen,
+            &output,
+            source_date_epoch,
+        )?;
+        outputs.push(output);
+    }
+    let library = first.library()?;
+    let mut comparisons = Vec::new();
+    let plan = LayerBakery::new(first, system_config.architecture)
+        .with_target(system_config.target.clone())
+        .with_system(TargetSystem {
+            name: system.to_owned(),
+            release: ReleaseInfo::default(),
+        })
+        .plan_root(&system_config.layer)?;
+    for plan in plan.ancestors() {
+        let first_tar = first
+            .dir()
+            .join(first.layers_dir())
+            .join(&plan.id)
+            .join("system.tar");
+        let second_tar = second
+            .dir()
+            .join(second.layers_dir())
+            .join(&plan.id)
+            .join("system.tar");
+        comparisons.push(compare_artifact(
+            format!("layer `{}` ({})", library.layers[plan.layer].name, plan.id),
+            &first_tar,
+            &second_tar,
+            Some(extract_tar),
+        )?);
+    }
+    let filesystems_dir = outputs[0].join("filesystems");
+    if filesystems_dir.exists() {
+        let mut images = fs::read_dir(&filesystems_dir)
+            .whatever("unable to read filesystems directory")?
+            .map(|entry| entry.map(|entry| entry.file_name()))
+            .collect::<Result<Vec<_>, _>>()
+            .whatever("unable to read filesystems directory")?;
+        images.sort();
+        for image in images {
+            if !image.to_string_lossy().ends_with(".img") {
+                continue;
+            }
+            comparisons.push(compare_artifact(
+                format!("filesystems/{}", image.to_string_lossy()),
+                &filesystems_dir.join(&image),
+                &outputs[1].join("filesystems").join(&image),
+                Some(extract_filesystem),
+            )?);
+        }
+    }
+    comparisons.push(compare_artifact(
+        "system.img".to_owned(),
+        &outputs[0].join("system.img"),
+        &outputs[1].join("system.img"),
+        None,
+    )?);
+    Ok(comparisons)
+}
+
+/// Function extracting the contents of an artifact into a directory.
+type Extract = fn(&Path, &Path) -> BakeryResult<()>;
+
+/// Compare the given artifact of both builds.
+fn compare_artifact(
+    name: String,
+    first: &Path,
+    second: &Path,
+    extract: Option<Extract>,
+) -> BakeryResult<ArtifactComparison> {
+    let first_digest = sha256_file(first).whatever_with(|_| format!("unable to read {first:?}"))?;
+    let second_digest =
+        sha256_file(second).whatever_with(|_| format!("unable to read {second:?}"))?;
+    let outcome = if first_digest == second_digest {
+        Outcome::Identical
+    } else if let Some(extract) = extract {
+        info!("comparing contents of {name}");
+        let first_dir = tempdir().whatever("unable to create temporary directory")?;
+        let second_dir = tempdir().whatever("unable to create temporary directory")?;
+        extract(first, first_dir.path())?;
+        extract(second, second_dir.path())?;
+        let first_tree = fsdiff::scan(first_dir.path()).whatever("unable to scan contents")?;
+        let second_tree = fsdiff::scan(second_dir.path()).whatever("unable to scan contents")?;
+        Outcome::Differs(fsdiff::diff(&first_tree, &second_tree, true))
+    } else {
+        Outcome::DiffersAt(first_difference(first, second).whatever("unable to compare files")?)
+    };
+    Ok(ArtifactComparison { name, outcome })
+}
+
+/// Extract the given tar file into the given directory.
+fn extract_tar(tar: &Path, dir: &Path) -> BakeryResult<()> {
+    run!(["tar", "--numeric-owner", "-x", "-f", tar, "-C", dir])
+        .whatever("unable to extract layer")?;
+    Ok(())
+}
+
+/// Extract the contents of the given filesystem image into the given directory.
+fn extract_filesystem(image: &Path, dir: &Path) -> BakeryResult<()> {
+    let fs_type = read_str!(ParentEnv, ["blkid", "-o", "value", "-s", "TYPE", image])
+        .whatever("unable to determine filesystem type")?;
+    match fs_type.trim() {
+        "ext4" | "ext3" | "ext2" => {
+            let request = format!("rdump / {}", dir.display());
+            run!(["debugfs", "-R", request, image])
+                .whatever("unable to extract ext4 filesystem")?;
+        }
+        "vfat" => {
+            run!(["mcopy", "-s", "-p", "-m", "-n", "-i", image, "::*", dir])
+                .whatever("unable to extract FAT filesystem")?;
+        }
+        "squashfs" => {
+            let target = dir.join("squashfs-root");
+            run!(["unsquashfs", "-d", &target, image])
+                .whatever("unable to extract squashfs filesystem")?;
+        }
+        fs_type => bail!("unsupported filesystem `{fs_type}`"),
+    }
+    Ok(())
+}
+
+/// Find the offset of the first byte which differs between the given files.
+fn first_difference(first: &Path, second: &Path) -> io::Result<u64> {
+    let mut first = BufReader::new(fs::File::open(first)?).bytes();
+    let mut second = BufReader::new(fs::File::open(second)?).bytes();
+    let mut offset = 0;
+    loop {
+        match (first.next().transpose()?, second.next().transpose()?) {
+            (Some(a), Some(b)) if a == b => offset += 1,
+            _ => return Ok(offset),
+        }
+    }
+}
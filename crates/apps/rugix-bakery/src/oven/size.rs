@@ -0,0 +1,143 @@
+//! Attribution of the size of layers to recipes, packages, and directories.
+//!
+//! When customizing a layer, the disk usage of the root is measured before and after
+//! each recipe. Together with the installed packages and the largest directories of the
+//! final root, the deltas are recorded in a `size-report.json` file in the layer
+//! directory. Systems embed the report of their root layer.
+
+use std::path::{Path, PathBuf};
+use std::{fs, io};
+
+use reportify::ResultExt;
+use serde::{Deserialize, Serialize};
+
+use crate::config::load_json;
+use crate::BakeryResult;
+
+use super::packages::{installed_packages, InstalledPackage};
+
+/// Name of the size report file in layer and system output directories.
+pub const SIZE_REPORT_FILE: &str = "size-report.json";
+
+/// Maximal depth of directories recorded in a size report.
+const MAX_DIRECTORY_DEPTH: usize = 3;
+
+/// Maximal number of directories recorded in a size report.
+const MAX_DIRECTORIES: usize = 50;
+
+/// Size report of a layer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SizeReport {
+    /// Name of the layer.
+    pub layer: String,
+    /// Total size of the layer's root in bytes.
+    pub total_size: u64,
+    /// Recipes applied to the root, including those of ancestor layers.
+    pub recipes: Vec<RecipeSize>,
+    /// Packages installed in the root.
+    pub packages: Vec<InstalledPackage>,
+    /// Largest directories of the root.
+    pub directories: Vec<DirectorySize>,
+}
+
+impl SizeReport {
+    /// Create the size report for the given root.
+    pub fn new(layer: &str, recipes: Vec<RecipeSize>, root: &Path) -> BakeryResult<Self> {
+        let mut directories = Vec::new();
+        let total_size = walk(root, Path::new(""), Some(&mut directories))
+            .whatever("unable to compute disk usage of root")?;
+        directories.sort_by(|a, b| b.size.cmp(&a.size));
+        directories.truncate(MAX_DIRECTORIES);
+        let mut packages = installed_packages(root)?;
+        packages.sort_by(|a, b| b.size.cmp(&a.size));
+        Ok(Self {
+            layer: layer.to_owned(),
+            total_size,
+            recipes,
+            packages,
+            directories,
+        })
+    }
+
+    /// Load the size report of the given layer or system directory, if there is one.
+    pub fn load(dir: &Path) -> BakeryResult<Option<Self>> {
+        let path = dir.join(SIZE_REPORT_FILE);
+        if path.exists() {
+            load_json(&path).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Write the size report into the given layer or system directory.
+    pub fn write(&self, dir: &Path) -> BakeryResult<()> {
+        fs::write(
+            dir.join(SIZE_REPORT_FILE),
+            serde_json::to_string_pretty(self).unwrap(),
+        )
+        .whatever("unable to write size report")
+    }
+}
+
+/// Size contributed by a recipe.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecipeSize {
+    /// Name of the layer the recipe has been applied to.
+    pub layer: String,
+    /// Name of the recipe.
+    pub recipe: String,
+    /// Change of the root's disk usage in bytes.
+    ///
+    /// Recipes restored from a checkpoint have no size delta.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size_delta: Option<i64>,
+}
+
+/// Size of a directory.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DirectorySize {
+    /// Path of the directory relative to the root.
+    pub path: PathBuf,
+    /// Size of the directory including its contents in bytes.
+    pub size: u64,
+}
+
+/// Size of the given file or directory in bytes.
+///
+/// Paths which do not exist have no size.
+pub fn disk_usage(path: &Path) -> io::Result<u64> {
+    match walk(path, Path::new(""), None) {
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(0),
+        result => result,
+    }
+}
+
+/// Compute the size of the given path while recording the sizes of directories.
+fn walk(
+    path: &Path,
+    relative: &Path,
+    mut directories: Option<&mut Vec<DirectorySize>>,
+) -> io::Result<u64> {
+    let metadata = fs::symlink_metadata(path)?;
+    let mut size = metadata.len();
+    if metadata.is_dir() {
+        for entry in fs::read_dir(path)? {
+            let entry = entry?;
+            size += walk(
+                &entry.path(),
+                &relative.join(entry.file_name()),
+                directories.as_deref_mut(),
+            )?;
+        }
+        let depth = relative.components().count();
+        if let Some(directories) = directories {
+            if depth > 0 && depth <= MAX_DIRECTORY_DEPTH {
+                directories.push(DirectorySize {
+                    path: relative.to_path_buf(),
+                    size,
+                });
+            }
+        }
+    }
+    Ok(size)
+}
@@ -23,11 +23,13 @@ use rugix_common::{grub_patch_env, rpi_patch_boot};
 use crate::config::images::{Filesystem, ImageLayout};
 use crate::config::load_json;
 use crate::config::systems::{SystemConfig, Target};
-use crate::oven::targets;
+use crate::oven::size::SizeReport;
 use crate::oven::targets::generic_grub_efi::initialize_grub;
 use crate::oven::targets::rpi_tryboot::initialize_tryboot;
 use crate::oven::targets::rpi_uboot::initialize_uboot;
-use crate::utils::caching::mtime;
+use crate::oven::{provenance, targets};
+use crate::utils::atomic;
+use crate::utils::caching::Hasher;
 use crate::BakeryResult;
 
 use super::layer::FrozenLayer;
@@ -40,6 +42,15 @@ pub struct ReleaseInfo {
     pub system_version: Option<String>,
 }
 
+/// System for which layers are baked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TargetSystem {
+    /// Name of the system.
+    pub name: String,
+    /// Release information of the system.
+    pub release: ReleaseInfo,
+}
+
 pub fn make_system(
     config: &SystemConfig,
     release_info: &ReleaseInfo,
@@ -48,34 +59,37 @@ pub fn make_system(
     out: &Path,
     source_date_epoch: u64,
 ) -> BakeryResult<()> {
-    let system_build_input = out.join("system-build-input.json");
-    let system_build_info = out.join("system-build-info.json");
-    if system_build_info.exists() {
-        let system_mtime = mtime(&system_build_info).whatever("unable to get system mtime")?;
-        let layer_mtime = frozen.last_modified()?;
-        if layer_mtime < system_mtime {
-            info!("system is newer than layer");
-            let system_info = load_json::<SystemBuildInput>(&system_build_info)?;
-            if &system_info.release == release_info {
-                info!("release info has not changed, skipping build");
+    let mut config_digest = Hasher::new();
+    config_digest.push(
+        "config",
+        serde_json::to_string(config).whatever("unable to serialize system configuration")?,
+    );
+    let build_input = SystemBuildInput {
+        layer: frozen.id().to_owned(),
+        release: release_info.clone(),
+        config: config_digest.finalize(),
+    };
+    if atomic::is_complete(out) {
+        match load_json::<SystemBuildInput>(&out.join("system-build-input.json")) {
+            Ok(previous) if previous == build_input => {
+                info!("layer, release info, and configuration have not changed, skipping build");
                 return Ok(());
             }
-        } else {
-            info!("layer is newer than system");
+            _ => {
+                info!("inputs of the system have changed");
+            }
         }
     }
 
-    std::fs::remove_dir_all(out).ok();
-    std::fs::create_dir_all(out).ok();
-
-    std::fs::write(
-        &system_build_input,
-        serde_json::to_string_pretty(&SystemBuildInput {
-            release: release_info.clone(),
-        })
-        .unwrap(),
-    )
-    .whatever("unable to write system info")?;
+    // The system is built in a temporary directory which replaces the output
+    // directory once the build is complete.
+    let final_out = out;
+    let partial_out = atomic::partial_path(final_out);
+    std::fs::remove_dir_all(&partial_out).ok();
+    std::fs::create_dir_all(&partial_out).whatever("unable to create output directory")?;
+    let out = partial_out.as_path();
+    let system_build_input = out.join("system-build-input.json");
+    let system_build_info = out.join("system-build-info.json");
 
     let layer = frozen.unfreeze()?;
     let layer_path = layer.path();
@@ -90,38 +104,8 @@ pub fn make_system(
     let system_dir = layer_path.join("roots/system");
     fs::create_dir_all(&system_dir).whatever("unable to create system directory")?;
 
-    info!("writing `/etc/rugix/system-build-info.json`");
-    let system_info_path = system_dir.join("etc/rugix/system-build-info.json");
-    fs::create_dir_all(system_info_path.parent().unwrap())
-        .whatever("unable to create `/etc/rugix`")?;
-
-    let time_version = jiff::Timestamp::from_second(source_date_epoch as i64)
-        .unwrap()
-        .strftime("%Y%m%d%H%M%S")
-        .to_string();
-    let release_version = release_info
-        .system_version
-        .as_deref()
-        .unwrap_or(&time_version);
-    let release_id = release_info
-        .system_id
-        .clone()
-        .unwrap_or_else(|| format!("{system_name}@{release_version}"));
-    info!("RELEASE_ID=\"{release_id}\", RELEASE_VERSION=\"{release_version}\"");
-
-    let system_info = SystemInfo {
-        name: system_name.to_owned(),
-        release: SystemReleaseInfo {
-            id: release_id,
-            version: release_version.to_owned(),
-        },
-    };
-
-    std::fs::write(
-        &system_info_path,
-        &serde_json::to_string_pretty(&system_info).unwrap(),
-    )
-    .whatever("unable to write `/etc/rugix/system-build-info.json`")?;
+    let system_info = write_system_info(&system_dir, system_name, release_info, source_date_epoch)?;
+    let release_version = system_info.release.version.as_str();
 
     info!("Generating SBOM");
     run!([
@@ -370,12 +354,82 @@ pub fn make_system(
     )
     .whatever("unable to write `system-build-info.json`")?;
 
+    std::fs::write(
+        &system_build_input,
+        serde_json::to_string_pretty(&build_input).unwrap(),
+    )
+    .whatever("unable to write system info")?;
+
+    // Embed the provenance of the layer and all its ancestors as well as its size report.
+    let provenance = provenance::load_chain(frozen.path().parent().unwrap())?;
+    std::fs::write(
+        out.join(provenance::PROVENANCE_FILE),
+        serde_json::to_string_pretty(&provenance).unwrap(),
+    )
+    .whatever("unable to write system provenance")?;
+    if let Some(report) = SizeReport::load(frozen.path().parent().unwrap())? {
+        report.write(out)?;
+    }
+
+    atomic::mark_complete(out).whatever("unable to mark system as complete")?;
+    if final_out.exists() {
+        std::fs::remove_dir_all(final_out).whatever("unable to remove previous system")?;
+    }
+    atomic::finish(out, final_out).whatever("unable to move system into place")?;
+
     Ok(())
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Write `/etc/rugix/system-build-info.json` into the given system root.
+pub fn write_system_info(
+    system_dir: &Path,
+    system_name: &str,
+    release_info: &ReleaseInfo,
+    source_date_epoch: u64,
+) -> BakeryResult<SystemInfo> {
+    info!("writing `/etc/rugix/system-build-info.json`");
+    let system_info_path = system_dir.join("etc/rugix/system-build-info.json");
+    fs::create_dir_all(system_info_path.parent().unwrap())
+        .whatever("unable to create `/etc/rugix`")?;
+
+    let time_version = jiff::Timestamp::from_second(source_date_epoch as i64)
+        .unwrap()
+        .strftime("%Y%m%d%H%M%S")
+        .to_string();
+    let release_version = release_info
+        .system_version
+        .as_deref()
+        .unwrap_or(&time_version);
+    let release_id = release_info
+        .system_id
+        .clone()
+        .unwrap_or_else(|| format!("{system_name}@{release_version}"));
+    info!("RELEASE_ID=\"{release_id}\", RELEASE_VERSION=\"{release_version}\"");
+
+    let system_info = SystemInfo {
+        name: system_name.to_owned(),
+        release: SystemReleaseInfo {
+            id: release_id,
+            version: release_version.to_owned(),
+        },
+    };
+
+    std::fs::write(
+        &system_info_path,
+        &serde_json::to_string_pretty(&system_info).unwrap(),
+    )
+    .whatever("unable to write `/etc/rugix/system-build-info.json`")?;
+
+    Ok(system_info)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SystemBuildInput {
+    /// Id of the layer the system has been built from.
+    pub layer: String,
     pub release: ReleaseInfo,
+    /// Digest of the system's configuration, e.g., its image layout and target.
+    pub config: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -0,0 +1,281 @@
+//! Declarative enabling, disabling, and masking of systemd units.
+//!
+//! Units are enabled and disabled by creating and removing the symbolic links
+//! `systemctl` would create based on the `[Install]` section of their unit files.
+//! Masked units are linked to `/dev/null`. The links are created directly in the root,
+//! hence, neither `systemctl` nor a `chroot` is required.
+
+use std::collections::HashSet;
+use std::fs;
+use std::os::unix::fs::symlink;
+use std::path::{Path, PathBuf};
+
+use reportify::{bail, ResultExt};
+
+use crate::config::recipes::SystemdConfig;
+use crate::BakeryResult;
+
+use super::files::resolve_in_root;
+
+/// Directory for the configuration of the system administrator.
+const CONFIG_DIR: &str = "/etc/systemd/system";
+
+/// Target of the links of masked units.
+const DEV_NULL: &str = "/dev/null";
+
+/// Maximal number of symbolic links followed when reading a unit file.
+const MAX_SYMLINKS: usize = 40;
+
+/// Directories searched for unit files in the order of their precedence.
+const UNIT_DIRS: &[&str] = &[
+    CONFIG_DIR,
+    "/usr/local/lib/systemd/system",
+    "/usr/lib/systemd/system",
+    "/lib/systemd/system",
+];
+
+/// Enable, disable, and mask the given units in the given root.
+pub fn apply_units(root: &Path, config: &SystemdConfig) -> BakeryResult<()> {
+    for unit in config.enable.iter().flatten() {
+        enable(root, unit, &mut HashSet::new())
+            .with_info(|_| format!("enabling systemd unit `{unit}`"))?;
+    }
+    for unit in config.disable.iter().flatten() {
+        disable(root, unit, &mut HashSet::new())
+            .with_info(|_| format!("disabling systemd unit `{unit}`"))?;
+    }
+    for unit in config.mask.iter().flatten() {
+        mask(root, unit).with_info(|_| format!("masking systemd unit `{unit}`"))?;
+    }
+    Ok(())
+}
+
+/// Enable the given unit and the units it lists in `Also=`.
+fn enable(root: &Path, unit: &str, visited: &mut HashSet<String>) -> BakeryResult<()> {
+    if !visited.insert(unit.to_owned()) {
+        return Ok(());
+    }
+    if is_masked(root, unit)? {
+        bail!("unit `{unit}` is masked");
+    }
+    let unit_file = find_unit(root, unit)?;
+    let install = InstallSection::load(root, &unit_file)?;
+    let name = match instance_name(unit, install.default_instance.as_deref()) {
+        Some(name) => name,
+        None => bail!("template unit `{unit}` requires an instance"),
+    };
+    if install.is_empty() {
+        bail!("unit `{unit}` has no `[Install]` section and cannot be enabled");
+    }
+    for target in &install.wanted_by {
+        link(
+            root,
+            &Path::new(CONFIG_DIR)
+                .join(format!("{target}.wants"))
+                .join(&name),
+            &unit_file,
+        )?;
+    }
+    for target in &install.required_by {
+        link(
+            root,
+            &Path::new(CONFIG_DIR)
+                .join(format!("{target}.requires"))
+                .join(&name),
+            &unit_file,
+        )?;
+    }
+    for alias in &install.aliases {
+        link(root, &Path::new(CONFIG_DIR).join(alias), &unit_file)?;
+    }
+    for also in &install.also {
+        enable(root, also, visited)?;
+    }
+    Ok(())
+}
+
+/// Disable the given unit and the units it lists in `Also=`.
+fn disable(root: &Path, unit: &str, visited: &mut HashSet<String>) -> BakeryResult<()> {
+    if !visited.insert(unit.to_owned()) {
+        return Ok(());
+    }
+    let config_dir = resolve_in_root(root, Path::new(CONFIG_DIR))?;
+    let mut names = vec![unit.to_owned()];
+    let mut also = Vec::new();
+    if let Ok(unit_file) = find_unit(root, unit) {
+        let install = InstallSection::load(root, &unit_file)?;
+        names.extend(instance_name(unit, install.default_instance.as_deref()));
+        for alias in &install.aliases {
+            remove_link(&config_dir.join(alias))?;
+        }
+        also = install.also;
+    }
+    if config_dir.is_dir() {
+        for entry in fs::read_dir(&config_dir).whatever("unable to read systemd configuration")? {
+            let dir = entry
+                .whatever("unable to read systemd configuration")?
+                .path();
+            let is_dependency_dir = dir
+                .extension()
+                .is_some_and(|extension| extension == "wants" || extension == "requires");
+            if is_dependency_dir && dir.is_dir() {
+                for name in &names {
+                    remove_link(&dir.join(name))?;
+                }
+            }
+        }
+    }
+    for also in &also {
+        disable(root, also, visited)?;
+    }
+    Ok(())
+}
+
+/// Mask the given unit by linking it to `/dev/null`.
+fn mask(root: &Path, unit: &str) -> BakeryResult<()> {
+    let path = resolve_in_root(root, &Path::new(CONFIG_DIR).join(unit))?;
+    if let Ok(metadata) = fs::symlink_metadata(&path) {
+        if !metadata.is_symlink() {
+            bail!("unit file {CONFIG_DIR}/{unit} exists and cannot be replaced");
+        }
+    }
+    link(root, &Path::new(CONFIG_DIR).join(unit), Path::new(DEV_NULL))
+}
+
+/// Indicates whether the given unit is masked.
+fn is_masked(root: &Path, unit: &str) -> BakeryResult<bool> {
+    let path = resolve_in_root(root, &Path::new(CONFIG_DIR).join(unit))?;
+    Ok(fs::read_link(path).is_ok_and(|target| target == DEV_NULL))
+}
+
+/// Path of the unit file of the given unit within the root.
+///
+/// Instances of template units use the unit file of their template.
+fn find_unit(root: &Path, unit: &str) -> BakeryResult<PathBuf> {
+    let mut candidates = vec![unit.to_owned()];
+    if let Some(template) = template_name(unit) {
+        candidates.push(template);
+    }
+    for candidate in &candidates {
+        for dir in UNIT_DIRS {
+            let path = Path::new(dir).join(candidate);
+            let resolved = resolve_in_root(root, &path)?;
+            let is_mask = fs::read_link(&resolved).is_ok_and(|target| target == DEV_NULL);
+            if !is_mask && fs::symlink_metadata(&resolved).is_ok() {
+                return Ok(path);
+            }
+        }
+    }
+    bail!("unable to find unit file of `{unit}`")
+}
+
+/// Name of the template of the given unit instance, e.g., `getty@.service`.
+fn template_name(unit: &str) -> Option<String> {
+    let (prefix, rest) = unit.split_once('@')?;
+    let (instance, suffix) = rest.rsplit_once('.')?;
+    if instance.is_empty() {
+        return None;
+    }
+    Some(format!("{prefix}@.{suffix}"))
+}
+
+/// Name under which the given unit is enabled.
+///
+/// Templates are enabled with their default instance, if they have one.
+fn instance_name(unit: &str, default_instance: Option<&str>) -> Option<String> {
+    let Some((prefix, suffix)) = unit.split_once("@.") else {
+        return Some(unit.to_owned());
+    };
+    default_instance.map(|instance| format!("{prefix}@{instance}.{suffix}"))
+}
+
+/// Create a symbolic link at the given path within the root.
+///
+/// An existing link is replaced.
+fn link(root: &Path, path: &Path, target: &Path) -> BakeryResult<()> {
+    if path == target {
+        // The unit has been referred to by its alias.
+        return Ok(());
+    }
+    let path = resolve_in_root(root, path)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).whatever("unable to create directory")?;
+    }
+    remove_link(&path)?;
+    symlink(target, &path).whatever_with(|_| format!("unable to create symbolic link {path:?}"))
+}
+
+/// Remove the symbolic link at the given path, if there is one.
+fn remove_link(path: &Path) -> BakeryResult<()> {
+    if fs::symlink_metadata(path).is_ok_and(|metadata| metadata.is_symlink()) {
+        fs::remove_file(path)
+            .whatever_with(|_| format!("unable to remove symbolic link {path:?}"))?;
+    }
+    Ok(())
+}
+
+/// Relevant settings of the `[Install]` section of a unit file.
+#[derive(Debug, Default)]
+struct InstallSection {
+    wanted_by: Vec<String>,
+    required_by: Vec<String>,
+    aliases: Vec<String>,
+    also: Vec<String>,
+    default_instance: Option<String>,
+}
+
+impl InstallSection {
+    /// Load the section from the given unit file within the root.
+    ///
+    /// Unit files may be symbolic links, which are followed within the root.
+    fn load(root: &Path, unit_file: &Path) -> BakeryResult<Self> {
+        let mut path = unit_file.to_path_buf();
+        for _ in 0..MAX_SYMLINKS {
+            let resolved = resolve_in_root(root, &path)?;
+            match fs::read_link(&resolved) {
+                Ok(target) => path = path.parent().unwrap_or(Path::new("/")).join(target),
+                Err(_) => {
+                    let contents = fs::read_to_string(&resolved)
+                        .whatever_with(|_| format!("unable to read unit file {path:?}"))?;
+                    return Ok(Self::parse(&contents));
+                }
+            }
+        }
+        bail!("too many symbolic links while reading unit file {unit_file:?}")
+    }
+
+    fn parse(contents: &str) -> Self {
+        let mut install = Self::default();
+        let mut in_install = false;
+        for line in contents.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with(['#', ';']) {
+                continue;
+            }
+            if line.starts_with('[') {
+                in_install = line == "[Install]";
+                continue;
+            }
+            let Some((key, value)) = line.split_once('=').filter(|_| in_install) else {
+                continue;
+            };
+            let values = value.split_whitespace().map(str::to_owned);
+            match key.trim() {
+                "WantedBy" => install.wanted_by.extend(values),
+                "RequiredBy" => install.required_by.extend(values),
+                "Alias" => install.aliases.extend(values),
+                "Also" => install.also.extend(values),
+                "DefaultInstance" => install.default_instance = Some(value.trim().to_owned()),
+                _ => {}
+            }
+        }
+        install
+    }
+
+    fn is_empty(&self) -> bool {
+        self.wanted_by.is_empty()
+            && self.required_by.is_empty()
+            && self.aliases.is_empty()
+            && self.also.is_empty()
+    }
+}
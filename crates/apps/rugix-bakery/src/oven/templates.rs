@@ -0,0 +1,122 @@
+//! Rendering of templates in the `files` directory of recipes.
+//!
+//! Files with a `.tmpl` suffix are rendered with MiniJinja when they are copied into
+//! the root. The suffix is removed from the names of rendered files. Templates can
+//! refer to the recipe's parameters (`params`), the architecture (`RUGIX_ARCH`), the
+//! name of the system (`system`), and its release information (`release`). Undefined
+//! variables are errors.
+
+use std::collections::HashMap;
+use std::ffi::OsStr;
+use std::fs;
+use std::path::Path;
+
+use minijinja::{context, AutoEscape, Environment, UndefinedBehavior, Value};
+use reportify::{whatever, ResultExt};
+
+use crate::config::systems::Architecture;
+use crate::BakeryResult;
+
+use super::system::TargetSystem;
+
+/// Suffix of template files.
+pub const TEMPLATE_SUFFIX: &str = ".tmpl";
+
+/// Renderer for the templates of a recipe.
+pub struct TemplateRenderer {
+    env: Environment<'static>,
+    ctx: Value,
+}
+
+impl TemplateRenderer {
+    /// Create a renderer with the given parameters, architecture, and system.
+    pub fn new(
+        parameters: &HashMap<String, String>,
+        arch: Architecture,
+        system: Option<&TargetSystem>,
+    ) -> Self {
+        let mut env = Environment::new();
+        env.set_undefined_behavior(UndefinedBehavior::Strict);
+        env.set_keep_trailing_newline(true);
+        env.set_auto_escape_callback(|_| AutoEscape::None);
+        let ctx = context! {
+            params => Value::from_serialize(parameters),
+            RUGIX_ARCH => arch.as_str(),
+            system => system.map(|system| system.name.as_str()),
+            release => system.map(|system| Value::from_serialize(&system.release)),
+        };
+        Self { env, ctx }
+    }
+
+    /// Render the template at the given path.
+    pub fn render(&self, path: &Path) -> BakeryResult<String> {
+        let source = fs::read_to_string(path)
+            .whatever_with(|_| format!("unable to read template {path:?}"))?;
+        self.env
+            .render_named_str(&path.to_string_lossy(), &source, &self.ctx)
+            .map_err(|error| {
+                let detail = match error.detail() {
+                    Some(detail) => format!("{}: {detail}", error.kind()),
+                    None => error.kind().to_string(),
+                };
+                match error.line() {
+                    Some(line) => {
+                        whatever!("unable to render template {path:?} at line {line}: {detail}")
+                    }
+                    None => whatever!("unable to render template {path:?}: {detail}"),
+                }
+            })
+    }
+}
+
+/// Name of the given file without the template suffix, if it is a template.
+pub fn template_name(name: &OsStr) -> Option<&OsStr> {
+    name.to_str()?
+        .strip_suffix(TEMPLATE_SUFFIX)
+        .filter(|name| !name.is_empty())
+        .map(OsStr::new)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::oven::system::ReleaseInfo;
+
+    #[test]
+    fn test_template_name() {
+        let name = |name: &str| template_name(OsStr::new(name)).and_then(OsStr::to_str);
+        assert_eq!(name("config.txt.tmpl"), Some("config.txt"));
+        assert_eq!(name("hostname.tmpl"), Some("hostname"));
+        assert_eq!(name(".env.tmpl"), Some(".env"));
+        assert_eq!(name(".tmpl"), None);
+        assert_eq!(name("config.txt"), None);
+        assert_eq!(name("config.tmpl.txt"), None);
+        assert_eq!(name("config.TMPL"), None);
+    }
+
+    #[test]
+    fn test_render() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("hostname.tmpl");
+        let parameters = HashMap::from([("hostname".to_owned(), "rugix".to_owned())]);
+        let system = TargetSystem {
+            name: "default".to_owned(),
+            release: ReleaseInfo {
+                system_id: None,
+                system_version: Some("1.0".to_owned()),
+            },
+        };
+        let renderer = TemplateRenderer::new(&parameters, Architecture::Arm64, Some(&system));
+        fs::write(
+            &path,
+            "{{ params.hostname }} {{ RUGIX_ARCH }} {{ system }} {{ release.system_version }}\n",
+        )
+        .unwrap();
+        assert_eq!(renderer.render(&path).unwrap(), "rugix arm64 default 1.0\n");
+        fs::write(&path, "<{{ params.hostname }}>").unwrap();
+        assert_eq!(renderer.render(&path).unwrap(), "<rugix>");
+        fs::write(&path, "{{ params.undefined }}").unwrap();
+        assert!(renderer.render(&path).is_err());
+    }
+}
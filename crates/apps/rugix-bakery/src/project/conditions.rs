@@ -0,0 +1,512 @@
+//! Conditions for including recipes and running steps.
+//!
+//! Conditions are small boolean expressions over the architecture (`arch`), the
+//! system's target (`target`), and recipe parameters (`params.<name>`):
+//!
+//! ```text
+//! arch == "arm64" && target != "generic-grub-efi"
+//! target in ["rpi-tryboot", "rpi-uboot"] || params.enable_wifi
+//! ```
+//!
+//! Values are compared as strings. A variable without a value, e.g., `target` when
+//! baking a layer without a system or an omitted optional parameter, is not equal to
+//! any string. Used on its own, a variable holds if its value is `true`. Referring to a
+//! parameter which the recipe does not declare is an error.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use reportify::{bail, whatever, ResultExt};
+
+use crate::config::recipes::ParameterDef;
+use crate::config::systems::{Architecture, Target};
+use crate::BakeryResult;
+
+/// Parsed condition.
+#[derive(Debug, Clone)]
+pub struct Condition {
+    /// Source of the condition.
+    source: String,
+    /// Expression of the condition.
+    expr: Expr,
+}
+
+impl Condition {
+    /// Parse a condition.
+    pub fn parse(source: &str) -> BakeryResult<Self> {
+        let tokens = tokenize(source).with_info(|_| format!("in condition `{source}`"))?;
+        let mut parser = Parser {
+            tokens,
+            position: 0,
+        };
+        let expr = parser
+            .parse_or()
+            .and_then(|expr| match parser.peek() {
+                None => Ok(expr),
+                Some(token) => bail!("unexpected {token}"),
+            })
+            .with_info(|_| format!("in condition `{source}`"))?;
+        Ok(Self {
+            source: source.to_owned(),
+            expr,
+        })
+    }
+
+    /// Evaluate the condition in the given context.
+    pub fn evaluate(&self, ctx: &ConditionContext) -> BakeryResult<bool> {
+        self.expr
+            .evaluate(ctx)
+            .with_info(|_| format!("in condition `{}`", self.source))
+    }
+}
+
+impl fmt::Display for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+/// Context in which conditions are evaluated.
+#[derive(Debug, Clone, Copy)]
+pub struct ConditionContext<'c> {
+    /// Architecture of the layer.
+    pub arch: Architecture,
+    /// Target of the system, if the layer is baked for a system.
+    pub target: Option<&'c Target>,
+    /// Parameter definitions of the recipe.
+    pub definitions: Option<&'c HashMap<String, ParameterDef>>,
+    /// Resolved parameters of the recipe.
+    pub parameters: Option<&'c HashMap<String, String>>,
+}
+
+impl ConditionContext<'_> {
+    /// Value of the given variable.
+    fn lookup(&self, variable: &str) -> BakeryResult<Option<&str>> {
+        match variable {
+            "arch" => Ok(Some(self.arch.as_str())),
+            "target" => Ok(self.target.map(Target::as_str)),
+            _ => {
+                let Some(name) = variable.strip_prefix("params.") else {
+                    bail!("unknown variable `{variable}`");
+                };
+                if !self
+                    .definitions
+                    .is_some_and(|definitions| definitions.contains_key(name))
+                {
+                    bail!("unknown parameter `{name}`");
+                }
+                Ok(self
+                    .parameters
+                    .and_then(|parameters| parameters.get(name))
+                    .map(String::as_str))
+            }
+        }
+    }
+}
+
+/// Expression of a condition.
+#[derive(Debug, Clone)]
+enum Expr {
+    /// Literal boolean.
+    Bool(bool),
+    /// Variable which holds if its value is `true`.
+    Variable(String),
+    /// Equality of two operands.
+    Eq(Operand, Operand),
+    /// Inequality of two operands.
+    Ne(Operand, Operand),
+    /// Membership of an operand in a list.
+    In(Operand, Vec<Operand>),
+    /// Negation.
+    Not(Box<Expr>),
+    /// Conjunction.
+    And(Box<Expr>, Box<Expr>),
+    /// Disjunction.
+    Or(Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    fn evaluate(&self, ctx: &ConditionContext) -> BakeryResult<bool> {
+        Ok(match self {
+            Expr::Bool(value) => *value,
+            Expr::Variable(variable) => ctx.lookup(variable)? == Some("true"),
+            Expr::Eq(left, right) => left.evaluate(ctx)? == right.evaluate(ctx)?,
+            Expr::Ne(left, right) => left.evaluate(ctx)? != right.evaluate(ctx)?,
+            Expr::In(operand, list) => {
+                let value = operand.evaluate(ctx)?;
+                let mut found = false;
+                for item in list {
+                    if item.evaluate(ctx)? == value {
+                        found = true;
+                        break;
+                    }
+                }
+                found
+            }
+            Expr::Not(expr) => !expr.evaluate(ctx)?,
+            Expr::And(left, right) => left.evaluate(ctx)? && right.evaluate(ctx)?,
+            Expr::Or(left, right) => left.evaluate(ctx)? || right.evaluate(ctx)?,
+        })
+    }
+}
+
+/// Operand of a comparison.
+#[derive(Debug, Clone)]
+enum Operand {
+    /// Literal value.
+    Literal(String),
+    /// Value of a variable.
+    Variable(String),
+}
+
+impl Operand {
+    fn evaluate<'c>(&'c self, ctx: &'c ConditionContext) -> BakeryResult<Option<&'c str>> {
+        match self {
+            Operand::Literal(value) => Ok(Some(value)),
+            Operand::Variable(variable) => ctx.lookup(variable),
+        }
+    }
+}
+
+/// Token of a condition.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Ident(String),
+    Literal(String),
+    Eq,
+    Ne,
+    Not,
+    And,
+    Or,
+    In,
+    Comma,
+    LParen,
+    RParen,
+    LBracket,
+    RBracket,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Ident(ident) => write!(f, "`{ident}`"),
+            Token::Literal(value) => write!(f, "{value:?}"),
+            Token::Eq => f.write_str("`==`"),
+            Token::Ne => f.write_str("`!=`"),
+            Token::Not => f.write_str("`!`"),
+            Token::And => f.write_str("`&&`"),
+            Token::Or => f.write_str("`||`"),
+            Token::In => f.write_str("`in`"),
+            Token::Comma => f.write_str("`,`"),
+            Token::LParen => f.write_str("`(`"),
+            Token::RParen => f.write_str("`)`"),
+            Token::LBracket => f.write_str("`[`"),
+            Token::RBracket => f.write_str("`]`"),
+        }
+    }
+}
+
+/// Split a condition into tokens.
+fn tokenize(source: &str) -> BakeryResult<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = source.chars().peekable();
+    while let Some(c) = chars.next() {
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '(' => Token::LParen,
+            ')' => Token::RParen,
+            '[' => Token::LBracket,
+            ']' => Token::RBracket,
+            ',' => Token::Comma,
+            '=' if chars.next_if_eq(&'=').is_some() => Token::Eq,
+            '!' if chars.next_if_eq(&'=').is_some() => Token::Ne,
+            '!' => Token::Not,
+            '&' if chars.next_if_eq(&'&').is_some() => Token::And,
+            '|' if chars.next_if_eq(&'|').is_some() => Token::Or,
+            '"' | '\'' => {
+                let mut value = String::new();
+                loop {
+                    match chars.next() {
+                        Some(end) if end == c => break,
+                        Some(c) => value.push(c),
+                        None => bail!("unterminated string"),
+                    }
+                }
+                Token::Literal(value)
+            }
+            c if c.is_ascii_alphanumeric() || c == '_' || c == '-' => {
+                let mut ident = String::from(c);
+                while let Some(c) =
+                    chars.next_if(|c| c.is_ascii_alphanumeric() || matches!(*c, '_' | '-' | '.'))
+                {
+                    ident.push(c);
+                }
+                match ident.as_str() {
+                    "in" => Token::In,
+                    _ => Token::Ident(ident),
+                }
+            }
+            c => bail!("unexpected character `{c}`"),
+        };
+        tokens.push(token);
+    }
+    Ok(tokens)
+}
+
+/// Recursive descent parser for conditions.
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> BakeryResult<Token> {
+        let token = self
+            .tokens
+            .get(self.position)
+            .cloned()
+            .ok_or_else(|| whatever!("unexpected end of condition"))?;
+        self.position += 1;
+        Ok(token)
+    }
+
+    fn eat(&mut self, token: &Token) -> bool {
+        if self.peek() == Some(token) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, expected: &Token) -> BakeryResult<()> {
+        let token = self.next()?;
+        if &token != expected {
+            bail!("expected {expected}, found {token}");
+        }
+        Ok(())
+    }
+
+    fn parse_or(&mut self) -> BakeryResult<Expr> {
+        let mut expr = self.parse_and()?;
+        while self.eat(&Token::Or) {
+            expr = Expr::Or(Box::new(expr), Box::new(self.parse_and()?));
+        }
+        Ok(expr)
+    }
+
+    fn parse_and(&mut self) -> BakeryResult<Expr> {
+        let mut expr = self.parse_not()?;
+        while self.eat(&Token::And) {
+            expr = Expr::And(Box::new(expr), Box::new(self.parse_not()?));
+        }
+        Ok(expr)
+    }
+
+    fn parse_not(&mut self) -> BakeryResult<Expr> {
+        if self.eat(&Token::Not) {
+            return Ok(Expr::Not(Box::new(self.parse_not()?)));
+        }
+        if self.eat(&Token::LParen) {
+            let expr = self.parse_or()?;
+            self.expect(&Token::RParen)?;
+            return Ok(expr);
+        }
+        let left = self.parse_operand()?;
+        if self.eat(&Token::Eq) {
+            return Ok(Expr::Eq(left, self.parse_operand()?));
+        }
+        if self.eat(&Token::Ne) {
+            return Ok(Expr::Ne(left, self.parse_operand()?));
+        }
+        if self.eat(&Token::In) {
+            self.expect(&Token::LBracket)?;
+            let mut list = Vec::new();
+            while !self.eat(&Token::RBracket) {
+                list.push(self.parse_operand()?);
+                if !self.eat(&Token::Comma) {
+                    self.expect(&Token::RBracket)?;
+                    break;
+                }
+            }
+            return Ok(Expr::In(left, list));
+        }
+        match left {
+            Operand::Variable(variable) => Ok(Expr::Variable(variable)),
+            Operand::Literal(value) => match value.as_str() {
+                "true" => Ok(Expr::Bool(true)),
+                "false" => Ok(Expr::Bool(false)),
+                _ => bail!("expected condition, found {value:?}"),
+            },
+        }
+    }
+
+    fn parse_operand(&mut self) -> BakeryResult<Operand> {
+        match self.next()? {
+            Token::Literal(value) => Ok(Operand::Literal(value)),
+            // Booleans and numbers are compared by their string representation.
+            Token::Ident(ident)
+                if ident == "true"
+                    || ident == "false"
+                    || ident.starts_with(|c: char| c.is_ascii_digit() || c == '-') =>
+            {
+                Ok(Operand::Literal(ident))
+            }
+            Token::Ident(ident) => {
+                if ident != "arch" && ident != "target" && !ident.starts_with("params.") {
+                    bail!("unknown variable `{ident}`");
+                }
+                Ok(Operand::Variable(ident))
+            }
+            token => bail!("expected value, found {token}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn evaluate(source: &str, ctx: &ConditionContext) -> bool {
+        Condition::parse(source).unwrap().evaluate(ctx).unwrap()
+    }
+
+    fn definitions(names: &[&str]) -> HashMap<String, ParameterDef> {
+        names
+            .iter()
+            .map(|name| (name.to_string(), ParameterDef::new()))
+            .collect()
+    }
+
+    fn system_ctx<'c>(
+        definitions: &'c HashMap<String, ParameterDef>,
+        parameters: &'c HashMap<String, String>,
+    ) -> ConditionContext<'c> {
+        ConditionContext {
+            arch: Architecture::Arm64,
+            target: Some(&Target::RpiTryboot),
+            definitions: Some(definitions),
+            parameters: Some(parameters),
+        }
+    }
+
+    fn layer_ctx() -> ConditionContext<'static> {
+        ConditionContext {
+            arch: Architecture::Amd64,
+            target: None,
+            definitions: None,
+            parameters: None,
+        }
+    }
+
+    #[test]
+    fn test_comparisons() {
+        let (definitions, parameters) = (HashMap::new(), HashMap::new());
+        let ctx = system_ctx(&definitions, &parameters);
+        assert!(evaluate(r#"arch == "arm64""#, &ctx));
+        assert!(evaluate("arch == 'arm64'", &ctx));
+        assert!(!evaluate(r#"arch != "arm64""#, &ctx));
+        assert!(evaluate(r#"target != "generic-grub-efi""#, &ctx));
+        assert!(evaluate(r#""rpi-tryboot" == target"#, &ctx));
+    }
+
+    #[test]
+    fn test_precedence() {
+        let ctx = layer_ctx();
+        // `&&` binds stronger than `||`.
+        assert!(evaluate("true || false && false", &ctx));
+        assert!(evaluate("false && false || true", &ctx));
+        assert!(!evaluate("(true || false) && false", &ctx));
+        // `!` binds stronger than `&&`.
+        assert!(!evaluate("!false && false", &ctx));
+        assert!(evaluate("!(false && false)", &ctx));
+        assert!(evaluate("!!true", &ctx));
+        assert!(evaluate(
+            r#"arch == "arm64" || arch == "amd64" && !(target == "rpi-tryboot")"#,
+            &ctx
+        ));
+    }
+
+    #[test]
+    fn test_in() {
+        let (definitions, parameters) = (HashMap::new(), HashMap::new());
+        let ctx = system_ctx(&definitions, &parameters);
+        assert!(evaluate(r#"target in ["rpi-tryboot", "rpi-uboot"]"#, &ctx));
+        assert!(evaluate(r#"target in ["rpi-uboot", "rpi-tryboot",]"#, &ctx));
+        assert!(!evaluate(r#"target in ["generic-grub-efi"]"#, &ctx));
+        assert!(!evaluate("target in []", &ctx));
+        assert!(evaluate(
+            r#"!(arch in ["amd64"]) && target in ["rpi-tryboot"]"#,
+            &ctx
+        ));
+    }
+
+    #[test]
+    fn test_parameters() {
+        let parameters = HashMap::from([
+            ("enable_wifi".to_owned(), "true".to_owned()),
+            ("enable_ssh".to_owned(), "false".to_owned()),
+            ("hostname".to_owned(), "rugix".to_owned()),
+            ("port".to_owned(), "22".to_owned()),
+        ]);
+        let definitions = definitions(&["enable_wifi", "enable_ssh", "hostname", "port"]);
+        let ctx = system_ctx(&definitions, &parameters);
+        assert!(evaluate("params.enable_wifi", &ctx));
+        assert!(!evaluate("params.enable_ssh", &ctx));
+        assert!(evaluate("params.enable_ssh == false", &ctx));
+        assert!(evaluate(r#"params.hostname == "rugix""#, &ctx));
+        assert!(evaluate("params.port == 22", &ctx));
+    }
+
+    #[test]
+    fn test_variables_without_value() {
+        let ctx = layer_ctx();
+        assert!(!evaluate("target", &ctx));
+        assert!(!evaluate(r#"target == "rpi-tryboot""#, &ctx));
+        assert!(evaluate(r#"target != "rpi-tryboot""#, &ctx));
+        assert!(!evaluate(r#"target in ["rpi-tryboot", "rpi-uboot"]"#, &ctx));
+        // Optional parameters without a default have no value.
+        let (definitions, parameters) = (definitions(&["enable_wifi", "hostname"]), HashMap::new());
+        let ctx = system_ctx(&definitions, &parameters);
+        assert!(!evaluate("params.enable_wifi", &ctx));
+        assert!(!evaluate(r#"params.hostname == "rugix""#, &ctx));
+    }
+
+    #[test]
+    fn test_unknown_parameters() {
+        let (definitions, parameters) = (definitions(&["enable_wifi"]), HashMap::new());
+        for ctx in [layer_ctx(), system_ctx(&definitions, &parameters)] {
+            let condition = Condition::parse("params.enable_wif").unwrap();
+            assert!(condition.evaluate(&ctx).is_err());
+            let condition = Condition::parse(r#"params.enable_wif == "true""#).unwrap();
+            assert!(condition.evaluate(&ctx).is_err());
+        }
+    }
+
+    #[test]
+    fn test_invalid_conditions() {
+        for source in [
+            "",
+            "true true",
+            "arch ==",
+            r#"arch == "arm64"#,
+            r#"system == "default""#,
+            r#""arm64""#,
+            "(true",
+            "true)",
+            r#"target in ["rpi-tryboot""#,
+            "arch = amd64",
+            "true & false",
+            "arch == amd64 $",
+        ] {
+            assert!(
+                Condition::parse(source).is_err(),
+                "{source:?} should be invalid"
+            );
+        }
+    }
+}
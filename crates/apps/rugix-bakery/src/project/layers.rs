@@ -1,8 +1,8 @@
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
 use crate::config::layers::LayerConfig;
 use crate::config::systems::Architecture;
-use crate::utils::caching::ModificationTime;
 
 use super::repositories::RepositoryIdx;
 
@@ -10,19 +10,29 @@ use super::repositories::RepositoryIdx;
 pub struct Layer {
     pub name: String,
     pub repo: RepositoryIdx,
-    pub modified: ModificationTime,
     pub default_config: Option<LayerConfig>,
     pub arch_configs: HashMap<Architecture, LayerConfig>,
+    /// Digest of the default configuration file.
+    pub default_digest: Option<String>,
+    /// Digests of the architecture-specific configuration files.
+    pub arch_digests: HashMap<Architecture, String>,
+    /// Path of the default configuration file.
+    pub default_path: Option<PathBuf>,
+    /// Paths of the architecture-specific configuration files.
+    pub arch_paths: HashMap<Architecture, PathBuf>,
 }
 
 impl Layer {
-    pub fn new(name: String, repo: RepositoryIdx, modified: ModificationTime) -> Self {
+    pub fn new(name: String, repo: RepositoryIdx) -> Self {
         Self {
             name,
             repo,
-            modified,
             default_config: None,
             arch_configs: HashMap::new(),
+            default_digest: None,
+            arch_digests: HashMap::new(),
+            default_path: None,
+            arch_paths: HashMap::new(),
         }
     }
 
@@ -32,4 +42,21 @@ impl Layer {
             .get(&arch)
             .or(self.default_config.as_ref())
     }
+
+    /// Digest of the configuration file used for the given architecture.
+    pub fn config_digest(&self, arch: Architecture) -> Option<&str> {
+        if self.arch_configs.contains_key(&arch) {
+            self.arch_digests.get(&arch).map(String::as_str)
+        } else {
+            self.default_digest.as_deref()
+        }
+    }
+
+    /// Path of the configuration file used for the given architecture.
+    pub fn config_path(&self, arch: Architecture) -> Option<&Path> {
+        self.arch_paths
+            .get(&arch)
+            .or(self.default_path.as_ref())
+            .map(PathBuf::as_path)
+    }
 }
@@ -1,3 +1,4 @@
+use std::cmp::Reverse;
 use std::collections::HashMap;
 use std::ffi::OsStr;
 use std::fs;
@@ -6,11 +7,11 @@ use std::path::Path;
 use std::str::FromStr;
 use std::sync::Arc;
 
-use reportify::{whatever, ResultExt};
+use reportify::{bail, whatever, ResultExt};
 
 use crate::config::load_config;
 use crate::config::systems::Architecture;
-use crate::utils::caching::mtime;
+use crate::utils::caching::digest_path;
 use crate::utils::idx_vec::{new_idx_type, IdxVec};
 use crate::BakeryResult;
 
@@ -25,6 +26,8 @@ pub struct Library {
     pub layers: IdxVec<LayerIdx, Layer>,
     pub recipe_tables: IdxVec<RepositoryIdx, HashMap<String, RecipeIdx>>,
     pub layer_tables: IdxVec<RepositoryIdx, HashMap<String, LayerIdx>>,
+    /// Recipes providing virtual names in the order of preference.
+    providers: HashMap<String, Vec<RecipeIdx>>,
 }
 
 impl Library {
@@ -52,6 +55,24 @@ impl Library {
             }
             tables.push(table);
         }
+        let mut providers = HashMap::<_, Vec<_>>::new();
+        for (idx, recipe) in recipes.iter() {
+            for name in recipe.config.provides.iter().flatten() {
+                providers.entry(name.clone()).or_default().push(idx);
+            }
+        }
+        // Providers with a higher priority are preferred. Ties are broken by name and
+        // path such that the selection does not depend on the order of the recipes.
+        for providers in providers.values_mut() {
+            providers.sort_by_key(|idx| {
+                let recipe = &recipes[*idx];
+                (
+                    Reverse(recipe.config.priority.unwrap_or_default()),
+                    recipe.name.to_string(),
+                    recipe.path.clone(),
+                )
+            });
+        }
         let mut layers = IdxVec::new();
         let mut layer_tables = IdxVec::<RepositoryIdx, _>::new();
         for (idx, repository) in repositories.iter() {
@@ -81,18 +102,21 @@ impl Library {
                     );
                     name = layer_name.to_owned();
                 }
-                let modified = mtime(&path).whatever("unable to obtain layer mtime")?;
+                let digest = digest_path(&path).whatever("unable to compute layer digest")?;
                 let layer_config = load_config(&path)?;
                 let layer_idx = *table
                     .entry(name.clone())
-                    .or_insert_with(|| layers.push(Layer::new(name, idx, modified)));
-                layers[layer_idx].modified = layers[layer_idx].modified.max(modified);
+                    .or_insert_with(|| layers.push(Layer::new(name, idx)));
                 match arch {
                     Some(arch) => {
                         layers[layer_idx].arch_configs.insert(arch, layer_config);
+                        layers[layer_idx].arch_digests.insert(arch, digest);
+                        layers[layer_idx].arch_paths.insert(arch, path);
                     }
                     None => {
                         layers[layer_idx].default_config = Some(layer_config);
+                        layers[layer_idx].default_digest = Some(digest);
+                        layers[layer_idx].default_path = Some(path);
                     }
                 }
             }
@@ -104,6 +128,7 @@ impl Library {
             recipe_tables: tables,
             layers,
             layer_tables,
+            providers,
         })
     }
 
@@ -126,6 +151,26 @@ impl Library {
             .ok_or_else(|| whatever!("unable to find recipe {name}"))
     }
 
+    /// Recipes providing the given virtual name in the order of preference.
+    pub fn providers(&self, name: &str) -> &[RecipeIdx] {
+        self.providers
+            .get(name)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Recipes with the given name or, if there is none, providing the given name.
+    pub fn lookup_all(&self, repo: RepositoryIdx, name: &str) -> BakeryResult<Vec<RecipeIdx>> {
+        if let Some(idx) = self.lookup(repo, name) {
+            return Ok(vec![idx]);
+        }
+        let providers = self.providers(name);
+        if providers.is_empty() {
+            bail!("unable to find recipe {name}");
+        }
+        Ok(providers.to_vec())
+    }
+
     pub fn lookup_layer(&self, repo: RepositoryIdx, name: &str) -> Option<LayerIdx> {
         if let Some((dependency_name, layer_name)) = name.split_once('/') {
             let dependency_idx = match dependency_name {
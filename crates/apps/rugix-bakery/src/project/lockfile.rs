@@ -0,0 +1,259 @@
+//! Lockfile recording the packages installed in layers.
+//!
+//! With `--update-lock`, the packages installed in the root of each baked layer are
+//! recorded in the project's `rugix-bakery.lock` file. The lockfile is meant to be
+//! committed to the project such that later bakes can be pinned to exactly the recorded
+//! versions with `--locked`. Other bakes neither use nor modify the lockfile.
+//!
+//! The lock of a layer covers all packages in its root, including those installed by
+//! its parent layers. Hence, a `--locked` bake also detects changes of inherited
+//! packages. Packages installed by the layer's own recipes are pinned together with their
+//! dependencies. As pacman and opkg cannot install specific versions, layers installing
+//! packages with them cannot be baked with `--locked`.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use reportify::{bail, ResultExt};
+use serde::{Deserialize, Serialize};
+
+use crate::config::systems::Architecture;
+use crate::utils::caching::Hasher;
+use crate::BakeryResult;
+
+use super::recipes::PackageManager;
+
+/// Name of the lockfile in the project directory.
+pub const LOCKFILE: &str = "rugix-bakery.lock";
+
+/// Version of the lockfile format.
+const LOCKFILE_VERSION: u32 = 1;
+
+/// Mode for handling the lockfile.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LockMode {
+    /// Neither pin packages nor modify the lockfile.
+    #[default]
+    Unlocked,
+    /// Pin packages to the versions in the lockfile and fail if they differ.
+    Locked,
+    /// Replace the packages in the lockfile with those of the baked layers.
+    Update,
+}
+
+/// Lockfile of a project.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Lockfile {
+    /// Version of the lockfile format.
+    version: u32,
+    /// Locked layers.
+    #[serde(default)]
+    layers: Vec<LayerLock>,
+}
+
+impl Default for Lockfile {
+    fn default() -> Self {
+        Self {
+            version: LOCKFILE_VERSION,
+            layers: Vec::new(),
+        }
+    }
+}
+
+impl Lockfile {
+    /// Load the lockfile from the given project directory.
+    ///
+    /// Returns an empty lockfile, if the project does not have one.
+    pub fn load(project_dir: &Path) -> BakeryResult<Self> {
+        let path = project_dir.join(LOCKFILE);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let lockfile =
+            toml::from_str::<Self>(&fs::read_to_string(&path).whatever("unable to read lockfile")?)
+                .whatever_with(|_| format!("unable to parse lockfile {path:?}"))?;
+        if lockfile.version != LOCKFILE_VERSION {
+            bail!("unsupported lockfile version {}", lockfile.version);
+        }
+        Ok(lockfile)
+    }
+
+    /// Write the lockfile into the given project directory.
+    pub fn write(&self, project_dir: &Path) -> BakeryResult<()> {
+        let mut lockfile =
+            String::from("# This file is generated by Rugix Bakery, do not edit it.\n");
+        lockfile.push_str(&toml::to_string_pretty(self).whatever("unable to serialize lockfile")?);
+        fs::write(project_dir.join(LOCKFILE), lockfile).whatever("unable to write lockfile")
+    }
+
+    /// Lock of the given layer and architecture, if any.
+    pub fn layer(&self, name: &str, arch: Architecture) -> Option<&LayerLock> {
+        self.layers
+            .iter()
+            .find(|lock| lock.name == name && lock.arch == arch.as_str())
+    }
+
+    /// Insert or replace the lock of a layer.
+    pub fn set_layer(&mut self, lock: LayerLock) {
+        self.layers
+            .retain(|other| other.name != lock.name || other.arch != lock.arch);
+        self.layers.push(lock);
+        self.layers
+            .sort_by(|a, b| (&a.name, &a.arch).cmp(&(&b.name, &b.arch)));
+    }
+}
+
+/// Packages installed in the root of a layer, including those of its parent layers.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LayerLock {
+    /// Name of the layer.
+    pub name: String,
+    /// Architecture of the layer.
+    pub arch: String,
+    /// Packages installed in the layer sorted by their name and architecture.
+    pub packages: Vec<LockedPackage>,
+}
+
+impl LayerLock {
+    /// Create the lock of the given layer with the given packages.
+    pub fn new(name: &str, arch: Architecture, mut packages: Vec<LockedPackage>) -> Self {
+        packages.sort_by(|a, b| (&a.name, &a.arch).cmp(&(&b.name, &b.arch)));
+        Self {
+            name: name.to_owned(),
+            arch: arch.as_str().to_owned(),
+            packages,
+        }
+    }
+
+    /// Locked package with the given name, if any.
+    pub fn package(&self, name: &str, manager: PackageManager) -> Option<&LockedPackage> {
+        self.packages
+            .iter()
+            .find(|package| package.name == name && package.manager == manager)
+    }
+
+    /// Digest of the locked packages.
+    pub fn digest(&self) -> String {
+        let mut hasher = Hasher::new();
+        for package in &self.packages {
+            hasher.push(
+                "package",
+                format!(
+                    "{} {} {} {}",
+                    package.name,
+                    package.version,
+                    package.arch,
+                    package.manager.as_str()
+                ),
+            );
+        }
+        hasher.finalize()
+    }
+
+    /// Describe the differences of the given packages to the locked packages.
+    ///
+    /// Packages are identified by their package manager, name, and architecture such that
+    /// packages installed for multiple architectures are compared individually. Returns
+    /// an empty list if the packages match the lock.
+    pub fn differences(&self, packages: &[LockedPackage]) -> Vec<String> {
+        let locked = self
+            .packages
+            .iter()
+            .map(|package| (package.key(), package))
+            .collect::<BTreeMap<_, _>>();
+        let installed = packages
+            .iter()
+            .map(|package| (package.key(), package))
+            .collect::<BTreeMap<_, _>>();
+        let mut differences = Vec::new();
+        for (key, locked) in &locked {
+            let name = locked.qualified_name();
+            match installed.get(key) {
+                None => differences.push(format!("{name}: locked but not installed")),
+                Some(installed) if installed.version != locked.version => {
+                    differences.push(format!(
+                        "{name}: locked {} but installed {}",
+                        locked.version, installed.version
+                    ))
+                }
+                Some(_) => {}
+            }
+        }
+        for (key, installed) in &installed {
+            if !locked.contains_key(key) {
+                let name = installed.qualified_name();
+                differences.push(format!("{name}: installed but not locked"));
+            }
+        }
+        differences
+    }
+}
+
+/// Locked version of a package.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockedPackage {
+    /// Name of the package.
+    pub name: String,
+    /// Version of the package.
+    pub version: String,
+    /// Architecture of the package.
+    pub arch: String,
+    /// Package manager which installed the package.
+    pub manager: PackageManager,
+}
+
+impl LockedPackage {
+    /// Key identifying the package within a layer.
+    fn key(&self) -> (&'static str, &str, &str) {
+        (self.manager.as_str(), &self.name, &self.arch)
+    }
+
+    /// Name of the package qualified with its architecture and package manager.
+    fn qualified_name(&self) -> String {
+        if self.arch.is_empty() {
+            format!("{} ({})", self.name, self.manager.as_str())
+        } else {
+            format!("{}:{} ({})", self.name, self.arch, self.manager.as_str())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn package(name: &str, version: &str, arch: &str) -> LockedPackage {
+        LockedPackage {
+            name: name.to_owned(),
+            version: version.to_owned(),
+            arch: arch.to_owned(),
+            manager: PackageManager::Apt,
+        }
+    }
+
+    #[test]
+    fn test_differences_multi_arch() {
+        let lock = LayerLock::new(
+            "base",
+            Architecture::Amd64,
+            vec![
+                package("libc6", "2.36-9", "amd64"),
+                package("libc6", "2.36-9", "i386"),
+            ],
+        );
+        assert!(lock.differences(&lock.packages).is_empty());
+        let installed = [
+            package("libc6", "2.36-9", "amd64"),
+            package("libc6", "2.36-10", "i386"),
+        ];
+        assert_eq!(
+            lock.differences(&installed),
+            ["libc6:i386 (apt): locked 2.36-9 but installed 2.36-10"]
+        );
+        assert_eq!(
+            lock.differences(&installed[..1]),
+            ["libc6:i386 (apt): locked but not installed"]
+        );
+    }
+}
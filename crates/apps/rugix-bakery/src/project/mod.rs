@@ -2,9 +2,10 @@
 
 use std::os::unix::ffi::OsStrExt;
 use std::path::{Path, PathBuf};
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, Mutex, MutexGuard};
 
 use library::Library;
+use lockfile::{LockMode, Lockfile};
 use reportify::ResultExt;
 use repositories::ProjectRepositories;
 use si_crypto_hashes::HashAlgorithm;
@@ -13,8 +14,11 @@ use crate::config::load_config;
 use crate::config::projects::ProjectConfig;
 use crate::BakeryResult;
 
+pub mod conditions;
 pub mod layers;
 pub mod library;
+pub mod lockfile;
+pub mod parameters;
 pub mod recipes;
 pub mod repositories;
 
@@ -41,6 +45,31 @@ impl ProjectRef {
         &self.shared.config
     }
 
+    /// Directory of build artifacts relative to the project directory.
+    pub fn build_dir(&self) -> &Path {
+        &self.shared.build_dir
+    }
+
+    /// Directory of baked layers relative to the project directory.
+    pub fn layers_dir(&self) -> PathBuf {
+        self.shared.build_dir.join("layers")
+    }
+
+    /// Directory of recipe checkpoints relative to the project directory.
+    pub fn checkpoints_dir(&self) -> PathBuf {
+        self.shared.build_dir.join("checkpoints")
+    }
+
+    /// Mode for handling the project's lockfile.
+    pub fn lock_mode(&self) -> LockMode {
+        self.shared.lock_mode
+    }
+
+    /// Lockfile of the project.
+    pub fn lockfile(&self) -> MutexGuard<'_, Lockfile> {
+        self.shared.lockfile.lock().unwrap()
+    }
+
     /// Retrieve the repositories of the project.
     ///
     /// This may load the repositories lazily.
@@ -73,10 +102,16 @@ impl ProjectRef {
 struct ProjectShared {
     /// Project directory.
     dir: PathBuf,
+    /// Directory for build state relative to the project directory.
+    build_dir: PathBuf,
     /// Local project id.
     id: LocalProjectId,
     /// Project configuration.
     config: Arc<ProjectConfig>,
+    /// Mode for handling the lockfile.
+    lock_mode: LockMode,
+    /// Lockfile of the project.
+    lockfile: Mutex<Lockfile>,
     /// Lazily-loaded project data.
     lazy: ProjectLazy,
 }
@@ -107,6 +142,10 @@ pub struct ProjectLoader {
     config_file: Option<PathBuf>,
     /// Local project id.
     local_id: LocalProjectId,
+    /// Directory for build state relative to the project directory.
+    build_dir: PathBuf,
+    /// Mode for handling the lockfile.
+    lock_mode: LockMode,
 }
 
 impl ProjectLoader {
@@ -116,6 +155,8 @@ impl ProjectLoader {
             project_dir: project_dir.to_path_buf(),
             config_file: None,
             local_id: local_id_from_bytes(project_dir.as_os_str().as_bytes()),
+            build_dir: PathBuf::from(".rugix"),
+            lock_mode: LockMode::default(),
         }
     }
 
@@ -137,6 +178,19 @@ impl ProjectLoader {
         self
     }
 
+    /// Set the directory for baked layers and checkpoints relative to the project
+    /// directory (defaults to `.rugix`).
+    pub fn with_build_dir(mut self, build_dir: &Path) -> Self {
+        self.build_dir = build_dir.to_path_buf();
+        self
+    }
+
+    /// Set the mode for handling the lockfile.
+    pub fn with_lock_mode(mut self, lock_mode: LockMode) -> Self {
+        self.lock_mode = lock_mode;
+        self
+    }
+
     /// The full path to the configuration file.
     fn config_path(&self) -> PathBuf {
         self.project_dir.join(
@@ -149,10 +203,14 @@ impl ProjectLoader {
     /// Load the project.
     pub fn load(self) -> BakeryResult<ProjectRef> {
         let config = load_config(&self.config_path())?;
+        let lockfile = Lockfile::load(&self.project_dir)?;
         Ok(ProjectRef {
             shared: Arc::new(ProjectShared {
                 dir: self.project_dir,
+                build_dir: self.build_dir,
                 config,
+                lock_mode: self.lock_mode,
+                lockfile: Mutex::new(lockfile),
                 id: self.local_id,
                 lazy: ProjectLazy::default(),
             }),
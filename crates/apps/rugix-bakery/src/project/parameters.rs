@@ -0,0 +1,316 @@
+//! Validation of recipe parameters.
+//!
+//! Recipes declare their parameters with an optional type and constraints. The values
+//! given by layers are validated against these definitions when scheduling the recipes
+//! of a layer. Values of parameters without a type are not type-checked, however, string
+//! values must still satisfy the constraints. Recipe steps receive the values as strings
+//! with list items separated by newlines.
+
+use std::collections::HashMap;
+
+use regex::Regex;
+use reportify::{bail, ResultExt};
+
+use crate::config::recipes::{ParameterDef, ParameterType, ParameterValue};
+use crate::BakeryResult;
+
+/// Placeholder for values of secret parameters in logs and provenance.
+pub const REDACTED: &str = "<secret>";
+
+/// Check the given parameter definitions for consistency.
+pub fn check_definitions(definitions: &HashMap<String, ParameterDef>) -> BakeryResult<()> {
+    for (name, def) in definitions {
+        check_definition(def).with_info(|_| format!("in definition of parameter `{name}`"))?;
+    }
+    Ok(())
+}
+
+/// Resolve the values of the given parameter definitions.
+///
+/// Parameters without a value use their default. Parameters which are neither
+/// required nor have a default may be omitted.
+pub fn resolve(
+    definitions: Option<&HashMap<String, ParameterDef>>,
+    values: Option<&HashMap<String, ParameterValue>>,
+) -> BakeryResult<HashMap<String, String>> {
+    if let Some(values) = values {
+        for name in values.keys() {
+            if !definitions.is_some_and(|definitions| definitions.contains_key(name)) {
+                bail!("unknown parameter `{name}`");
+            }
+        }
+    }
+    let mut resolved = HashMap::new();
+    for (name, def) in definitions.into_iter().flatten() {
+        match values.and_then(|values| values.get(name)) {
+            Some(value) => {
+                check_value(def, value)
+                    .with_info(|_| format!("invalid value for parameter `{name}`"))?;
+                resolved.insert(name.clone(), value.to_string());
+            }
+            None => {
+                if let Some(default) = &def.default {
+                    resolved.insert(name.clone(), default.to_string());
+                } else if def.required.unwrap_or(true) {
+                    bail!("missing value for required parameter `{name}`");
+                }
+            }
+        }
+    }
+    Ok(resolved)
+}
+
+/// Indicates whether the parameter with the given name is secret.
+pub fn is_secret(definitions: Option<&HashMap<String, ParameterDef>>, name: &str) -> bool {
+    definitions
+        .and_then(|definitions| definitions.get(name))
+        .and_then(|def| def.secret)
+        .unwrap_or(false)
+}
+
+/// Check a single parameter definition for consistency.
+fn check_definition(def: &ParameterDef) -> BakeryResult<()> {
+    let ty = def.ty.unwrap_or(ParameterType::String);
+    if ty == ParameterType::Enum && def.values.as_deref().unwrap_or_default().is_empty() {
+        bail!("`enum` parameters require `values`");
+    }
+    if let Some(items) = def.items {
+        if ty != ParameterType::List {
+            bail!("`items` is only allowed for `list` parameters");
+        }
+        if items == ParameterType::List {
+            bail!("lists of lists are not supported");
+        }
+    }
+    if let Some(pattern) = &def.pattern {
+        compile_pattern(pattern)?;
+    }
+    if let Some(default) = &def.default {
+        check_value(def, default).with_info(|_| "invalid default value")?;
+    }
+    Ok(())
+}
+
+/// Check the given value against the given parameter definition.
+fn check_value(def: &ParameterDef, value: &ParameterValue) -> BakeryResult<()> {
+    match (def.ty, value) {
+        (Some(ty), _) => check_typed(def, ty, value),
+        (None, ParameterValue::String(_)) => check_typed(def, ParameterType::String, value),
+        // Untyped parameters accept values of any type.
+        (None, _) => Ok(()),
+    }
+}
+
+/// Check the given value against the given type and the definition's constraints.
+fn check_typed(def: &ParameterDef, ty: ParameterType, value: &ParameterValue) -> BakeryResult<()> {
+    match (ty, value) {
+        (ParameterType::String | ParameterType::Enum, ParameterValue::String(value)) => {
+            if let Some(values) = &def.values {
+                if !values.contains(value) {
+                    bail!(
+                        "expected one of {}, found {value:?}",
+                        values
+                            .iter()
+                            .map(|value| format!("{value:?}"))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    );
+                }
+            }
+            if let Some(pattern) = &def.pattern {
+                if !compile_pattern(pattern)?.is_match(value) {
+                    bail!("value {value:?} does not match pattern `{pattern}`");
+                }
+            }
+        }
+        (ParameterType::Bool, ParameterValue::Boolean(_))
+        | (ParameterType::Int, ParameterValue::Integer(_))
+        | (ParameterType::Float, ParameterValue::Float(_) | ParameterValue::Integer(_)) => {}
+        (ParameterType::List, ParameterValue::List(items)) => {
+            let item_ty = def.items.unwrap_or(ParameterType::String);
+            for (idx, item) in items.iter().enumerate() {
+                check_typed(def, item_ty, item).with_info(|_| format!("in item {idx}"))?;
+            }
+        }
+        _ => bail!("expected {}, found {}", type_name(ty), value_kind(value)),
+    }
+    Ok(())
+}
+
+/// Compile the given pattern such that it must match the entire value.
+fn compile_pattern(pattern: &str) -> BakeryResult<Regex> {
+    Regex::new(&format!("^(?:{pattern})$"))
+        .whatever_with(|_| format!("invalid pattern `{pattern}`"))
+}
+
+/// Name of the given parameter type as used in recipe configurations.
+fn type_name(ty: ParameterType) -> &'static str {
+    match ty {
+        ParameterType::String => "string",
+        ParameterType::Bool => "bool",
+        ParameterType::Int => "int",
+        ParameterType::Float => "float",
+        ParameterType::Enum => "enum",
+        ParameterType::List => "list",
+    }
+}
+
+/// Human-readable kind of the given value.
+fn value_kind(value: &ParameterValue) -> String {
+    match value {
+        ParameterValue::String(value) => format!("string {value:?}"),
+        ParameterValue::Boolean(value) => format!("bool `{value}`"),
+        ParameterValue::Integer(value) => format!("int `{value}`"),
+        ParameterValue::Float(value) => format!("float `{value}`"),
+        ParameterValue::List(_) => "list".to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn string(value: &str) -> ParameterValue {
+        ParameterValue::String(value.to_owned())
+    }
+
+    fn definitions(defs: &[(&str, ParameterDef)]) -> HashMap<String, ParameterDef> {
+        defs.iter()
+            .map(|(name, def)| (name.to_string(), def.clone()))
+            .collect()
+    }
+
+    fn values(values: &[(&str, ParameterValue)]) -> HashMap<String, ParameterValue> {
+        values
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn test_resolve_defaults() {
+        let definitions = definitions(&[
+            (
+                "hostname",
+                ParameterDef::new().with_default(Some(string("rugix"))),
+            ),
+            ("optional", ParameterDef::new().with_required(Some(false))),
+        ]);
+        let resolved = resolve(Some(&definitions), None).unwrap();
+        assert_eq!(resolved.len(), 1);
+        assert_eq!(resolved["hostname"], "rugix");
+    }
+
+    #[test]
+    fn test_resolve_values() {
+        let definitions = definitions(&[
+            (
+                "hostname",
+                ParameterDef::new().with_default(Some(string("rugix"))),
+            ),
+            (
+                "packages",
+                ParameterDef::new()
+                    .with_ty(Some(ParameterType::List))
+                    .with_items(Some(ParameterType::String)),
+            ),
+        ]);
+        let values = values(&[
+            ("hostname", string("device")),
+            (
+                "packages",
+                ParameterValue::List(vec![string("vim"), string("htop")]),
+            ),
+        ]);
+        let resolved = resolve(Some(&definitions), Some(&values)).unwrap();
+        assert_eq!(resolved["hostname"], "device");
+        assert_eq!(resolved["packages"], "vim\nhtop");
+    }
+
+    #[test]
+    fn test_resolve_missing_required() {
+        let definitions = definitions(&[("hostname", ParameterDef::new())]);
+        assert!(resolve(Some(&definitions), None).is_err());
+    }
+
+    #[test]
+    fn test_resolve_unknown_parameter() {
+        let definitions = definitions(&[("hostname", ParameterDef::new())]);
+        let values = values(&[("hostname", string("rugix")), ("unknown", string("value"))]);
+        assert!(resolve(Some(&definitions), Some(&values)).is_err());
+        assert!(resolve(None, Some(&values)).is_err());
+    }
+
+    #[test]
+    fn test_resolve_untyped() {
+        let definitions = definitions(&[
+            (
+                "use_musl",
+                ParameterDef::new().with_default(Some(ParameterValue::Boolean(true))),
+            ),
+            ("jobs", ParameterDef::new()),
+            (
+                "hostname",
+                ParameterDef::new().with_pattern(Some("[a-z]+".to_owned())),
+            ),
+        ]);
+        check_definitions(&definitions).unwrap();
+        let given = values(&[
+            ("use_musl", string("false")),
+            ("jobs", ParameterValue::Integer(4)),
+            ("hostname", string("rugix")),
+        ]);
+        let resolved = resolve(Some(&definitions), Some(&given)).unwrap();
+        assert_eq!(resolved["use_musl"], "false");
+        assert_eq!(resolved["jobs"], "4");
+        // String values of untyped parameters must still satisfy the constraints.
+        let invalid = values(&[
+            ("jobs", ParameterValue::Integer(4)),
+            ("hostname", string("-")),
+        ]);
+        assert!(resolve(Some(&definitions), Some(&invalid)).is_err());
+    }
+
+    #[test]
+    fn test_check_typed_scalars() {
+        let def = ParameterDef::new();
+        assert!(check_typed(&def, ParameterType::String, &string("value")).is_ok());
+        assert!(check_typed(&def, ParameterType::String, &ParameterValue::Integer(1)).is_err());
+        assert!(check_typed(&def, ParameterType::Bool, &ParameterValue::Boolean(true)).is_ok());
+        assert!(check_typed(&def, ParameterType::Bool, &string("true")).is_err());
+        assert!(check_typed(&def, ParameterType::Int, &ParameterValue::Integer(42)).is_ok());
+        assert!(check_typed(&def, ParameterType::Int, &ParameterValue::Float(4.2)).is_err());
+        assert!(check_typed(&def, ParameterType::Float, &ParameterValue::Float(4.2)).is_ok());
+        assert!(check_typed(&def, ParameterType::Float, &ParameterValue::Integer(42)).is_ok());
+    }
+
+    #[test]
+    fn test_check_typed_enum() {
+        let def = ParameterDef::new()
+            .with_ty(Some(ParameterType::Enum))
+            .with_values(Some(vec!["arm64".to_owned(), "amd64".to_owned()]));
+        assert!(check_typed(&def, ParameterType::Enum, &string("arm64")).is_ok());
+        assert!(check_typed(&def, ParameterType::Enum, &string("armhf")).is_err());
+    }
+
+    #[test]
+    fn test_check_typed_pattern() {
+        let def = ParameterDef::new().with_pattern(Some("[a-z]+".to_owned()));
+        assert!(check_typed(&def, ParameterType::String, &string("rugix")).is_ok());
+        // Patterns must match the entire value.
+        assert!(check_typed(&def, ParameterType::String, &string("rugix-1")).is_err());
+    }
+
+    #[test]
+    fn test_check_typed_list() {
+        let def = ParameterDef::new()
+            .with_ty(Some(ParameterType::List))
+            .with_items(Some(ParameterType::Int));
+        let valid =
+            ParameterValue::List(vec![ParameterValue::Integer(1), ParameterValue::Integer(2)]);
+        let invalid = ParameterValue::List(vec![ParameterValue::Integer(1), string("2")]);
+        assert!(check_typed(&def, ParameterType::List, &valid).is_ok());
+        assert!(check_typed(&def, ParameterType::List, &invalid).is_err());
+        assert!(check_typed(&def, ParameterType::List, &ParameterValue::Integer(1)).is_err());
+    }
+}
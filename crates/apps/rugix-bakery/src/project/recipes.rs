@@ -1,7 +1,7 @@
 //! Data structures for representing recipes.
 
 use std::ffi::OsStr;
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 use std::sync::Arc;
 use std::{fmt, fs, ops};
 
@@ -10,10 +10,13 @@ use serde::{Deserialize, Serialize};
 use reportify::{bail, whatever, ResultExt};
 
 use crate::config::load_config;
-use crate::config::recipes::RecipeConfig;
-use crate::utils::caching::{mtime_recursive, ModificationTime};
+use crate::config::recipes::{FileEntry, FilesStepConfig, RecipeConfig};
+use crate::oven::templates::template_name;
+use crate::utils::caching::digest_path;
 use crate::BakeryResult;
 
+use super::conditions::Condition;
+use super::parameters::check_definitions;
 use super::repositories::RepositoryIdx;
 
 /// Auxiliary data structure for loading recipes.
@@ -42,15 +45,19 @@ impl RecipeLoader {
     /// Loads a recipe from the given path.
     pub fn load(&self, path: &Path) -> BakeryResult<Recipe> {
         let path = path.to_path_buf();
-        let modified = mtime_recursive(&path).whatever("unable to determine mtime")?;
+        let digest = digest_path(&path).whatever("unable to compute recipe digest")?;
         let name = path
             .file_name()
             .ok_or_else(|| whatever!("unable to determine recipe name from path `{path:?}`"))?
             .to_string_lossy()
             .into();
         let config_path = path.join("recipe.toml");
-        let config =
+        let config: RecipeConfig =
             load_config(&config_path).with_info(|_| format!("while loading recipe {name:?}"))?;
+        if let Some(parameters) = &config.parameters {
+            check_definitions(parameters)
+                .with_info(|_| format!("while loading recipe {name:?}"))?;
+        }
         let mut steps = Vec::new();
         let steps_dir = path.join("steps");
         if steps_dir.exists() {
@@ -61,13 +68,29 @@ impl RecipeLoader {
             }
         }
         steps.sort_by_key(|step| step.position);
+        for (filename, step_config) in config.steps.iter().flatten() {
+            let Some(step) = steps.iter_mut().find(|step| &step.filename == filename) else {
+                bail!("configuration for unknown step `{filename}` of recipe {name:?}");
+            };
+            step.when = step_config
+                .when
+                .as_deref()
+                .map(Condition::parse)
+                .transpose()
+                .with_info(|_| format!("while loading recipe {name:?}"))?;
+        }
+        let has_templates = steps.iter().any(|step| match &step.kind {
+            StepKind::Files { files } => files.iter().any(|file| contains_templates(&file.source)),
+            _ => false,
+        });
         let recipe = Recipe {
             repository: self.repository,
-            modified,
+            digest,
             name,
             config,
             steps,
             path,
+            has_templates,
         };
         Ok(recipe)
     }
@@ -76,8 +99,8 @@ impl RecipeLoader {
 /// A recipe.
 #[derive(Debug, Clone)]
 pub struct Recipe {
-    /// The lastest modification time of the recipe.
-    pub modified: ModificationTime,
+    /// Digest of the recipe's files.
+    pub digest: String,
     pub repository: RepositoryIdx,
     /// The name of the recipe.
     pub name: RecipeName,
@@ -87,6 +110,24 @@ pub struct Recipe {
     pub steps: Vec<RecipeStep>,
     /// The path of the recipe.
     pub path: PathBuf,
+    /// Indicates whether the recipe's files contain templates.
+    pub has_templates: bool,
+}
+
+/// Indicates whether the given file is a template or a directory containing templates.
+fn contains_templates(path: &Path) -> bool {
+    let Ok(metadata) = fs::symlink_metadata(path) else {
+        return false;
+    };
+    if metadata.is_dir() {
+        fs::read_dir(path)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .any(|entry| contains_templates(&entry.path()))
+    } else {
+        metadata.is_file() && path.file_name().and_then(template_name).is_some()
+    }
 }
 
 /// A name of a recipe.
@@ -123,6 +164,9 @@ pub struct RecipeStep {
     pub kind: StepKind,
     /// The filename of the step.
     pub filename: String,
+    /// Condition under which the step is run.
+    #[serde(skip)]
+    pub when: Option<Condition>,
 }
 
 impl RecipeStep {
@@ -147,10 +191,24 @@ impl RecipeStep {
                 let manager = match kind.rsplit_once('.') {
                     Some((_, "apt")) => Some(PackageManager::Apt),
                     Some((_, "apk")) => Some(PackageManager::Apk),
+                    Some((_, "dnf")) => Some(PackageManager::Dnf),
+                    Some((_, "pacman")) => Some(PackageManager::Pacman),
+                    Some((_, "opkg")) => Some(PackageManager::Opkg),
                     _ => None,
                 };
                 StepKind::Packages { packages, manager }
             }
+            "files" => {
+                let recipe_dir = path.parent().and_then(Path::parent).unwrap();
+                let config = load_config::<FilesStepConfig>(path)?;
+                let files = config
+                    .files
+                    .iter()
+                    .map(|entry| FileCopy::new(recipe_dir, entry))
+                    .collect::<BakeryResult<_>>()
+                    .with_info(|_| format!("while loading files step {path:?}"))?;
+                StepKind::Files { files }
+            }
             "install" => StepKind::Install,
             "run" => StepKind::Run,
             _ => bail!("unknown step kind `{kind}`"),
@@ -159,6 +217,7 @@ impl RecipeStep {
             position,
             kind,
             filename,
+            when: None,
         })
     }
 }
@@ -171,14 +230,117 @@ pub enum StepKind {
         manager: Option<PackageManager>,
         packages: Vec<String>,
     },
+    /// Copy files from the recipe's `files` directory into the system.
+    Files { files: Vec<FileCopy> },
     /// Run a script in the `chroot` environment of the system.
     Install,
     /// Run a script on the host machine.
     Run,
 }
 
+/// File or directory to copy into the system.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileCopy {
+    /// Path of the file or directory in the recipe's `files` directory.
+    pub source: PathBuf,
+    /// Absolute path of the destination in the system.
+    pub destination: PathBuf,
+    /// Permission bits of copied files, if they should not be those of the source.
+    pub mode: Option<u32>,
+    /// Permission bits of copied directories.
+    ///
+    /// If set, the permissions and owner of existing directories are replaced.
+    pub directory_mode: Option<u32>,
+    /// Name or numeric id of the owner.
+    pub owner: String,
+    /// Name or numeric id of the group.
+    pub group: String,
+}
+
+impl FileCopy {
+    /// Validate the given entry of a files step of the recipe in the given directory.
+    fn new(recipe_dir: &Path, entry: &FileEntry) -> BakeryResult<Self> {
+        let source = Path::new(&entry.source);
+        if source.is_absolute()
+            || source
+                .components()
+                .any(|component| matches!(component, Component::ParentDir))
+        {
+            bail!("source {source:?} must be relative to the `files` directory");
+        }
+        let source = recipe_dir.join("files").join(source);
+        if !source.exists() {
+            bail!("source {source:?} does not exist");
+        }
+        let destination = PathBuf::from(&entry.destination);
+        if !destination.is_absolute() {
+            bail!("destination {destination:?} must be absolute");
+        }
+        let parse_mode = |mode: &str| {
+            u32::from_str_radix(mode, 8)
+                .ok()
+                .filter(|mode| *mode <= 0o7777)
+                .ok_or_else(|| whatever!("invalid mode `{mode}`"))
+        };
+        Ok(Self {
+            source,
+            destination,
+            mode: entry.mode.as_deref().map(parse_mode).transpose()?,
+            directory_mode: entry
+                .directory_mode
+                .as_deref()
+                .map(parse_mode)
+                .transpose()?,
+            owner: entry.owner.clone().unwrap_or_else(|| "root".to_owned()),
+            group: entry.group.clone().unwrap_or_else(|| "root".to_owned()),
+        })
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum PackageManager {
     Apt,
     Apk,
+    Dnf,
+    Pacman,
+    Opkg,
+}
+
+impl PackageManager {
+    /// Name of the package manager as used in filenames of package steps.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PackageManager::Apt => "apt",
+            PackageManager::Apk => "apk",
+            PackageManager::Dnf => "dnf",
+            PackageManager::Pacman => "pacman",
+            PackageManager::Opkg => "opkg",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::utils::idx_vec::Idx;
+
+    #[test]
+    fn test_load_core_recipes() {
+        let recipes_dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("../../../container/repositories/core/recipes");
+        let loader = RecipeLoader::new(RepositoryIdx::from_usize(0));
+        let mut loaded = 0;
+        for entry in fs::read_dir(&recipes_dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                loader
+                    .load(&path)
+                    .unwrap_or_else(|error| panic!("unable to load recipe {path:?}: {error:?}"));
+                loaded += 1;
+            }
+        }
+        assert!(loaded > 0);
+    }
 }
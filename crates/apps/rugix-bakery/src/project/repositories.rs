@@ -13,6 +13,7 @@ use reportify::{bail, ResultExt};
 use crate::config::repositories::{
     GitSourceConfig, PathSourceConfig, RepositoryConfig, SourceConfig,
 };
+use crate::utils::caching::Hasher;
 use crate::utils::idx_vec::{new_idx_type, IdxVec};
 use crate::{paths, BakeryResult};
 
@@ -202,6 +203,8 @@ pub struct Source {
     pub config: SourceConfig,
     /// The directory where the source has been materialized.
     pub dir: PathBuf,
+    /// The commit which has been checked out, if the source is a Git repository.
+    pub commit: Option<String>,
 }
 
 impl Source {
@@ -211,24 +214,33 @@ impl Source {
     pub fn materialize(config: SourceConfig, root_dir: &Path, update: bool) -> BakeryResult<Self> {
         let id = compute_source_id(&config);
         debug!("materializing source {id}");
-        let path = match &config {
-            SourceConfig::Path(config) => root_dir.join(&config.path),
+        let (path, commit) = match &config {
+            SourceConfig::Path(config) => (root_dir.join(&config.path), None),
             SourceConfig::Git(config) => {
                 let mut path = root_dir.join(".rugix/repositories");
                 path.push(id.as_str());
-                check_out_git_source(config, &path, update)?;
+                let commit = check_out_git_source(config, &path, update)?;
                 if let Some(repository_path) = &config.dir {
                     path.push(repository_path);
                 }
-                path
+                (path, Some(commit))
             }
         };
         Ok(Self {
             id,
             config,
             dir: path,
+            commit,
         })
     }
+
+    /// Push the id and the checked out commit of the source into the hasher.
+    pub fn push_digest(&self, hasher: &mut Hasher) {
+        hasher.push("source", self.id.as_str());
+        if let Some(commit) = &self.commit {
+            hasher.push("commit", commit);
+        }
+    }
 }
 
 /// Globally unique id of a source.
@@ -274,10 +286,14 @@ fn compute_source_id(config: &SourceConfig) -> SourceId {
     SourceId(hex::encode(&hasher.finalize()[..]).into())
 }
 
-/// Check out the Git repository in the given directory.
+/// Check out the Git repository in the given directory and return the commit.
 ///
 /// The *fetch* flag indicates whether updates should be fetched from the remote.
-fn check_out_git_source(config: &GitSourceConfig, path: &Path, fetch: bool) -> BakeryResult<()> {
+fn check_out_git_source(
+    config: &GitSourceConfig,
+    path: &Path,
+    fetch: bool,
+) -> BakeryResult<String> {
     if !path.exists() {
         run!(["git", "clone", &config.url, path]).whatever("unable to clone repository")?;
     }
@@ -302,7 +318,7 @@ fn check_out_git_source(config: &GitSourceConfig, path: &Path, fetch: bool) -> B
     }
     let head = rev_parse!("HEAD^{{commit}}")?;
     if head != commit {
-        run!(env, ["git", "checkout", commit]).whatever("error checking out commit")?;
+        run!(env, ["git", "checkout", &commit]).whatever("error checking out commit")?;
     }
-    Ok(())
+    Ok(commit)
 }
@@ -0,0 +1,42 @@
+//! Utilities for producing build outputs atomically.
+//!
+//! Outputs are first written to a temporary path next to their final location and then
+//! renamed into place. In addition, output directories carry a completion marker which
+//! is only created once everything in the directory has been written. Freshness checks
+//! must require the marker such that interrupted builds are never treated as valid.
+
+use std::path::{Path, PathBuf};
+use std::{fs, io};
+
+/// Name of the marker file indicating that an output directory is complete.
+const COMPLETE_MARKER: &str = ".complete";
+
+/// Temporary path next to the given path to write an output to.
+pub fn partial_path(path: &Path) -> PathBuf {
+    let mut file_name = path.file_name().unwrap_or_default().to_os_string();
+    file_name.push(".partial");
+    path.with_file_name(file_name)
+}
+
+/// Atomically move a partial output into its final place.
+pub fn finish(partial: &Path, path: &Path) -> Result<(), io::Error> {
+    fs::rename(partial, path)
+}
+
+/// Check whether the given output directory has been marked as complete.
+pub fn is_complete(dir: &Path) -> bool {
+    dir.join(COMPLETE_MARKER).exists()
+}
+
+/// Mark the given output directory as complete.
+pub fn mark_complete(dir: &Path) -> Result<(), io::Error> {
+    fs::write(dir.join(COMPLETE_MARKER), b"")
+}
+
+/// Remove the completion marker of the given output directory, if it exists.
+pub fn unmark_complete(dir: &Path) -> Result<(), io::Error> {
+    match fs::remove_file(dir.join(COMPLETE_MARKER)) {
+        Err(error) if error.kind() != io::ErrorKind::NotFound => Err(error),
+        _ => Ok(()),
+    }
+}
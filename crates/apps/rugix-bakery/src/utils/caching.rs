@@ -1,20 +1,45 @@
 //! Utilities for caching.
 
-use std::fs;
 use std::io::{self, Read, Write};
+use std::os::unix::ffi::OsStrExt;
+use std::os::unix::fs::MetadataExt;
 use std::path::{Path, PathBuf};
-use std::time::SystemTime;
+use std::time::{Duration, SystemTime};
+use std::{fmt, fs};
 
 use reportify::{bail, ResultExt};
-use rugix_cli::progress::ProgressBarSegment;
+use reqwest::header::{CONTENT_RANGE, RANGE};
+use reqwest::StatusCode;
 use serde::{Deserialize, Serialize};
 use sha1::{Digest, Sha1};
-use tracing::info;
+use sha2::{Sha256, Sha512};
+use tracing::{info, warn};
 use url::Url;
 
+use crate::cli::status::DownloadStatus;
+use crate::utils::atomic;
 use crate::BakeryResult;
 
-pub fn download(url: &Url) -> BakeryResult<PathBuf> {
+/// Metadata stored next to a downloaded file in the cache.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DownloadMetadata {
+    /// URL the file has been downloaded from.
+    pub url: String,
+    /// Size of the file in bytes.
+    pub size: u64,
+    /// SHA256 digest of the file.
+    pub sha256: String,
+}
+
+/// Path of the metadata file of the given cache file.
+pub fn download_metadata_path(cache_file_path: &Path) -> PathBuf {
+    let mut file_name = cache_file_path.file_name().unwrap().to_os_string();
+    file_name.push(".meta.json");
+    cache_file_path.with_file_name(file_name)
+}
+
+/// Path of the cache file for the given URL.
+pub fn download_cache_path(url: &Url) -> BakeryResult<PathBuf> {
     let Some(file_name) = url.path_segments().and_then(|segments| segments.last()) else {
         bail!("unable to obtain file name from URL");
     };
@@ -27,43 +52,246 @@ pub fn download(url: &Url) -> BakeryResult<PathBuf> {
         cache_file_name.push('.');
         cache_file_name.push_str(extension);
     }
-    let cache_file_path = Path::new(".rugix/cache").join(cache_file_name);
-    if !cache_file_path.exists() {
-        info!("downloading `{url}`");
-        std::fs::create_dir_all(".rugix/cache").whatever("error creating cache directory")?;
-        let mut response = reqwest::blocking::get(url.clone()).whatever("error retrieving URL")?;
-        if response.status().is_success() {
-            let Some(size) = response.content_length() else {
-                bail!("server did not send `Content-Length` header");
-            };
-            let progress = ProgressBarSegment::new(size);
-            let mut file =
-                fs::File::create(&cache_file_path).whatever("error creating hash file")?;
-            let mut buffer = vec![0u8; 8096];
-            let mut downloaded_size = 0;
-            loop {
-                let chunk_size = response
-                    .read(&mut buffer)
-                    .whatever("error reading from response")?;
-                if chunk_size > 0 {
-                    file.write_all(&buffer[..chunk_size])
-                        .whatever("error writing to cache file")?;
-                    downloaded_size += chunk_size as u64;
-                    progress.set_step(downloaded_size);
-                } else {
-                    break;
+    Ok(Path::new(".rugix/cache").join(cache_file_name))
+}
+
+/// Record that the given cached file has just been used.
+///
+/// The modification time of cached files is used to evict the least recently used
+/// entries when collecting garbage.
+pub fn touch(path: &Path) -> Result<(), io::Error> {
+    fs::File::options()
+        .write(true)
+        .open(path)?
+        .set_modified(SystemTime::now())
+}
+
+/// Number of attempts per URL before falling back to the next mirror.
+const DOWNLOAD_ATTEMPTS: u32 = 3;
+
+/// Delay before retrying a failed download, doubled with every further attempt.
+const DOWNLOAD_BACKOFF: Duration = Duration::from_secs(2);
+
+/// Download the file from the given URL into the cache, if it is not cached already.
+///
+/// The file is first downloaded to a partial file which is renamed into place once the
+/// download is complete. Interrupted downloads are resumed via HTTP range requests,
+/// failed downloads are retried with exponential backoff, and the given mirrors are
+/// tried in order if the file cannot be downloaded from the URL itself. Cached files are
+/// only reused if their metadata file exists, which is written once the download is
+/// complete.
+pub fn download(url: &Url, mirrors: &[Url]) -> BakeryResult<PathBuf> {
+    let cache_file_path = download_cache_path(url)?;
+    let file_name = url
+        .path_segments()
+        .and_then(|segments| segments.last())
+        .unwrap();
+    let metadata_path = download_metadata_path(&cache_file_path);
+    if cache_file_path.exists() && metadata_path.exists() {
+        touch(&cache_file_path).whatever("unable to update last use of cached file")?;
+        return Ok(cache_file_path);
+    }
+    std::fs::create_dir_all(".rugix/cache").whatever("error creating cache directory")?;
+    let partial_path = atomic::partial_path(&cache_file_path);
+    let status = rugix_cli::add_status(DownloadStatus::new(format!("Download: {file_name}")));
+    for source in std::iter::once(url).chain(mirrors) {
+        let mut backoff = DOWNLOAD_BACKOFF;
+        for attempt in 1..=DOWNLOAD_ATTEMPTS {
+            info!("downloading `{source}` (attempt {attempt}/{DOWNLOAD_ATTEMPTS})");
+            match download_partial(source, &partial_path, &status) {
+                Ok(()) => {
+                    let (sha256, size) = sha256_file(&partial_path)
+                        .whatever("error computing checksum of download")?;
+                    atomic::finish(&partial_path, &cache_file_path)
+                        .whatever("error moving download into cache")?;
+                    let metadata = DownloadMetadata {
+                        url: url.to_string(),
+                        size,
+                        sha256,
+                    };
+                    fs::write(
+                        &metadata_path,
+                        serde_json::to_string_pretty(&metadata).unwrap(),
+                    )
+                    .whatever("error writing download metadata")?;
+                    return Ok(cache_file_path);
+                }
+                Err(error) => {
+                    warn!("error downloading `{source}`: {error:?}");
+                    status.set_message(format!("attempt {attempt} of `{source}` failed"));
+                    if attempt < DOWNLOAD_ATTEMPTS {
+                        std::thread::sleep(backoff);
+                        backoff *= 2;
+                    }
                 }
             }
-        } else {
-            bail!("error downloading file: {}", response.status());
         }
     }
-    Ok(cache_file_path)
+    bail!("unable to download `{url}`, all attempts failed");
+}
+
+/// Download the file from the given URL to the given partial file.
+///
+/// If the partial file exists, the download is resumed where it stopped.
+fn download_partial(url: &Url, partial_path: &Path, status: &DownloadStatus) -> BakeryResult<()> {
+    let offset = fs::metadata(partial_path)
+        .map(|metadata| metadata.len())
+        .unwrap_or(0);
+    let mut request = reqwest::blocking::Client::new().get(url.clone());
+    if offset > 0 {
+        request = request.header(RANGE, format!("bytes={offset}-"));
+    }
+    let mut response = request.send().whatever("error retrieving URL")?;
+    let (mut file, mut downloaded, size) = match response.status() {
+        StatusCode::PARTIAL_CONTENT if offset > 0 => {
+            let Some((start, size)) = response
+                .headers()
+                .get(CONTENT_RANGE)
+                .and_then(|value| value.to_str().ok())
+                .and_then(parse_content_range)
+            else {
+                fs::remove_file(partial_path).ok();
+                bail!("server sent invalid `Content-Range` header");
+            };
+            if start != offset {
+                fs::remove_file(partial_path).ok();
+                bail!("server resumed download at byte {start} instead of {offset}");
+            }
+            info!("resuming download at byte {offset}");
+            let file = fs::OpenOptions::new()
+                .append(true)
+                .open(partial_path)
+                .whatever("error opening partial download")?;
+            (file, offset, size)
+        }
+        StatusCode::RANGE_NOT_SATISFIABLE => {
+            fs::remove_file(partial_path).ok();
+            bail!("server is unable to resume download");
+        }
+        response_status if response_status.is_success() => {
+            let file =
+                fs::File::create(partial_path).whatever("error creating partial download")?;
+            (file, 0, response.content_length())
+        }
+        response_status => bail!("error downloading file: {response_status}"),
+    };
+    status.set_progress(downloaded, size);
+    let mut buffer = vec![0u8; 8096];
+    loop {
+        let chunk_size = response
+            .read(&mut buffer)
+            .whatever("error reading from response")?;
+        if chunk_size == 0 {
+            break;
+        }
+        file.write_all(&buffer[..chunk_size])
+            .whatever("error writing partial download")?;
+        downloaded += chunk_size as u64;
+        status.set_progress(downloaded, size);
+    }
+    file.flush().whatever("error writing partial download")?;
+    if let Some(size) = size {
+        if downloaded != size {
+            bail!("incomplete download, expected {size} bytes, got {downloaded}");
+        }
+    }
+    Ok(())
+}
+
+/// Parse the start and the total size, if known, from a `Content-Range` header.
+fn parse_content_range(value: &str) -> Option<(u64, Option<u64>)> {
+    let (range, size) = value.strip_prefix("bytes ")?.split_once('/')?;
+    let (start, _) = range.split_once('-')?;
+    let size = match size {
+        "*" => None,
+        size => Some(size.parse().ok()?),
+    };
+    Some((start.parse().ok()?, size))
+}
+
+/// Algorithm of a [`ContentDigest`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DigestAlgorithm {
+    Sha256,
+    Sha512,
+}
+
+impl DigestAlgorithm {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            DigestAlgorithm::Sha256 => "sha256",
+            DigestAlgorithm::Sha512 => "sha512",
+        }
+    }
+}
+
+/// Digest of the content of a file, e.g., `sha256:<hex>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContentDigest {
+    algorithm: DigestAlgorithm,
+    value: String,
+}
+
+impl ContentDigest {
+    /// Parse a digest of the form `<algorithm>:<hex>`.
+    pub fn parse(digest: &str) -> BakeryResult<Self> {
+        let Some((algorithm, value)) = digest.split_once(':') else {
+            bail!("invalid digest `{digest}`, expected `<algorithm>:<hex>`");
+        };
+        let (algorithm, length) = match algorithm {
+            "sha256" => (DigestAlgorithm::Sha256, 64),
+            "sha512" => (DigestAlgorithm::Sha512, 128),
+            _ => bail!("unsupported digest algorithm `{algorithm}`, expected `sha256` or `sha512`"),
+        };
+        if value.len() != length || !value.chars().all(|c| c.is_ascii_hexdigit()) {
+            bail!("invalid {} digest `{value}`", algorithm.as_str());
+        }
+        Ok(Self {
+            algorithm,
+            value: value.to_ascii_lowercase(),
+        })
+    }
+
+    /// Compute the digest of the given file with the given algorithm.
+    pub fn compute(algorithm: DigestAlgorithm, path: &Path) -> Result<Self, io::Error> {
+        let mut file = fs::File::open(path)?;
+        let value = match algorithm {
+            DigestAlgorithm::Sha256 => {
+                let mut hasher = Sha256::new();
+                io::copy(&mut file, &mut hasher)?;
+                hex::encode(hasher.finalize())
+            }
+            DigestAlgorithm::Sha512 => {
+                let mut hasher = Sha512::new();
+                io::copy(&mut file, &mut hasher)?;
+                hex::encode(hasher.finalize())
+            }
+        };
+        Ok(Self { algorithm, value })
+    }
+
+    /// Verify that the given file has this digest.
+    pub fn verify(&self, path: &Path) -> BakeryResult<()> {
+        info!("verifying digest of {path:?}");
+        let actual = Self::compute(self.algorithm, path)
+            .whatever_with(|_| format!("unable to compute digest of {path:?}"))?;
+        if &actual != self {
+            bail!("digest mismatch for {path:?}, expected `{self}` but found `{actual}`");
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for ContentDigest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.algorithm.as_str(), self.value)
+    }
 }
 
 #[derive(Debug, Default)]
 pub struct Hasher {
     hasher: Sha1,
+    record: Option<Vec<(String, String)>>,
 }
 
 impl Hasher {
@@ -71,53 +299,78 @@ impl Hasher {
         Self::default()
     }
 
+    /// Create a hasher which records all pushed values.
+    pub fn recording() -> Self {
+        Self {
+            hasher: Sha1::default(),
+            record: Some(Vec::new()),
+        }
+    }
+
     pub fn push(&mut self, tag: &str, value: impl AsRef<[u8]>) {
+        let value = value.as_ref();
         self.hasher.update(tag.as_bytes());
         self.hasher.update(b":");
-        self.hasher.update(value.as_ref());
+        self.hasher.update(value);
         self.hasher.update(b"\n");
+        if let Some(record) = &mut self.record {
+            record.push((tag.to_owned(), String::from_utf8_lossy(value).into_owned()));
+        }
     }
 
-    pub fn finalize(self) -> String {
-        hex::encode(self.hasher.finalize())
+    /// Values pushed so far, if the hasher is recording.
+    pub fn recorded(&self) -> &[(String, String)] {
+        self.record.as_deref().unwrap_or_default()
     }
-}
 
-/// Modification time in seconds since the UNIX epoch.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
-pub struct ModificationTime(u64);
-
-impl ModificationTime {
-    /// Extract the modification time from the provided filesystem metadata.
-    fn from_metadata(metadata: &fs::Metadata) -> Result<Self, io::Error> {
-        metadata.modified().map(|modified| {
-            ModificationTime(
-                modified
-                    .duration_since(SystemTime::UNIX_EPOCH)
-                    .unwrap()
-                    .as_secs(),
-            )
-        })
+    pub fn finalize(self) -> String {
+        hex::encode(self.hasher.finalize())
     }
 }
 
-/// The modification time of the given path.
-pub fn mtime(path: &Path) -> Result<ModificationTime, io::Error> {
-    fs::metadata(path).and_then(|metadata| ModificationTime::from_metadata(&metadata))
+/// Compute a digest of the contents of the given path.
+///
+/// For directories, the digest covers the names, types, permissions, and contents of
+/// all entries, recursively. Modification times are deliberately ignored such that
+/// touching or checking out files does not change the digest.
+pub fn digest_path(path: &Path) -> Result<String, io::Error> {
+    let mut hasher = Hasher::new();
+    push_path(&mut hasher, path, Path::new(""))?;
+    Ok(hasher.finalize())
 }
 
-/// Recursively scans a path and return the latest modification time.
-pub fn mtime_recursive(path: &Path) -> Result<ModificationTime, io::Error> {
-    let mut time = mtime(path)?;
-    if path.is_dir() {
-        for entry in fs::read_dir(path)? {
-            let entry = entry?;
-            time = time.max(if entry.file_type()?.is_dir() {
-                mtime_recursive(&entry.path())?
-            } else {
-                ModificationTime::from_metadata(&entry.metadata()?)?
-            });
+fn push_path(hasher: &mut Hasher, path: &Path, relative: &Path) -> Result<(), io::Error> {
+    let metadata = fs::symlink_metadata(path)?;
+    let relative_str = relative.to_string_lossy();
+    hasher.push("mode", format!("{:o}", metadata.mode() & 0o7777));
+    if metadata.is_symlink() {
+        hasher.push("symlink", relative_str.as_bytes());
+        hasher.push("target", fs::read_link(path)?.as_os_str().as_bytes());
+    } else if metadata.is_dir() {
+        hasher.push("dir", relative_str.as_bytes());
+        let mut entries = fs::read_dir(path)?
+            .map(|entry| entry.map(|entry| entry.file_name()))
+            .collect::<Result<Vec<_>, _>>()?;
+        entries.sort();
+        for name in entries {
+            push_path(hasher, &path.join(&name), &relative.join(&name))?;
         }
+    } else if !metadata.is_file() {
+        // Device nodes, FIFOs, and sockets have no content to hash.
+        hasher.push("special", relative_str.as_bytes());
+        hasher.push("rdev", metadata.rdev().to_string());
+    } else {
+        hasher.push("file", relative_str.as_bytes());
+        let mut file_hasher = Sha1::new();
+        io::copy(&mut fs::File::open(path)?, &mut file_hasher)?;
+        hasher.push("content", hex::encode(file_hasher.finalize()));
     }
-    Ok(time)
+    Ok(())
+}
+
+/// Compute the SHA256 digest and the size of the given file.
+pub fn sha256_file(path: &Path) -> Result<(String, u64), io::Error> {
+    let mut hasher = Sha256::new();
+    let size = io::copy(&mut fs::File::open(path)?, &mut hasher)?;
+    Ok((hex::encode(hasher.finalize()), size))
 }
@@ -0,0 +1,78 @@
+//! Utilities for decompressing images.
+//!
+//! The compression format is detected based on the magic bytes at the start of the
+//! file such that the file name does not matter.
+
+use std::fs;
+use std::io::{self, BufReader, Read};
+use std::path::Path;
+
+/// Compression format of a file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compression {
+    Xz,
+    Gzip,
+    Zstd,
+    Bzip2,
+    Lz4,
+}
+
+impl Compression {
+    /// Detect the compression format based on the first bytes of a file.
+    pub fn detect(magic: &[u8]) -> Option<Self> {
+        if magic.starts_with(&[0xFD, b'7', b'z', b'X', b'Z', 0x00]) {
+            Some(Compression::Xz)
+        } else if magic.starts_with(&[0x1F, 0x8B]) {
+            Some(Compression::Gzip)
+        } else if magic.starts_with(&[0x28, 0xB5, 0x2F, 0xFD]) {
+            Some(Compression::Zstd)
+        } else if magic.starts_with(b"BZh") {
+            Some(Compression::Bzip2)
+        } else if magic.starts_with(&[0x04, 0x22, 0x4D, 0x18]) {
+            Some(Compression::Lz4)
+        } else {
+            None
+        }
+    }
+
+    /// Name of the compression format.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Compression::Xz => "XZ",
+            Compression::Gzip => "GZ",
+            Compression::Zstd => "Zstandard",
+            Compression::Bzip2 => "BZ2",
+            Compression::Lz4 => "LZ4",
+        }
+    }
+
+    /// Wrap the given reader such that it yields the decompressed data.
+    pub fn decoder<'r>(self, reader: impl Read + 'r) -> io::Result<Box<dyn Read + 'r>> {
+        let reader = BufReader::new(reader);
+        Ok(match self {
+            Compression::Xz => Box::new(xz2::read::XzDecoder::new_multi_decoder(reader)),
+            Compression::Gzip => Box::new(flate2::read::MultiGzDecoder::new(reader)),
+            Compression::Zstd => Box::new(zstd::stream::read::Decoder::with_buffer(reader)?),
+            Compression::Bzip2 => Box::new(bzip2::read::MultiBzDecoder::new(reader)),
+            Compression::Lz4 => Box::new(lz4_flex::frame::FrameDecoder::new(reader)),
+        })
+    }
+}
+
+/// Detect the compression format of the given file.
+pub fn detect_compression(path: &Path) -> io::Result<Option<Compression>> {
+    let mut magic = Vec::with_capacity(6);
+    fs::File::open(path)?.take(6).read_to_end(&mut magic)?;
+    Ok(Compression::detect(&magic))
+}
+
+/// Open the given file for reading its decompressed contents.
+///
+/// Uncompressed files are read as they are.
+pub fn open_decompressed(path: &Path) -> io::Result<Box<dyn Read>> {
+    let file = fs::File::open(path)?;
+    match detect_compression(path)? {
+        Some(compression) => compression.decoder(file),
+        None => Ok(Box::new(file)),
+    }
+}
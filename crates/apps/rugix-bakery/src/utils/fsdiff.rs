@@ -0,0 +1,237 @@
+//! Utilities for comparing directory trees.
+
+use std::collections::BTreeMap;
+use std::os::unix::fs::{FileTypeExt, MetadataExt};
+use std::path::{Component, Path, PathBuf};
+use std::{fs, io};
+
+use serde::Serialize;
+
+use super::caching::sha256_file;
+
+/// Kind of a file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case", tag = "type")]
+pub enum FileKind {
+    File,
+    Directory,
+    Symlink { target: PathBuf },
+    Device { rdev: u64 },
+    Fifo,
+    Socket,
+}
+
+/// Information about a file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FileInfo {
+    /// Kind of the file.
+    #[serde(flatten)]
+    pub kind: FileKind,
+    /// Permission bits of the file.
+    pub mode: u32,
+    /// Owner of the file.
+    pub uid: u32,
+    /// Group of the file.
+    pub gid: u32,
+    /// Size of the file in bytes.
+    pub size: u64,
+    /// Modification time of the file in seconds.
+    pub mtime: i64,
+    /// SHA256 digest of the file's contents, if it is a regular file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sha256: Option<String>,
+}
+
+/// Files of a directory tree indexed by their path relative to its root.
+pub type FileTree = BTreeMap<PathBuf, FileInfo>;
+
+/// Scan the directory tree with the given root.
+pub fn scan(root: &Path) -> io::Result<FileTree> {
+    let mut tree = FileTree::new();
+    scan_dir(root, Path::new(""), &mut tree)?;
+    Ok(tree)
+}
+
+fn scan_dir(root: &Path, relative: &Path, tree: &mut FileTree) -> io::Result<()> {
+    let mut entries = fs::read_dir(root.join(relative))?.collect::<Result<Vec<_>, _>>()?;
+    entries.sort_by_key(|entry| entry.file_name());
+    for entry in entries {
+        let relative = relative.join(entry.file_name());
+        let path = entry.path();
+        let metadata = fs::symlink_metadata(&path)?;
+        let file_type = metadata.file_type();
+        let mut sha256 = None;
+        let kind = if file_type.is_symlink() {
+            FileKind::Symlink {
+                target: fs::read_link(&path)?,
+            }
+        } else if file_type.is_dir() {
+            FileKind::Directory
+        } else if file_type.is_file() {
+            sha256 = Some(sha256_file(&path)?.0);
+            FileKind::File
+        } else if file_type.is_block_device() || file_type.is_char_device() {
+            FileKind::Device {
+                rdev: metadata.rdev(),
+            }
+        } else if file_type.is_fifo() {
+            FileKind::Fifo
+        } else {
+            FileKind::Socket
+        };
+        tree.insert(
+            relative.clone(),
+            FileInfo {
+                kind,
+                mode: metadata.mode() & 0o7777,
+                uid: metadata.uid(),
+                gid: metadata.gid(),
+                size: if file_type.is_file() {
+                    metadata.len()
+                } else {
+                    0
+                },
+                mtime: metadata.mtime(),
+                sha256,
+            },
+        );
+        if file_type.is_dir() {
+            scan_dir(root, &relative, tree)?;
+        }
+    }
+    Ok(())
+}
+
+/// Change of a file between two trees.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "kebab-case", tag = "change")]
+pub enum Change {
+    Added {
+        new: FileInfo,
+    },
+    Removed {
+        old: FileInfo,
+    },
+    Modified {
+        /// Properties which changed, e.g., `content`, `mode`, or `owner`.
+        changed: Vec<&'static str>,
+        old: FileInfo,
+        new: FileInfo,
+    },
+}
+
+impl Change {
+    /// Indicates whether the change is a modification of metadata only.
+    pub fn is_metadata_only(&self) -> bool {
+        match self {
+            Change::Modified { changed, .. } => !changed.contains(&"content"),
+            _ => false,
+        }
+    }
+}
+
+/// Change of the file with the given path.
+#[derive(Debug, Clone, Serialize)]
+pub struct FileChange {
+    /// Path of the file relative to the root of the trees.
+    pub path: PathBuf,
+    /// Change of the file.
+    #[serde(flatten)]
+    pub change: Change,
+}
+
+/// Compute the changes between the given trees.
+///
+/// Modification times are only compared if `compare_mtime` is set.
+pub fn diff(old: &FileTree, new: &FileTree, compare_mtime: bool) -> Vec<FileChange> {
+    let mut changes = Vec::new();
+    for (path, old_info) in old {
+        let change = match new.get(path) {
+            None => Change::Removed {
+                old: old_info.clone(),
+            },
+            Some(new_info) => {
+                let mut changed = Vec::new();
+                if old_info.kind != new_info.kind {
+                    changed.push("kind");
+                }
+                if old_info.sha256 != new_info.sha256 || old_info.size != new_info.size {
+                    changed.push("content");
+                }
+                if old_info.mode != new_info.mode {
+                    changed.push("mode");
+                }
+                if old_info.uid != new_info.uid || old_info.gid != new_info.gid {
+                    changed.push("owner");
+                }
+                if compare_mtime && old_info.mtime != new_info.mtime {
+                    changed.push("mtime");
+                }
+                if changed.is_empty() {
+                    continue;
+                }
+                Change::Modified {
+                    changed,
+                    old: old_info.clone(),
+                    new: new_info.clone(),
+                }
+            }
+        };
+        changes.push(FileChange {
+            path: path.clone(),
+            change,
+        });
+    }
+    for (path, new_info) in new {
+        if !old.contains_key(path) {
+            changes.push(FileChange {
+                path: path.clone(),
+                change: Change::Added {
+                    new: new_info.clone(),
+                },
+            });
+        }
+    }
+    changes.sort_by(|a, b| a.path.cmp(&b.path));
+    changes
+}
+
+/// Summary of the changes within a directory.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ChangeSummary {
+    /// Number of added files.
+    pub added: usize,
+    /// Number of removed files.
+    pub removed: usize,
+    /// Number of modified files.
+    pub modified: usize,
+    /// Change of the total size of the files in bytes.
+    pub size_delta: i64,
+}
+
+/// Summarize the given changes grouped by their top-level directory.
+pub fn summarize(changes: &[FileChange]) -> BTreeMap<PathBuf, ChangeSummary> {
+    let mut summaries = BTreeMap::<PathBuf, ChangeSummary>::new();
+    for change in changes {
+        let top_level = match change.path.components().next() {
+            Some(Component::Normal(name)) => PathBuf::from(name),
+            _ => change.path.clone(),
+        };
+        let summary = summaries.entry(top_level).or_default();
+        match &change.change {
+            Change::Added { new } => {
+                summary.added += 1;
+                summary.size_delta += new.size as i64;
+            }
+            Change::Removed { old } => {
+                summary.removed += 1;
+                summary.size_delta -= old.size as i64;
+            }
+            Change::Modified { old, new, .. } => {
+                summary.modified += 1;
+                summary.size_delta += new.size as i64 - old.size as i64;
+            }
+        }
+    }
+    summaries
+}
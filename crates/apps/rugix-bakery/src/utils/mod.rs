@@ -1,6 +1,9 @@
 //! Various utilities.
 
+pub mod atomic;
 pub mod caching;
+pub mod compression;
+pub mod fsdiff;
 pub mod idx_vec;
 pub mod once_cell_ext;
 pub mod prelude;